@@ -0,0 +1,104 @@
+//! Times the hot paths of syncing cameras. Run with `cargo bench`.
+//!
+//! This is a plain `main` with `std::time::Instant` rather than a benchmark framework, so it has no extra dependencies.
+//! Each line reports the mean time per iteration.
+
+use bevy::{
+    ecs::{event::Events, schedule::Schedule, world::World},
+    math::{Quat, Vec3},
+    time::Time,
+    transform::components::Transform,
+};
+use smooth_bevy_cameras::{
+    look_transform_system, LookTransform, LookTransformMoved, LookTransformMovedEpsilon, Smoother,
+};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const SMOOTH_ITERATIONS: u32 = 10_000;
+const SYNC_ITERATIONS: u32 = 100;
+const FROM_TRANSFORM_ITERATIONS: u32 = 10_000;
+
+fn main() {
+    bench_smooth_transform();
+    for cameras in [100, 1_000, 10_000] {
+        bench_sync(cameras);
+    }
+    bench_from_transform();
+}
+
+fn report(name: &str, iterations: u32, elapsed: Duration) {
+    println!("{name:<40} {:>12.3?} / iter", elapsed / iterations);
+}
+
+fn bench_smooth_transform() {
+    let goals = [
+        LookTransform::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO, Vec3::Y),
+        LookTransform::new(Vec3::new(5.0, 2.0, 0.0), Vec3::X, Vec3::Y),
+    ];
+    let mut smoother = Smoother::new(0.9);
+
+    let start = Instant::now();
+    for i in 0..SMOOTH_ITERATIONS {
+        let goal = &goals[i as usize % goals.len()];
+        black_box(smoother.smooth_transform(black_box(goal), 1.0 / 60.0));
+    }
+    report(
+        "Smoother::smooth_transform",
+        SMOOTH_ITERATIONS,
+        start.elapsed(),
+    );
+}
+
+fn bench_sync(cameras: usize) {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<LookTransformMovedEpsilon>();
+    world.init_resource::<Events<LookTransformMoved>>();
+    for i in 0..cameras {
+        let eye = Vec3::new(i as f32, 2.0, 5.0);
+        world.spawn((
+            LookTransform::new(eye, Vec3::new(i as f32, 0.0, 0.0), Vec3::Y),
+            Smoother::new(0.9),
+            Transform::default(),
+        ));
+    }
+    let mut schedule = Schedule::default();
+    schedule.add_systems(look_transform_system);
+    // Builds the schedule and initializes the system outside of the timed loop.
+    schedule.run(&mut world);
+
+    let start = Instant::now();
+    for _ in 0..SYNC_ITERATIONS {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        schedule.run(&mut world);
+        world.resource_mut::<Events<LookTransformMoved>>().update();
+    }
+    report(
+        &format!("look_transform_system, {cameras} cameras"),
+        SYNC_ITERATIONS,
+        start.elapsed(),
+    );
+}
+
+fn bench_from_transform() {
+    let transforms = [
+        Transform::from_xyz(0.0, 2.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        Transform::from_xyz(-3.0, 1.0, 0.0).with_rotation(Quat::from_rotation_y(1.0)),
+    ];
+
+    let start = Instant::now();
+    for i in 0..FROM_TRANSFORM_ITERATIONS {
+        let transform = transforms[i as usize % transforms.len()];
+        black_box(LookTransform::from(black_box(transform)));
+    }
+    report(
+        "LookTransform::from(Transform)",
+        FROM_TRANSFORM_ITERATIONS,
+        start.elapsed(),
+    );
+}
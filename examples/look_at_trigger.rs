@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use smooth_bevy_cameras::{
+    controllers::fps::{FpsCameraBundle, FpsCameraController, FpsCameraPlugin},
+    LookTransform, LookTransformPlugin,
+};
+
+fn main() {
+    App::new()
+        .insert_resource(Msaa::Sample4)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(LookTransformPlugin::default())
+        .add_plugins(FpsCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, look_at_trigger_system)
+        .run();
+}
+
+/// Lights up when the camera looks at it.
+#[derive(Component)]
+struct LookAtTrigger {
+    threshold_degrees: f32,
+    idle: Handle<StandardMaterial>,
+    triggered: Handle<StandardMaterial>,
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Plane {
+            size: 5.0,
+            subdivisions: 4,
+        })),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..Default::default()
+    });
+
+    // cubes that light up when looked at
+    for x in [-1.5, 0.0, 1.5] {
+        let idle = materials.add(Color::rgb(0.8, 0.7, 0.6).into());
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+                material: idle.clone(),
+                transform: Transform::from_xyz(x, 0.5, 0.0),
+                ..Default::default()
+            },
+            LookAtTrigger {
+                threshold_degrees: 5.0,
+                idle,
+                triggered: materials.add(Color::rgb(1.0, 0.8, 0.1).into()),
+            },
+        ));
+    }
+
+    // light
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..Default::default()
+    });
+
+    commands
+        .spawn(Camera3dBundle::default())
+        .insert(FpsCameraBundle::new(
+            FpsCameraController::default(),
+            Vec3::new(-2.0, 5.0, 5.0),
+            Vec3::new(0., 0., 0.),
+            Vec3::Y,
+        ));
+}
+
+fn look_at_trigger_system(
+    cameras: Query<&LookTransform>,
+    mut triggers: Query<(&LookAtTrigger, &Transform, &mut Handle<StandardMaterial>)>,
+) {
+    let Ok(camera) = cameras.get_single() else {
+        return;
+    };
+
+    for (trigger, transform, mut material) in triggers.iter_mut() {
+        let looking = camera.is_looking_at(transform.translation, trigger.threshold_degrees);
+        let wanted = if looking {
+            &trigger.triggered
+        } else {
+            &trigger.idle
+        };
+        if *material != *wanted {
+            *material = wanted.clone();
+        }
+    }
+}
@@ -8,7 +8,7 @@ fn main() {
     App::new()
         .insert_resource(Msaa::Sample4)
         .add_plugins(DefaultPlugins)
-        .add_plugins(LookTransformPlugin)
+        .add_plugins(LookTransformPlugin::default())
         .add_plugins(FpsCameraPlugin::default())
         .add_systems(Startup, setup)
         .run();
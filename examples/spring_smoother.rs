@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use smooth_bevy_cameras::{
+    LookTransform, LookTransformBundle, LookTransformPlugin, Smoother, SpringSmoother,
+};
+
+/// Press Space to switch between the exponential `Smoother` and the `SpringSmoother`. The camera target jumps between two
+/// cubes every couple of seconds so the difference is easy to see.
+fn main() {
+    App::new()
+        .insert_resource(Msaa::Sample4)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(LookTransformPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (jump_target, toggle_smoother))
+        .run();
+}
+
+const TARGETS: [Vec3; 2] = [Vec3::new(-1.5, 0.5, 0.0), Vec3::new(1.5, 0.5, 0.0)];
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Plane {
+            size: 5.0,
+            subdivisions: 4,
+        })),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..Default::default()
+    });
+
+    // cubes
+    for target in TARGETS {
+        commands.spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            transform: Transform::from_translation(target),
+            ..Default::default()
+        });
+    }
+
+    // light
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..Default::default()
+    });
+
+    let eye = Vec3::new(0.0, 2.5, 5.0);
+    commands
+        .spawn(LookTransformBundle {
+            transform: LookTransform::new(eye, TARGETS[0], Vec3::Y),
+            smoother: Smoother::new(0.9),
+        })
+        .insert(Camera3dBundle {
+            transform: Transform::from_translation(eye).looking_at(TARGETS[0], Vec3::Y),
+            ..default()
+        });
+}
+
+fn jump_target(time: Res<Time>, mut cameras: Query<&mut LookTransform>) {
+    let index = (time.elapsed_seconds() / 2.0) as usize % TARGETS.len();
+    for mut camera in cameras.iter_mut() {
+        camera.target = TARGETS[index];
+    }
+}
+
+fn toggle_smoother(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    cameras: Query<(Entity, Has<Smoother>), With<LookTransform>>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    for (entity, has_smoother) in cameras.iter() {
+        let mut camera = commands.entity(entity);
+        if has_smoother {
+            info!("Using SpringSmoother");
+            camera
+                .remove::<Smoother>()
+                .insert(SpringSmoother::new(60.0, 8.0));
+        } else {
+            info!("Using Smoother");
+            camera.remove::<SpringSmoother>().insert(Smoother::new(0.9));
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{CameraTransition, LookTransformSystemSet};
+
+#[cfg(feature = "render")]
+use bevy::render::camera::Viewport;
+use bevy::{
+    app::prelude::*,
+    ecs::{prelude::*, system::SystemParam},
+    math::{prelude::*, Rect},
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+#[cfg(feature = "bevy_egui")]
+use bevy_egui::EguiContext;
+
+pub struct ActiveCameraPlugin;
+
+impl Plugin for ActiveCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ActiveCamera>()
+            .register_type::<CameraViewport>()
+            .register_type::<CameraInputFocus>()
+            .add_systems(
+                Update,
+                focus_camera_on_hover_system.before(LookTransformSystemSet::LookTransformInput),
+            );
+    }
+}
+
+/// Marks the camera that the built-in controllers respond to, e.g. the main view rather than a minimap.
+///
+/// This is optional: while no entity has an `ActiveCamera`, the controllers respond on every camera, as before.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct ActiveCamera;
+
+/// The part of the primary window a camera draws to, for split screen. The built-in controllers ignore the mouse on a
+/// camera with a `CameraViewport` while the cursor is outside of it, but keyboard and gamepad input still go to the
+/// [`ActiveCamera`].
+///
+/// Like the camera's own `Viewport`, the rectangle is in physical pixels from the top left of the window. With the
+/// `render` feature, it converts from a `Viewport`.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CameraViewport {
+    pub physical_position: UVec2,
+    pub physical_size: UVec2,
+}
+
+impl CameraViewport {
+    pub fn new(physical_position: UVec2, physical_size: UVec2) -> Self {
+        Self {
+            physical_position,
+            physical_size,
+        }
+    }
+
+    /// Whether a point, in physical pixels from the top left of the window, is inside the viewport.
+    pub fn contains(&self, physical_point: Vec2) -> bool {
+        let min = self.physical_position.as_vec2();
+        let max = min + self.physical_size.as_vec2();
+
+        physical_point.cmpge(min).all() && physical_point.cmplt(max).all()
+    }
+}
+
+#[cfg(feature = "render")]
+impl From<&Viewport> for CameraViewport {
+    fn from(viewport: &Viewport) -> Self {
+        Self::new(viewport.physical_position, viewport.physical_size)
+    }
+}
+
+/// For editor-like UIs with a viewport per panel: the built-in controllers only respond on a camera with a
+/// `CameraInputFocus` while it's `focused`, and only to the mouse while the cursor is inside its `hover_rect`, if it has
+/// one. This is on top of the [`ActiveCamera`] and [`CameraViewport`] checks.
+///
+/// [`focus_camera_on_hover_system`], added by the [`ActiveCameraPlugin`], focuses whichever camera the cursor is over.
+/// Without it, set `focused` yourself, e.g. when a panel is clicked, so the keyboard goes to that camera alone.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CameraInputFocus {
+    pub focused: bool,
+    /// The panel's rectangle, in physical pixels from the top left of the window like [`CameraViewport`]. When `None`,
+    /// the camera's `CameraViewport` is used for hovering instead, if it has one.
+    pub hover_rect: Option<Rect>,
+}
+
+impl CameraInputFocus {
+    pub fn new(hover_rect: Rect) -> Self {
+        Self {
+            focused: false,
+            hover_rect: Some(hover_rect),
+        }
+    }
+}
+
+/// Focuses each [`CameraInputFocus`] camera whose `hover_rect` or [`CameraViewport`] contains the cursor, and unfocuses
+/// the rest. While the cursor is outside the window, the focus stays where it was.
+pub fn focus_camera_on_hover_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut CameraInputFocus, Option<&CameraViewport>)>,
+) {
+    let Some(cursor) = windows
+        .get_single()
+        .ok()
+        .and_then(Window::physical_cursor_position)
+    else {
+        return;
+    };
+
+    for (mut focus, viewport) in cameras.iter_mut() {
+        let hovered = match (focus.hover_rect, viewport) {
+            (Some(rect), _) => rect.contains(cursor),
+            (None, Some(viewport)) => viewport.contains(cursor),
+            (None, None) => false,
+        };
+        if focus.focused != hovered {
+            focus.focused = hovered;
+        }
+    }
+}
+
+/// Stops the built-in controllers from responding to the mouse, e.g. while the cursor is over your own UI. This is the
+/// recommended way to integrate a UI: set it to `true` every frame the UI wants the mouse, before
+/// `LookTransformSystemSet::LookTransformInput`, and the `LookTransformPlugin` clears it again in `Last`.
+///
+/// The keyboard and gamepad are unaffected. With the `bevy_egui` feature, it's also set whenever egui wants the pointer.
+#[derive(Clone, Copy, Debug, Default, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct CameraInputLock(pub bool);
+
+pub fn clear_camera_input_lock_system(mut lock: ResMut<CameraInputLock>) {
+    lock.0 = false;
+}
+
+#[cfg(feature = "bevy_egui")]
+pub fn egui_camera_input_lock_system(
+    mut lock: ResMut<CameraInputLock>,
+    mut contexts: Query<&mut EguiContext, With<PrimaryWindow>>,
+) {
+    for mut context in contexts.iter_mut() {
+        if context.get_mut().wants_pointer_input() {
+            lock.0 = true;
+        }
+    }
+}
+
+/// Which cameras the built-in controllers should respond on. See [`ActiveCamera`], [`CameraViewport`],
+/// [`CameraInputFocus`], and [`CameraInputLock`].
+#[derive(SystemParam)]
+pub struct ActiveCameras<'w, 's> {
+    lock: Option<Res<'w, CameraInputLock>>,
+    active: Query<'w, 's, (), With<ActiveCamera>>,
+    viewports: Query<'w, 's, &'static CameraViewport>,
+    focus: Query<'w, 's, &'static CameraInputFocus>,
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+}
+
+impl<'w, 's> ActiveCameras<'w, 's> {
+    /// Whether `entity` should respond to input at all: it's the [`ActiveCamera`], or there isn't one, and it doesn't
+    /// have an unfocused [`CameraInputFocus`].
+    pub fn contains(&self, entity: Entity) -> bool {
+        let active = self.active.is_empty() || self.active.contains(entity);
+
+        active && self.focus.get(entity).map_or(true, |focus| focus.focused)
+    }
+
+    /// Whether `entity` should respond to the mouse: the [`CameraInputLock`] isn't set, and the cursor is inside its
+    /// [`CameraViewport`] and [`CameraInputFocus`] `hover_rect`, when it has them.
+    pub fn has_cursor(&self, entity: Entity) -> bool {
+        if self.lock.as_deref().is_some_and(|lock| lock.0) {
+            return false;
+        }
+        let viewport = self.viewports.get(entity).ok();
+        let hover_rect = self
+            .focus
+            .get(entity)
+            .ok()
+            .and_then(|focus| focus.hover_rect);
+        if viewport.is_none() && hover_rect.is_none() {
+            return true;
+        }
+
+        self.windows
+            .get_single()
+            .ok()
+            .and_then(Window::physical_cursor_position)
+            .is_some_and(|cursor| {
+                viewport.map_or(true, |viewport| viewport.contains(cursor))
+                    && hover_rect.map_or(true, |rect| rect.contains(cursor))
+            })
+    }
+}
+
+/// A system that moves the [`ActiveCamera`] marker to `entity`, e.g.
+/// `app.add_systems(OnEnter(GameState::Map), set_active_camera(map_camera))`.
+pub fn set_active_camera(
+    entity: Entity,
+) -> impl FnMut(Commands, Query<Entity, With<ActiveCamera>>) {
+    move |mut commands, active| {
+        for other in active.iter().filter(|&e| e != entity) {
+            commands.entity(other).remove::<ActiveCamera>();
+        }
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(ActiveCamera);
+        }
+    }
+}
+
+/// Stops every built-in controller, and any [`CameraController`](crate::CameraController), from moving the camera, e.g.
+/// during a cutscene or while a menu is open, without touching each controller's `enabled` flag. `CameraCommand`s and
+/// `CameraTransition`s still move it.
+///
+/// Insert and remove it with [`CameraCommandsExt::disable_all`] and [`CameraCommandsExt::enable_all`].
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct AllCameraControllersDisabled;
+
+/// The query filter controllers use for the cameras they may move: not in a [`CameraTransition`] and not
+/// [`AllCameraControllersDisabled`].
+pub type ControllableCamera = (
+    Without<CameraTransition>,
+    Without<AllCameraControllersDisabled>,
+);
+
+/// Camera shortcuts for `Commands`, e.g. `commands.disable_all(camera)` when a cutscene starts.
+pub trait CameraCommandsExt {
+    /// Inserts [`AllCameraControllersDisabled`] on `entity`, if it exists.
+    fn disable_all(&mut self, entity: Entity);
+    /// Removes [`AllCameraControllersDisabled`] from `entity`, if it exists.
+    fn enable_all(&mut self, entity: Entity);
+}
+
+impl CameraCommandsExt for Commands<'_, '_> {
+    fn disable_all(&mut self, entity: Entity) {
+        if let Some(mut entity) = self.get_entity(entity) {
+            entity.insert(AllCameraControllersDisabled);
+        }
+    }
+
+    fn enable_all(&mut self, entity: Entity) {
+        if let Some(mut entity) = self.get_entity(entity) {
+            entity.remove::<AllCameraControllersDisabled>();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::{
+        ecs::system::{RunSystemOnce, SystemState},
+        math::DVec2,
+    };
+
+    #[test]
+    fn test_set_active_camera() {
+        let mut app = App::new();
+        let main = app.world.spawn(ActiveCamera).id();
+        let minimap = app.world.spawn_empty().id();
+
+        let mut state: SystemState<ActiveCameras> = SystemState::new(&mut app.world);
+        assert!(state.get(&app.world).contains(main));
+        assert!(!state.get(&app.world).contains(minimap));
+
+        app.add_systems(Update, set_active_camera(minimap));
+        app.update();
+        assert!(app.world.get::<ActiveCamera>(main).is_none());
+        assert!(state.get(&app.world).contains(minimap));
+
+        app.world.entity_mut(minimap).remove::<ActiveCamera>();
+        assert!(state.get(&app.world).contains(main));
+
+        // The right half of the window.
+        let mut window = Window::default();
+        window.resolution.set_physical_resolution(200, 100);
+        window.set_physical_cursor_position(Some(DVec2::new(50.0, 50.0)));
+        app.world.spawn((window, PrimaryWindow));
+        app.world.entity_mut(minimap).insert(CameraViewport::new(
+            UVec2::new(100, 0),
+            UVec2::new(100, 100),
+        ));
+        assert!(state.get(&app.world).has_cursor(main));
+        assert!(!state.get(&app.world).has_cursor(minimap));
+
+        // The lock stops every camera from responding to the mouse.
+        app.world.insert_resource(CameraInputLock(true));
+        assert!(!state.get(&app.world).has_cursor(main));
+    }
+
+    #[test]
+    fn test_input_focus_follows_hover() {
+        let mut app = App::new();
+        app.add_systems(Update, focus_camera_on_hover_system);
+        let mut window = Window::default();
+        window.resolution.set_physical_resolution(200, 100);
+        window.set_physical_cursor_position(Some(DVec2::new(150.0, 50.0)));
+        let window = app.world.spawn((window, PrimaryWindow)).id();
+        let left = app
+            .world
+            .spawn(CameraInputFocus::new(Rect::new(0.0, 0.0, 100.0, 100.0)))
+            .id();
+        let right = app
+            .world
+            .spawn((
+                CameraInputFocus::default(),
+                CameraViewport::new(UVec2::new(100, 0), UVec2::new(100, 100)),
+            ))
+            .id();
+        app.update();
+
+        let mut state: SystemState<ActiveCameras> = SystemState::new(&mut app.world);
+        assert!(!state.get(&app.world).contains(left));
+        assert!(state.get(&app.world).contains(right));
+        assert!(state.get(&app.world).has_cursor(right));
+
+        // Focused without the hover system, but the mouse is elsewhere.
+        app.world.get_mut::<CameraInputFocus>(left).unwrap().focused = true;
+        assert!(state.get(&app.world).contains(left));
+        assert!(!state.get(&app.world).has_cursor(left));
+
+        let mut window = app.world.get_mut::<Window>(window).unwrap();
+        window.set_physical_cursor_position(Some(DVec2::new(50.0, 50.0)));
+        app.update();
+        assert!(state.get(&app.world).has_cursor(left));
+        assert!(!state.get(&app.world).contains(right));
+    }
+
+    #[test]
+    fn test_disable_all_stops_controllers() {
+        use crate::controllers::orbit::{control_system, ControlEvent, OrbitCameraController};
+        use crate::LookTransform;
+        use bevy::time::Time;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((OrbitCameraController::default(), start))
+            .id();
+        let zoom = |app: &mut App| {
+            app.world.send_event(ControlEvent::Zoom(0.5));
+            app.update();
+            app.world.get::<LookTransform>(camera).unwrap().radius()
+        };
+
+        app.world
+            .run_system_once(move |mut commands: Commands| commands.disable_all(camera));
+        assert_eq!(zoom(&mut app), 10.0);
+
+        app.world
+            .run_system_once(move |mut commands: Commands| commands.enable_all(camera));
+        assert_eq!(zoom(&mut app), 5.0);
+    }
+}
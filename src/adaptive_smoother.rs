@@ -0,0 +1,117 @@
+use crate::{LookTransform, Smoother};
+
+use bevy::{ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time};
+
+/// Adjusts a camera's `Smoother` lag weight every frame based on how fast its `LookTransform` is moving, so the camera
+/// responds snappily to fast input while still trailing smoothly at low speeds.
+///
+/// The speed is the faster of the `eye` and `target`, in units per second. At or below `low_speed_threshold`, the lag
+/// weight is `max_lag`; at or above `high_speed_threshold`, it's `min_lag`; and in between it's linearly interpolated.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct AdaptiveSmoother {
+    pub min_lag: f32,
+    pub max_lag: f32,
+    pub high_speed_threshold: f32,
+    pub low_speed_threshold: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_transform: Option<LookTransform>,
+}
+
+impl Default for AdaptiveSmoother {
+    fn default() -> Self {
+        Self::new(0.5, 0.9, 20.0, 1.0)
+    }
+}
+
+impl AdaptiveSmoother {
+    pub fn new(
+        min_lag: f32,
+        max_lag: f32,
+        high_speed_threshold: f32,
+        low_speed_threshold: f32,
+    ) -> Self {
+        Self {
+            min_lag,
+            max_lag,
+            high_speed_threshold,
+            low_speed_threshold,
+            last_transform: None,
+        }
+    }
+
+    /// The lag weight for a camera moving at `speed` units per second.
+    pub fn lag_weight(&self, speed: f32) -> f32 {
+        let range = self.high_speed_threshold - self.low_speed_threshold;
+        let t = if range > 0.0 {
+            ((speed - self.low_speed_threshold) / range).clamp(0.0, 1.0)
+        } else if speed >= self.high_speed_threshold {
+            1.0
+        } else {
+            0.0
+        };
+
+        self.max_lag + (self.min_lag - self.max_lag) * t
+    }
+}
+
+pub fn adaptive_smoother_system(
+    time: Res<Time>,
+    mut cameras: Query<(&LookTransform, &mut AdaptiveSmoother, &mut Smoother)>,
+) {
+    let dt = time.delta_seconds();
+    for (transform, mut adaptive, mut smoother) in cameras.iter_mut() {
+        let last = adaptive.last_transform.replace(*transform);
+        let (Some(last), true) = (last, dt > 0.0) else {
+            continue;
+        };
+
+        let distance = transform
+            .eye
+            .distance(last.eye)
+            .max(transform.target.distance(last.target));
+        smoother.set_lag_weight(adaptive.lag_weight(distance / dt));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::app::prelude::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_fast_movement_lowers_lag() {
+        let adaptive = AdaptiveSmoother::new(0.2, 0.8, 10.0, 2.0);
+        assert_relative_eq!(adaptive.lag_weight(0.0), 0.8);
+        assert_relative_eq!(adaptive.lag_weight(6.0), 0.5);
+        assert_relative_eq!(adaptive.lag_weight(100.0), 0.2);
+
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(100));
+        app.insert_resource(time)
+            .add_systems(Update, adaptive_smoother_system);
+        let camera = app
+            .world
+            .spawn((LookTransform::default(), adaptive, Smoother::new(0.5)))
+            .id();
+        app.update();
+
+        // 0.6 units in 0.1 seconds.
+        app.world.get_mut::<LookTransform>(camera).unwrap().eye.x += 0.6;
+        app.update();
+        let smoother = app.world.get::<Smoother>(camera).unwrap();
+        assert_relative_eq!(smoother.lag_weight(), 0.5, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{CameraTransition, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    animation::{AnimationClip, EntityPath, Keyframes, VariableCurve},
+    app::prelude::*,
+    asset::prelude::*,
+    core::Name,
+    ecs::prelude::*,
+    math::Vec3,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+};
+
+/// Moves [`BevyAnimationCameraDriver`] cameras along their `AnimationClip`s.
+pub struct BevyAnimationCameraDriverPlugin;
+
+impl Plugin for BevyAnimationCameraDriverPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BevyAnimationCameraDriver>()
+            .add_systems(
+                Update,
+                animation_camera_driver_system.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+    }
+}
+
+/// Drives the camera's `LookTransform` from an `AnimationClip`, e.g. a camera path animated in Blender and exported as
+/// glTF.
+///
+/// The translation of the clip's `camera/eye` bone becomes the `eye`, and the translation of its `camera/target` bone
+/// becomes the `target`; either may be left out to keep the current one. When a non-looping clip ends, this component is
+/// removed.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct BevyAnimationCameraDriver {
+    pub clip: Handle<AnimationClip>,
+    /// Seconds of the clip to play per second.
+    pub speed: f32,
+    pub elapsed: f32,
+    /// If `true`, the clip restarts from the beginning when it ends, forever.
+    pub looping: bool,
+}
+
+impl Default for BevyAnimationCameraDriver {
+    fn default() -> Self {
+        Self::new(Handle::default())
+    }
+}
+
+impl BevyAnimationCameraDriver {
+    /// The path of the bone whose translation drives the `eye`.
+    pub const EYE_PATH: [&'static str; 2] = ["camera", "eye"];
+    /// The path of the bone whose translation drives the `target`.
+    pub const TARGET_PATH: [&'static str; 2] = ["camera", "target"];
+
+    pub fn new(clip: Handle<AnimationClip>) -> Self {
+        Self {
+            clip,
+            speed: 1.0,
+            elapsed: 0.0,
+            looping: false,
+        }
+    }
+
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// The `LookTransform` `seconds` into `clip`, starting from `transform` for any missing bones.
+    pub fn sample(clip: &AnimationClip, seconds: f32, transform: LookTransform) -> LookTransform {
+        let translation = |path: [&'static str; 2]| {
+            let path = EntityPath {
+                parts: path.into_iter().map(Name::new).collect(),
+            };
+            clip.get_curves_by_path(&path)
+                .and_then(|curves| sample_translation(curves, seconds))
+        };
+
+        LookTransform {
+            eye: translation(Self::EYE_PATH).unwrap_or(transform.eye),
+            target: translation(Self::TARGET_PATH).unwrap_or(transform.target),
+            ..transform
+        }
+    }
+}
+
+/// Linearly interpolates the first translation curve, holding its first and last keyframes outside of its time range.
+fn sample_translation(curves: &[VariableCurve], seconds: f32) -> Option<Vec3> {
+    curves.iter().find_map(|curve| {
+        let Keyframes::Translation(keyframes) = &curve.keyframes else {
+            return None;
+        };
+        let times = &curve.keyframe_timestamps;
+        let next = times
+            .partition_point(|&t| t <= seconds)
+            .min(keyframes.len());
+        match (next.checked_sub(1), keyframes.get(next)) {
+            (Some(prev), Some(&to)) => {
+                let s = (seconds - times[prev]) / (times[next] - times[prev]);
+                Some(keyframes[prev].lerp(to, s))
+            }
+            (Some(prev), None) => keyframes.get(prev).copied(),
+            (None, first) => first.copied(),
+        }
+    })
+}
+
+pub fn animation_camera_driver_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    clips: Res<Assets<AnimationClip>>,
+    mut cameras: Query<
+        (Entity, &mut BevyAnimationCameraDriver, &mut LookTransform),
+        Without<CameraTransition>,
+    >,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut driver, mut transform) in cameras.iter_mut() {
+        // The clip may still be loading.
+        let Some(clip) = clips.get(&driver.clip) else {
+            continue;
+        };
+        let duration = clip.duration();
+
+        driver.elapsed += driver.speed * dt;
+        let finished = !driver.looping && driver.elapsed >= duration;
+        let seconds = if driver.looping && duration > 0.0 {
+            driver.elapsed.rem_euclid(duration)
+        } else {
+            driver.elapsed.min(duration)
+        };
+
+        let sample = BevyAnimationCameraDriver::sample(clip, seconds, *transform);
+        if sample != *transform {
+            *transform = sample;
+        }
+
+        if finished {
+            commands
+                .entity(entity)
+                .remove::<BevyAnimationCameraDriver>();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    fn path(parts: [&'static str; 2]) -> EntityPath {
+        EntityPath {
+            parts: parts.into_iter().map(Name::new).collect(),
+        }
+    }
+
+    #[test]
+    fn test_drives_eye_and_target_from_clip() {
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            path(BevyAnimationCameraDriver::EYE_PATH),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 2.0],
+                keyframes: Keyframes::Translation(vec![Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0)]),
+            },
+        );
+        clip.add_curve_to_path(
+            path(BevyAnimationCameraDriver::TARGET_PATH),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0],
+                keyframes: Keyframes::Translation(vec![Vec3::NEG_Z]),
+            },
+        );
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Assets<AnimationClip>>()
+            .add_systems(Update, animation_camera_driver_system);
+        let clip = app.world.resource_mut::<Assets<AnimationClip>>().add(clip);
+        let camera = app
+            .world
+            .spawn((
+                BevyAnimationCameraDriver::new(clip),
+                LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y),
+            ))
+            .id();
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            app.update();
+        };
+
+        step(&mut app);
+        let transform = *app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(transform.eye, Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(transform.target, Vec3::NEG_Z);
+
+        for _ in 0..4 {
+            step(&mut app);
+        }
+        let transform = *app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(transform.eye, Vec3::new(4.0, 0.0, 0.0));
+        assert!(app.world.get::<BevyAnimationCameraDriver>(camera).is_none());
+    }
+}
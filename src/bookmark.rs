@@ -0,0 +1,162 @@
+use crate::{ActiveCameras, CameraCommand, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, input::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+};
+
+/// Saves and restores [`CameraBookmarks`] with the keys in the [`CameraBookmarkConfig`] resource.
+pub struct CameraBookmarkPlugin;
+
+impl Plugin for CameraBookmarkPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraBookmarks>()
+            .register_type::<CameraBookmarkConfig>()
+            .init_resource::<CameraBookmarks>()
+            .init_resource::<CameraBookmarkConfig>()
+            .add_event::<CameraCommand>()
+            .add_systems(
+                Update,
+                camera_bookmark_system.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+    }
+}
+
+/// Up to 9 saved camera positions, like the numbered views of a 3D modelling tool.
+///
+/// Slots are numbered 0 to 8; others are ignored. With the `serde` feature, this can be saved along with a project to
+/// keep its views.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Resource, Default, Debug)]
+pub struct CameraBookmarks {
+    pub slots: [Option<LookTransform>; 9],
+}
+
+impl CameraBookmarks {
+    pub fn save(&mut self, slot: usize, transform: LookTransform) {
+        if let Some(saved) = self.slots.get_mut(slot) {
+            *saved = Some(transform);
+        }
+    }
+
+    pub fn restore(&self, slot: usize) -> Option<LookTransform> {
+        self.slots.get(slot).copied().flatten()
+    }
+}
+
+/// The keys for each of the [`CameraBookmarks`] slots. Press one with `save_modifier` held to save the active camera
+/// there, or alone to move the cameras back to it.
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct CameraBookmarkConfig {
+    pub slot_keys: [KeyCode; 9],
+    pub save_modifier: KeyCode,
+    /// How many seconds the `CameraCommand::Transition` to a restored bookmark lasts.
+    pub transition_duration: f32,
+    pub enabled: bool,
+}
+
+impl Default for CameraBookmarkConfig {
+    fn default() -> Self {
+        Self {
+            slot_keys: [
+                KeyCode::Key1,
+                KeyCode::Key2,
+                KeyCode::Key3,
+                KeyCode::Key4,
+                KeyCode::Key5,
+                KeyCode::Key6,
+                KeyCode::Key7,
+                KeyCode::Key8,
+                KeyCode::Key9,
+            ],
+            save_modifier: KeyCode::ControlLeft,
+            transition_duration: 0.5,
+            enabled: true,
+        }
+    }
+}
+
+pub fn camera_bookmark_system(
+    config: Res<CameraBookmarkConfig>,
+    keyboard: Res<Input<KeyCode>>,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    mut commands: EventWriter<CameraCommand>,
+    cameras: Query<(Entity, &LookTransform)>,
+    active_cameras: ActiveCameras,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let saving = keyboard.pressed(config.save_modifier);
+    for (slot, &key) in config.slot_keys.iter().enumerate() {
+        if !keyboard.just_pressed(key) {
+            continue;
+        }
+        if saving {
+            if let Some((_, transform)) = cameras
+                .iter()
+                .find(|(entity, _)| active_cameras.contains(*entity))
+            {
+                bookmarks.save(slot, *transform);
+            }
+        } else if let Some(to) = bookmarks.restore(slot) {
+            commands.send(CameraCommand::Transition {
+                to,
+                duration: config.transition_duration,
+            });
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_save_and_restore_with_keys() {
+        let config = CameraBookmarkConfig::default();
+        let saved = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        app.init_resource::<Input<KeyCode>>()
+            .add_plugins(CameraBookmarkPlugin);
+        app.world.spawn(saved);
+
+        let mut keyboard = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard.press(config.save_modifier);
+        keyboard.press(config.slot_keys[2]);
+        app.update();
+        assert_eq!(
+            app.world.resource::<CameraBookmarks>().restore(2),
+            Some(saved)
+        );
+        assert_eq!(app.world.resource::<CameraBookmarks>().restore(9), None);
+
+        let mut keyboard = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard.clear();
+        keyboard.release_all();
+        keyboard.press(config.slot_keys[2]);
+        app.update();
+
+        let commands = app.world.resource::<Events<CameraCommand>>();
+        let sent: Vec<_> = commands.get_reader().read(commands).copied().collect();
+        assert_eq!(
+            sent,
+            [CameraCommand::Transition {
+                to: saved,
+                duration: config.transition_duration,
+            }]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{ActiveCameras, CameraTransition, LookTransform};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, input::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    time::Time,
+};
+use std::collections::VecDeque;
+
+/// Records [`CameraHistory`] and binds Ctrl+Z to undo and Ctrl+Y or Ctrl+Shift+Z to redo.
+#[derive(Default)]
+pub struct CameraHistoryPlugin {
+    pub override_input_system: bool,
+}
+
+impl CameraHistoryPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for CameraHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<CameraHistory>()
+            .add_systems(Update, record_camera_history_system);
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                camera_history_input_map.before(record_camera_history_system),
+            );
+        }
+    }
+}
+
+/// An undo/redo stack of camera positions, for interactive viewers.
+///
+/// A position is recorded when `record_key` is pressed, or once the camera has rested for `idle_timeout` seconds.
+/// Recording after an undo discards the positions that could have been redone.
+#[derive(Clone, Component, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraHistory {
+    stack: VecDeque<LookTransform>,
+    /// The position in `stack` of the current entry.
+    index: usize,
+    /// The oldest entries are forgotten beyond this many.
+    pub max_size: usize,
+    pub record_key: Option<KeyCode>,
+    pub idle_timeout: Option<f32>,
+    /// Positions within this `LookTransform::distance_to` of the current entry aren't recorded, so resting the camera
+    /// again after a nudge doesn't fill the history.
+    pub min_distance: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    idle_time: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_seen: Option<LookTransform>,
+}
+
+impl Default for CameraHistory {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl CameraHistory {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            stack: VecDeque::new(),
+            index: 0,
+            max_size,
+            record_key: None,
+            idle_timeout: Some(0.5),
+            min_distance: 0.01,
+            idle_time: 0.0,
+            last_seen: None,
+        }
+    }
+
+    /// Makes `transform` the current entry, unless the current entry is within `min_distance` of it.
+    pub fn record(&mut self, transform: LookTransform) {
+        if self
+            .current()
+            .is_some_and(|current| current.is_near(&transform, self.min_distance))
+        {
+            return;
+        }
+
+        if !self.stack.is_empty() {
+            self.stack.truncate(self.index + 1);
+        }
+        self.stack.push_back(transform);
+        while self.stack.len() > self.max_size.max(1) {
+            self.stack.pop_front();
+        }
+        self.index = self.stack.len() - 1;
+    }
+
+    pub fn current(&self) -> Option<LookTransform> {
+        self.stack.get(self.index).copied()
+    }
+
+    /// Steps back to the previous entry and returns it, for the caller to apply.
+    pub fn undo(&mut self) -> Option<LookTransform> {
+        if self.index == 0 {
+            return None;
+        }
+        self.index -= 1;
+        self.current()
+    }
+
+    /// Steps forward to the entry most recently undone and returns it, for the caller to apply.
+    pub fn redo(&mut self) -> Option<LookTransform> {
+        if self.index + 1 >= self.stack.len() {
+            return None;
+        }
+        self.index += 1;
+        self.current()
+    }
+
+    pub fn clear(&mut self) {
+        self.stack.clear();
+        self.index = 0;
+    }
+}
+
+pub fn record_camera_history_system(
+    time: Res<Time>,
+    keyboard: Res<Input<KeyCode>>,
+    mut cameras: Query<(&LookTransform, &mut CameraHistory)>,
+) {
+    let dt = time.delta_seconds();
+    for (transform, mut history) in cameras.iter_mut() {
+        if history.last_seen == Some(*transform) {
+            history.idle_time += dt;
+        } else {
+            history.last_seen = Some(*transform);
+            history.idle_time = 0.0;
+        }
+
+        let key_pressed = history.record_key.is_some_and(|k| keyboard.just_pressed(k));
+        let rested = history
+            .idle_timeout
+            .is_some_and(|timeout| history.idle_time >= timeout);
+        if key_pressed || rested {
+            history.record(*transform);
+        }
+    }
+}
+
+pub fn camera_history_input_map(
+    keyboard: Res<Input<KeyCode>>,
+    mut cameras: Query<(Entity, &mut CameraHistory, &mut LookTransform), Without<CameraTransition>>,
+    active_cameras: ActiveCameras,
+) {
+    if !keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let undo = keyboard.just_pressed(KeyCode::Z) && !shift;
+    let redo = keyboard.just_pressed(KeyCode::Y) || (keyboard.just_pressed(KeyCode::Z) && shift);
+    if !undo && !redo {
+        return;
+    }
+
+    for (_, mut history, mut transform) in cameras
+        .iter_mut()
+        .filter(|(entity, _, _)| active_cameras.contains(*entity))
+    {
+        // Don't lose a position that hasn't rested long enough to be recorded.
+        if undo {
+            history.record(*transform);
+        }
+
+        let restored = if undo { history.undo() } else { history.redo() };
+        if let Some(restored) = restored {
+            *transform = restored;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::Vec3;
+
+    fn at(x: f32) -> LookTransform {
+        LookTransform::new(Vec3::new(x, 0.0, 5.0), Vec3::ZERO, Vec3::Y)
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = CameraHistory::new(3);
+        for x in 0..4 {
+            history.record(at(x as f32));
+        }
+
+        // The first entry was forgotten.
+        assert_eq!(history.undo(), Some(at(2.0)));
+        assert_eq!(history.undo(), Some(at(1.0)));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(at(2.0)));
+
+        // Recording discards the redo entries.
+        history.record(at(5.0));
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(at(2.0)));
+
+        // Nudges are ignored.
+        history.record(at(2.001));
+        assert_eq!(history.current(), Some(at(2.0)));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{CameraTransition, EasingFn, LookTransform};
+
+use bevy::{
+    app::prelude::*,
+    asset::{io::Reader, prelude::*, AssetLoader, AsyncReadExt, LoadContext},
+    ecs::prelude::*,
+    reflect::{Reflect, TypePath},
+    time::Time,
+    utils::BoxedFuture,
+};
+
+/// Loads [`CameraPath`] assets and moves [`CameraPathFollower`] cameras along them.
+pub struct CameraPathPlugin;
+
+impl Plugin for CameraPathPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CameraPath>()
+            .init_asset_loader::<CameraPathLoader>()
+            .register_type::<CameraPathFollower>()
+            .add_event::<CameraPathFinished>()
+            .add_systems(Update, camera_path_system);
+    }
+}
+
+/// A sequence of waypoints for a camera to travel through, e.g. for cinematics.
+///
+/// Loaded from `.camera_path.ron` (or `.campath.ron`) files that look like:
+///
+/// ```ron
+/// (
+///     waypoints: [
+///         (eye: (0.0, 2.0, 5.0), target: (0.0, 0.0, 0.0), up: (0.0, 1.0, 0.0)),
+///         (eye: (5.0, 2.0, 0.0), target: (0.0, 0.0, 0.0), up: (0.0, 1.0, 0.0)),
+///     ],
+///     looping: true,
+/// )
+/// ```
+#[derive(Asset, Clone, Debug, Default, TypePath, serde::Deserialize, serde::Serialize)]
+pub struct CameraPath {
+    pub waypoints: Vec<LookTransform>,
+    /// If `true`, the path continues from the last waypoint back to the first, forever.
+    #[serde(default)]
+    pub looping: bool,
+}
+
+impl CameraPath {
+    fn validate(self) -> Result<Self, CameraPathLoaderError> {
+        if self.waypoints.is_empty() {
+            return Err(CameraPathLoaderError::NoWaypoints);
+        }
+
+        Ok(self)
+    }
+
+    /// The number of segments between waypoints, including the closing segment of a looping path.
+    pub fn segment_count(&self) -> usize {
+        match self.waypoints.len() {
+            0 => 0,
+            n if self.looping => n,
+            n => n - 1,
+        }
+    }
+
+    /// The transform at `position` segments along the path, so `1.5` is halfway from the second waypoint to the third.
+    pub fn sample(&self, position: f32) -> Option<LookTransform> {
+        let first = *self.waypoints.first()?;
+        let segments = self.segment_count();
+        if segments == 0 {
+            return Some(first);
+        }
+
+        let position = position.clamp(0.0, segments as f32);
+        let index = (position.floor() as usize).min(segments - 1);
+        let from = self.waypoints[index];
+        let to = self.waypoints[(index + 1) % self.waypoints.len()];
+
+        Some(from.lerp(to, position - index as f32))
+    }
+}
+
+/// Moves the camera's `LookTransform` along a [`CameraPath`]. When a non-looping path ends, this component is removed and a
+/// [`CameraPathFinished`] event is sent.
+#[derive(Clone, Component, Debug, Default, Reflect)]
+#[reflect(Component, Debug)]
+pub struct CameraPathFollower {
+    pub path: Handle<CameraPath>,
+    /// How many segments to travel per second.
+    pub speed: f32,
+    pub elapsed: f32,
+    /// Eases each full traversal of the path (each lap, if it loops).
+    pub easing: EasingFn,
+}
+
+impl CameraPathFollower {
+    pub fn new(path: Handle<CameraPath>, speed: f32) -> Self {
+        Self {
+            path,
+            speed,
+            elapsed: 0.0,
+            easing: EasingFn::Linear,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Event)]
+pub struct CameraPathFinished {
+    pub entity: Entity,
+}
+
+pub fn camera_path_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    paths: Res<Assets<CameraPath>>,
+    mut finished: EventWriter<CameraPathFinished>,
+    mut cameras: Query<
+        (Entity, &mut CameraPathFollower, &mut LookTransform),
+        Without<CameraTransition>,
+    >,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut follower, mut transform) in cameras.iter_mut() {
+        // The path may still be loading.
+        let Some(path) = paths.get(&follower.path) else {
+            continue;
+        };
+        let segments = path.segment_count() as f32;
+
+        follower.elapsed += dt;
+        let laps = if segments > 0.0 && follower.speed > 0.0 {
+            follower.elapsed * follower.speed / segments
+        } else {
+            1.0
+        };
+        let lap_progress = if path.looping {
+            laps.fract()
+        } else {
+            laps.min(1.0)
+        };
+
+        if let Some(sample) = path.sample(follower.easing.ease(lap_progress) * segments) {
+            *transform = sample;
+        }
+
+        if !path.looping && laps >= 1.0 {
+            commands.entity(entity).remove::<CameraPathFollower>();
+            finished.send(CameraPathFinished { entity });
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CameraPathLoader;
+
+#[derive(Debug)]
+pub enum CameraPathLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    /// The path has no waypoints, so there's nothing for a camera to follow.
+    NoWaypoints,
+}
+
+impl std::fmt::Display for CameraPathLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not read camera path: {e}"),
+            Self::Ron(e) => write!(f, "could not parse camera path: {e}"),
+            Self::NoWaypoints => write!(f, "camera path has no waypoints"),
+        }
+    }
+}
+
+impl std::error::Error for CameraPathLoaderError {}
+
+impl AssetLoader for CameraPathLoader {
+    type Asset = CameraPath;
+    type Settings = ();
+    type Error = CameraPathLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<CameraPath, CameraPathLoaderError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(CameraPathLoaderError::Io)?;
+
+            let path: CameraPath =
+                ron::de::from_bytes(&bytes).map_err(CameraPathLoaderError::Ron)?;
+
+            path.validate()
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["camera_path.ron", "campath.ron"]
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::Vec3;
+
+    fn waypoint(x: f32) -> LookTransform {
+        LookTransform::new(Vec3::new(x, 1.0, 0.0), Vec3::new(x, 0.0, -1.0), Vec3::Y)
+    }
+
+    #[test]
+    fn test_sample_wraps_looping_path() {
+        let mut path = CameraPath {
+            waypoints: vec![waypoint(0.0), waypoint(2.0), waypoint(4.0)],
+            looping: false,
+        };
+        assert_eq!(path.segment_count(), 2);
+        assert_eq!(path.sample(0.5), Some(waypoint(1.0)));
+        assert_eq!(path.sample(2.0), Some(waypoint(4.0)));
+
+        path.looping = true;
+        assert_eq!(path.segment_count(), 3);
+        assert_eq!(path.sample(2.5), Some(waypoint(2.0)));
+    }
+
+    #[test]
+    fn test_parse_ron() {
+        let path: CameraPath = ron::from_str(
+            "(waypoints: [(eye: (0.0, 1.0, 0.0), target: (0.0, 0.0, -1.0), up: (0.0, 1.0, 0.0))])",
+        )
+        .unwrap();
+
+        assert_eq!(path.waypoints, vec![waypoint(0.0)]);
+        assert!(!path.looping);
+        assert!(path.validate().is_ok());
+
+        let empty: CameraPath = ron::from_str("(waypoints: [])").unwrap();
+        assert!(matches!(
+            empty.validate(),
+            Err(CameraPathLoaderError::NoWaypoints)
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{CameraTransition, LookTransform};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+};
+
+/// Records and plays back [`CameraRecorder`] cameras.
+pub struct CameraRecorderPlugin;
+
+impl Plugin for CameraRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraRecorder>()
+            .add_systems(Update, camera_recorder_system);
+    }
+}
+
+/// Records a camera's movement, e.g. flying through a scene for a trailer, and plays it back.
+///
+/// While `recording`, the `LookTransform` is saved every `record_interval` frames, with the seconds since recording
+/// started. While `playback`, the `LookTransform` is interpolated between the saved keyframes until the last one, then
+/// `playback` turns off. Either mode starts its clock on the first frame it's on; use `start_recording` and
+/// `start_playback` to switch between them.
+#[derive(Clone, Component, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraRecorder {
+    pub recording: bool,
+    pub playback: bool,
+    /// (seconds since the start, transform), in order.
+    pub keyframes: Vec<(f32, LookTransform)>,
+    /// The elapsed time of the app when the current recording or playback started.
+    pub start_time: f32,
+    /// Record a keyframe every this many frames.
+    pub record_interval: u32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    started: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    frames_since_keyframe: u32,
+}
+
+impl Default for CameraRecorder {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl CameraRecorder {
+    pub fn new(record_interval: u32) -> Self {
+        Self {
+            recording: false,
+            playback: false,
+            keyframes: Vec::new(),
+            start_time: 0.0,
+            record_interval,
+            started: false,
+            frames_since_keyframe: 0,
+        }
+    }
+
+    /// Forgets the keyframes and records new ones, starting on the next frame.
+    pub fn start_recording(&mut self) {
+        self.keyframes.clear();
+        self.recording = true;
+        self.playback = false;
+        self.started = false;
+    }
+
+    /// Plays the keyframes from the beginning, starting on the next frame.
+    pub fn start_playback(&mut self) {
+        self.recording = false;
+        self.playback = true;
+        self.started = false;
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+        self.playback = false;
+    }
+
+    /// The seconds between the first and last keyframes.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |&(t, _)| t)
+    }
+
+    /// The transform `t` seconds into the recording, interpolated between keyframes.
+    pub fn sample(&self, t: f32) -> Option<LookTransform> {
+        let next = self.keyframes.partition_point(|&(time, _)| time <= t);
+        match (
+            self.keyframes.get(next.wrapping_sub(1)),
+            self.keyframes.get(next),
+        ) {
+            (Some(&(t0, from)), Some(&(t1, to))) => Some(from.lerp(to, (t - t0) / (t1 - t0))),
+            (Some(&(_, last)), None) => Some(last),
+            (None, first) => first.map(|&(_, first)| first),
+        }
+    }
+
+    /// The keyframes as RON, for saving to a file.
+    #[cfg(feature = "camera_path")]
+    pub fn export_ron(&self) -> String {
+        ron::ser::to_string_pretty(&self.keyframes, ron::ser::PrettyConfig::default())
+            .expect("keyframes are always serializable")
+    }
+
+    /// Keyframes saved by `CameraRecorder::export_ron`, ready to play back.
+    #[cfg(feature = "camera_path")]
+    pub fn import_ron(&mut self, ron: &str) -> Result<(), ron::error::SpannedError> {
+        self.keyframes = ron::from_str(ron)?;
+        Ok(())
+    }
+}
+
+pub fn camera_recorder_system(
+    time: Res<Time>,
+    mut cameras: Query<(&mut CameraRecorder, &mut LookTransform), Without<CameraTransition>>,
+) {
+    let now = time.elapsed_seconds();
+    for (mut recorder, mut transform) in cameras.iter_mut() {
+        if !recorder.recording && !recorder.playback {
+            recorder.started = false;
+            continue;
+        }
+        if !recorder.started {
+            recorder.started = true;
+            recorder.start_time = now;
+            recorder.frames_since_keyframe = 0;
+        }
+        let elapsed = now - recorder.start_time;
+
+        if recorder.recording {
+            if recorder.keyframes.is_empty()
+                || recorder.frames_since_keyframe + 1 >= recorder.record_interval
+            {
+                recorder.keyframes.push((elapsed, *transform));
+                recorder.frames_since_keyframe = 0;
+            } else {
+                recorder.frames_since_keyframe += 1;
+            }
+        } else {
+            if let Some(sample) = recorder.sample(elapsed) {
+                *transform = sample;
+            }
+            if elapsed >= recorder.duration() {
+                recorder.playback = false;
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record_and_play_back() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, camera_recorder_system);
+        let mut recorder = CameraRecorder::new(2);
+        recorder.start_recording();
+        let camera = app.world.spawn((recorder, LookTransform::default())).id();
+        let step = |app: &mut App, x: f32| {
+            app.world.get_mut::<LookTransform>(camera).unwrap().eye.x = x;
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            app.update();
+        };
+
+        // Keyframes on every other frame.
+        for x in 0..5 {
+            step(&mut app, x as f32);
+        }
+        let recorder = app.world.get::<CameraRecorder>(camera).unwrap();
+        let times: Vec<_> = recorder.keyframes.iter().map(|&(t, _)| t).collect();
+        assert_eq!(times, [0.0, 1.0, 2.0]);
+
+        app.world
+            .get_mut::<CameraRecorder>(camera)
+            .unwrap()
+            .start_playback();
+        step(&mut app, 10.0);
+        assert_eq!(app.world.get::<LookTransform>(camera).unwrap().eye.x, 0.0);
+        step(&mut app, 10.0);
+        assert_relative_eq!(app.world.get::<LookTransform>(camera).unwrap().eye.x, 1.0);
+        for _ in 0..3 {
+            step(&mut app, 10.0);
+        }
+        assert_eq!(app.world.get::<LookTransform>(camera).unwrap().eye.x, 4.0);
+        assert!(!app.world.get::<CameraRecorder>(camera).unwrap().playback);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{LookTransform, Smoother};
+
+use bevy::math::prelude::*;
+
+/// A snapshot of a camera's position and smoothing, for saving and restoring it across sessions or scene transitions.
+///
+/// With the `serde` feature, this implements `Serialize` and `Deserialize`. Vectors are stored as plain arrays, so the
+/// format doesn't depend on how Bevy serializes its math types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraState {
+    pub eye: [f32; 3],
+    pub target: [f32; 3],
+    pub up: [f32; 3],
+    pub lag_weight: f32,
+}
+
+impl CameraState {
+    pub fn capture(look: &LookTransform, smoother: &Smoother) -> Self {
+        Self {
+            eye: look.eye.to_array(),
+            target: look.target.to_array(),
+            up: look.up.to_array(),
+            lag_weight: smoother.lag_weight(),
+        }
+    }
+
+    /// Moves the camera to the saved state. The smoother is reset to it too, so the camera doesn't animate from its old
+    /// position.
+    pub fn apply(&self, look: &mut LookTransform, smoother: &mut Smoother) {
+        *look = self.look_transform();
+        smoother.set_lag_weight(self.lag_weight);
+        smoother.reset_to(look);
+    }
+
+    pub fn look_transform(&self) -> LookTransform {
+        LookTransform::new(
+            Vec3::from_array(self.eye),
+            Vec3::from_array(self.target),
+            Vec3::from_array(self.up),
+        )
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_and_apply() {
+        let saved_look = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::ZERO, Vec3::Y);
+        let state = CameraState::capture(&saved_look, &Smoother::new(0.8));
+
+        let mut look = LookTransform::default();
+        let mut smoother = Smoother::new(0.5);
+        smoother.smooth_transform(&look, 0.1);
+        state.apply(&mut look, &mut smoother);
+
+        assert_eq!(look, saved_look);
+        assert_eq!(smoother.lag_weight(), 0.8);
+        // No animation from the old position.
+        assert_eq!(smoother.smooth_transform(&look, 0.1), saved_look);
+    }
+}
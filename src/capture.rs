@@ -0,0 +1,137 @@
+use bevy::{
+    app::prelude::*,
+    asset::{Assets, Handle},
+    ecs::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::{
+        camera::{Camera, CameraUpdateSystem, RenderTarget},
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+        texture::Image,
+    },
+};
+
+/// Sets up [`CameraCapture`]s.
+pub struct CameraCapturePlugin;
+
+impl Plugin for CameraCapturePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraCapture>().add_systems(
+            PostUpdate,
+            setup_camera_capture_system.before(CameraUpdateSystem),
+        );
+    }
+}
+
+/// Renders a camera to an image instead of the window, e.g. for a minimap or a security camera screen.
+///
+/// Spawn it on a camera with a `CameraCapture::new(width, height)`, and [`setup_camera_capture_system`] creates a
+/// `width` by `height` render texture, stores it in `target_image`, and points the camera's `RenderTarget` at it. If
+/// `target_image` is already an image in `Assets<Image>`, that image is drawn to instead. Afterwards, the component stays
+/// on the camera, so the image can be shown in the UI with `UiImage::new(capture.target_image.clone())` or on a mesh
+/// with a `StandardMaterial`.
+///
+/// Give the capture camera a lower `Camera::order` than the main camera, so its image is ready before the main camera
+/// draws it.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct CameraCapture {
+    pub target_image: Handle<Image>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for CameraCapture {
+    fn default() -> Self {
+        Self::new(512, 512)
+    }
+}
+
+impl CameraCapture {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            target_image: Handle::default(),
+            width,
+            height,
+        }
+    }
+
+    /// An empty image to render to, `width` by `height`.
+    pub fn render_texture(&self) -> Image {
+        let size = Extent3d {
+            width: self.width.max(1),
+            height: self.height.max(1),
+            depth_or_array_layers: 1,
+        };
+        let mut image = Image {
+            texture_descriptor: TextureDescriptor {
+                label: Some("camera_capture"),
+                size,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Bgra8UnormSrgb,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            },
+            ..Default::default()
+        };
+        // Fills the image with zeros.
+        image.resize(size);
+
+        image
+    }
+}
+
+pub fn setup_camera_capture_system(
+    mut images: ResMut<Assets<Image>>,
+    mut cameras: Query<(&mut CameraCapture, &mut Camera), Added<CameraCapture>>,
+) {
+    for (mut capture, mut camera) in cameras.iter_mut() {
+        if !images.contains(&capture.target_image) {
+            capture.target_image = images.add(capture.render_texture());
+        }
+        camera.target = RenderTarget::Image(capture.target_image.clone());
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_renders_to_new_image() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Image>>()
+            .add_systems(Update, setup_camera_capture_system);
+        let camera = app
+            .world
+            .spawn((Camera::default(), CameraCapture::new(320, 200)))
+            .id();
+        app.update();
+
+        let capture = app.world.get::<CameraCapture>(camera).unwrap();
+        let image = app
+            .world
+            .resource::<Assets<Image>>()
+            .get(&capture.target_image)
+            .unwrap();
+        assert_eq!(image.width(), 320);
+        assert_eq!(image.height(), 200);
+        assert!(matches!(
+            &app.world.get::<Camera>(camera).unwrap().target,
+            RenderTarget::Image(handle) if *handle == capture.target_image
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{CameraTransition, EasingFn, LookTransform};
+
+use bevy::{ecs::prelude::*, math::prelude::*};
+
+/// Moves cameras without going through a controller's input map, e.g. from AI, scripting, or tests.
+///
+/// Commands apply to every entity with a `LookTransform`. Cameras in the middle of a [`CameraTransition`] ignore all
+/// commands except `Transition`, which starts the new transition from wherever the old one had got to.
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+pub enum CameraCommand {
+    /// Translates both the `eye` and the `target`.
+    Pan(Vec3),
+    /// Orbits the `eye` around the `target`, in radians, like [`LookTransform::orbit_by_delta`].
+    Orbit { delta_pitch: f32, delta_yaw: f32 },
+    /// Multiplies the radius.
+    Zoom(f32),
+    /// Moves the `eye`, keeping the `target`.
+    SetEye(Vec3),
+    /// Moves the `target`, keeping the `eye`.
+    SetTarget(Vec3),
+    /// Turns the camera to face a point, keeping the `eye` and the radius.
+    LookAt(Vec3),
+    /// Starts a linear `CameraTransition` to `to` lasting `duration` seconds.
+    Transition { to: LookTransform, duration: f32 },
+}
+
+impl CameraCommand {
+    /// Applies the command to `transform`. Does nothing for `Transition`, since that needs to insert a component.
+    pub fn apply(&self, transform: &mut LookTransform) {
+        match *self {
+            CameraCommand::Pan(delta) => *transform = transform.translate(delta),
+            CameraCommand::Orbit {
+                delta_pitch,
+                delta_yaw,
+            } => {
+                *transform = transform.orbit_by_delta(delta_pitch, delta_yaw);
+            }
+            CameraCommand::Zoom(scalar) => {
+                *transform = transform.with_radius(scalar * transform.radius());
+            }
+            CameraCommand::SetEye(eye) => transform.eye = eye,
+            CameraCommand::SetTarget(target) => transform.target = target,
+            CameraCommand::LookAt(point) => {
+                if let Some(dir) = (point - transform.eye).try_normalize() {
+                    transform.target = transform.eye + transform.radius() * dir;
+                }
+            }
+            CameraCommand::Transition { .. } => (),
+        }
+    }
+}
+
+pub fn apply_camera_commands(
+    mut commands: Commands,
+    mut events: EventReader<CameraCommand>,
+    mut cameras: Query<(Entity, &mut LookTransform, Option<&CameraTransition>)>,
+) {
+    for event in events.read() {
+        for (entity, mut transform, transition) in cameras.iter_mut() {
+            if let CameraCommand::Transition { to, duration } = *event {
+                let from = transition.map_or(*transform, CameraTransition::current);
+                commands.entity(entity).insert(CameraTransition::new(
+                    from,
+                    to,
+                    duration,
+                    EasingFn::Linear,
+                ));
+            } else if transition.is_none() {
+                event.apply(&mut transform);
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::app::prelude::*;
+
+    #[test]
+    fn test_commands_without_input() {
+        let mut app = App::new();
+        app.add_event::<CameraCommand>()
+            .add_systems(Update, apply_camera_commands);
+        let camera = app
+            .world
+            .spawn(LookTransform::new(
+                Vec3::new(0.0, 0.0, 5.0),
+                Vec3::ZERO,
+                Vec3::Y,
+            ))
+            .id();
+
+        app.world.send_event(CameraCommand::Pan(Vec3::X));
+        app.world.send_event(CameraCommand::Orbit {
+            delta_pitch: 0.0,
+            delta_yaw: std::f32::consts::FRAC_PI_2,
+        });
+        app.world.send_event(CameraCommand::Zoom(2.0));
+        app.update();
+
+        let transform = *app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(transform.target, Vec3::X);
+        assert_relative_eq!(transform.eye.x, 11.0, epsilon = 1e-4);
+        assert_relative_eq!(transform.eye.z, 0.0, epsilon = 1e-4);
+
+        let to = LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y);
+        app.world
+            .send_event(CameraCommand::Transition { to, duration: 1.0 });
+        app.update();
+
+        let transition = app.world.get::<CameraTransition>(camera).unwrap();
+        assert_eq!(transition.from, transform);
+        assert_eq!(transition.to, to);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{CameraTransition, LookTransform};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    transform::components::GlobalTransform,
+};
+
+/// Keeps a camera out of the floor, ceiling, and anything outside of the world.
+///
+/// The constraint is applied to the smoothed transform just before it's written to the `Transform`, so the
+/// `LookTransform` itself may still leave the bounds. Add it next to a [`LookTransformBundle`](crate::LookTransformBundle),
+/// or with [`LookTransformBundle::with_constraint`](crate::LookTransformBundle::with_constraint).
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CameraConstraint {
+    /// The lowest world Y coordinate of the `eye`. The `target` may go lower, e.g. to look at the ground.
+    pub floor: Option<f32>,
+    /// The highest world Y coordinate of the `eye`.
+    pub ceiling: Option<f32>,
+    /// Both the `eye` and `target` are kept inside this box.
+    pub world_bounds: Option<WorldBounds>,
+}
+
+/// An axis-aligned box in world space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct WorldBounds {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl WorldBounds {
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    pub fn clamp(&self, point: Vec3) -> Vec3 {
+        point.clamp(self.min, self.max)
+    }
+}
+
+impl CameraConstraint {
+    pub fn apply(&self, transform: LookTransform) -> LookTransform {
+        let mut eye = transform.eye;
+        let mut target = transform.target;
+
+        if let Some(bounds) = self.world_bounds {
+            eye = bounds.clamp(eye);
+            target = bounds.clamp(target);
+        }
+        if let Some(floor) = self.floor {
+            eye.y = eye.y.max(floor);
+        }
+        if let Some(ceiling) = self.ceiling {
+            eye.y = eye.y.min(ceiling);
+        }
+
+        LookTransform {
+            eye,
+            target,
+            ..transform
+        }
+    }
+}
+
+/// Keeps a camera facing another entity, e.g. for surveillance or cutscene cameras.
+///
+/// Every frame, the `target` of the `LookTransform` is moved to the entity's `GlobalTransform` plus `offset`, while the
+/// `eye` is left to the controller. This runs in [`LookTransformSystem::Sync`](crate::LookTransformSystem::Sync), after
+/// the controllers, so a `Smoother` still smooths the moving target. If the entity has no `GlobalTransform`, or it's
+/// gone, the `target` is left alone.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct LookAtConstraint {
+    pub target_entity: Entity,
+    /// Added to the entity's translation, in world space.
+    pub offset: Vec3,
+}
+
+impl Default for LookAtConstraint {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER)
+    }
+}
+
+impl LookAtConstraint {
+    pub fn new(target_entity: Entity) -> Self {
+        Self {
+            target_entity,
+            offset: Vec3::ZERO,
+        }
+    }
+}
+
+pub fn look_at_constraint_system(
+    targets: Query<&GlobalTransform>,
+    mut cameras: Query<(&LookAtConstraint, &mut LookTransform), Without<CameraTransition>>,
+) {
+    for (constraint, mut transform) in cameras.iter_mut() {
+        let Ok(target) = targets.get(constraint.target_entity) else {
+            continue;
+        };
+
+        let target = target.translation() + constraint.offset;
+        // Only write the `LookTransform` when it actually moves.
+        if transform.target != target {
+            transform.target = target;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::app::prelude::*;
+
+    #[test]
+    fn test_floor_and_bounds() {
+        let constraint = CameraConstraint {
+            floor: Some(1.0),
+            ceiling: None,
+            world_bounds: Some(WorldBounds::new(Vec3::splat(-10.0), Vec3::splat(10.0))),
+        };
+        let transform = LookTransform::new(
+            Vec3::new(20.0, -5.0, 0.0),
+            Vec3::new(0.0, -1.0, -30.0),
+            Vec3::Y,
+        );
+
+        let constrained = constraint.apply(transform);
+        assert_eq!(constrained.eye, Vec3::new(10.0, 1.0, 0.0));
+        assert_eq!(constrained.target, Vec3::new(0.0, -1.0, -10.0));
+        assert_eq!(constrained.up, Vec3::Y);
+    }
+
+    #[test]
+    fn test_look_at_follows_entity() {
+        let mut app = App::new();
+        app.add_systems(Update, look_at_constraint_system);
+
+        let entity = app
+            .world
+            .spawn(GlobalTransform::from_translation(Vec3::new(1.0, 2.0, 3.0)))
+            .id();
+        let eye = Vec3::new(0.0, 10.0, 0.0);
+        let camera = app
+            .world
+            .spawn((
+                LookAtConstraint {
+                    offset: Vec3::Y,
+                    ..LookAtConstraint::new(entity)
+                },
+                LookTransform::new(eye, Vec3::ZERO, Vec3::Y),
+            ))
+            .id();
+        app.update();
+
+        let transform = app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(transform.target, Vec3::new(1.0, 3.0, 3.0));
+        assert_eq!(transform.eye, eye);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::LookTransform;
+
+use bevy::{ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect};
+
+/// Mixes the `LookTransform`s of two other entities into this camera's, e.g. 80% of a scripted pan and 20% of the
+/// player looking around.
+///
+/// Each source is an entity with its own `LookTransform` and controller, marked [`Inactive`] so only this camera is
+/// drawn from. [`blend_camera_controllers_system`] interpolates between them linearly every frame, from `controller_a` at
+/// a `weight` of `0.0` to `controller_b` at `1.0`. The `weight` is a plain field, so anything can animate it. While
+/// either source is missing, the camera is left alone.
+///
+/// Remember that the built-in controllers only respond on the [`ActiveCamera`](crate::ActiveCamera), if there is one.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct BlendedCameraController {
+    pub controller_a: Entity,
+    pub controller_b: Entity,
+    pub weight: f32,
+}
+
+impl Default for BlendedCameraController {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
+impl BlendedCameraController {
+    /// Starts entirely on `controller_a`.
+    pub fn new(controller_a: Entity, controller_b: Entity) -> Self {
+        Self {
+            controller_a,
+            controller_b,
+            weight: 0.0,
+        }
+    }
+}
+
+/// Marks an entity whose `LookTransform` is still updated by its controller, but not written to its `Transform`, e.g. a
+/// source for a [`BlendedCameraController`].
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct Inactive;
+
+pub fn blend_camera_controllers_system(
+    sources: Query<&LookTransform, With<Inactive>>,
+    mut cameras: Query<(&BlendedCameraController, &mut LookTransform), Without<Inactive>>,
+) {
+    for (blend, mut transform) in cameras.iter_mut() {
+        let (Ok(a), Ok(b)) = (
+            sources.get(blend.controller_a),
+            sources.get(blend.controller_b),
+        ) else {
+            continue;
+        };
+
+        *transform = a.lerp(*b, blend.weight.clamp(0.0, 1.0));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{LookTransformBundle, LookTransformPlugin, Smoother};
+
+    use bevy::{app::prelude::*, math::prelude::*, time::Time, transform::components::Transform};
+
+    #[test]
+    fn test_blends_inactive_sources() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::default());
+        let source = |app: &mut App, eye: Vec3| {
+            app.world
+                .spawn((
+                    LookTransformBundle {
+                        transform: LookTransform::new(eye, Vec3::ZERO, Vec3::Y),
+                        smoother: Smoother::new(0.0),
+                    },
+                    Transform::default(),
+                    Inactive,
+                ))
+                .id()
+        };
+        let a = source(&mut app, Vec3::new(0.0, 0.0, 10.0));
+        let b = source(&mut app, Vec3::new(10.0, 0.0, 0.0));
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: LookTransform::default(),
+                    smoother: Smoother::new(0.0),
+                },
+                Transform::default(),
+                BlendedCameraController {
+                    weight: 0.2,
+                    ..BlendedCameraController::new(a, b)
+                },
+            ))
+            .id();
+        app.update();
+
+        let eye = Vec3::new(2.0, 0.0, 8.0);
+        assert!(app
+            .world
+            .get::<LookTransform>(camera)
+            .unwrap()
+            .eye
+            .abs_diff_eq(eye, 1e-5));
+        assert!(app
+            .world
+            .get::<Transform>(camera)
+            .unwrap()
+            .translation
+            .abs_diff_eq(eye, 1e-5));
+        // The sources aren't drawn from.
+        assert_eq!(
+            *app.world.get::<Transform>(a).unwrap(),
+            Transform::default()
+        );
+    }
+}
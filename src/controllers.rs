@@ -3,15 +3,41 @@ mod macros {
     #[macro_export]
     macro_rules! define_on_controller_enabled_changed(($ControllerStruct:ty) => {
         fn on_controller_enabled_changed(
-            mut smoothers: Query<(&mut Smoother, &$ControllerStruct), Changed<$ControllerStruct>>,
+            mut smoothers: Query<
+                (Option<&mut Smoother>, Option<&mut SpringSmoother>, &$ControllerStruct),
+                Changed<$ControllerStruct>,
+            >,
         ) {
-            for (mut smoother, controller) in smoothers.iter_mut() {
-                smoother.set_enabled(controller.enabled);
+            for (smoother, spring, controller) in smoothers.iter_mut() {
+                if let Some(mut smoother) = smoother {
+                    smoother.set_enabled(controller.enabled);
+                }
+                if let Some(mut spring) = spring {
+                    spring.set_enabled(controller.enabled);
+                }
             }
         }
     });
 }
 
+pub mod edge_scroll;
+pub mod follow;
 pub mod fps;
+pub mod free_fly;
+pub mod gamepad;
+pub mod input_config;
+pub mod isometric;
 pub mod orbit;
+pub mod orbit_around_entity;
+#[cfg(feature = "orthographic")]
+pub mod orthographic;
+pub mod pan_zoom_2d;
+#[cfg(feature = "render")]
+pub mod screen_space_pivot;
+pub mod smooth_orbit_2d;
+pub mod third_person;
+pub mod top_down;
+pub mod touch;
+pub mod trackball;
 pub mod unreal;
+pub mod zoom;
@@ -0,0 +1,148 @@
+use crate::{ActiveCameras, ControllableCamera, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::prelude::*,
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    window::{PrimaryWindow, Window},
+};
+
+/// Pans cameras while the cursor is near the edge of the primary window. Requires an [`EdgeScrollConfig`] resource.
+pub struct EdgeScrollPlugin;
+
+impl Plugin for EdgeScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EdgeScrollConfig>().add_systems(
+            Update,
+            edge_scroll_system.in_set(LookTransformSystemSet::LookTransformInput),
+        );
+    }
+}
+
+/// Insert this resource to pan strategy game cameras by moving the cursor to the edge of the window.
+///
+/// The camera moves in its own frame: "edge-right" always pans toward the right of the screen, whatever the camera's
+/// azimuth. Scrolling pauses while any mouse button or key is held, so it doesn't fight other camera input.
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct EdgeScrollConfig {
+    /// How close to the edge of the window, in logical pixels, the cursor must be to pan.
+    pub margin_px: f32,
+    /// Units per second.
+    pub speed: f32,
+    pub enabled: bool,
+}
+
+impl Default for EdgeScrollConfig {
+    fn default() -> Self {
+        Self {
+            margin_px: 10.0,
+            speed: 10.0,
+            enabled: true,
+        }
+    }
+}
+
+/// The direction to pan for a cursor at `cursor` in a window of `window_size`, with `+X` to the right and `+Y` toward
+/// the top of the screen. Each axis is `-1.0`, `0.0`, or `1.0`.
+pub fn edge_scroll_direction(cursor: Vec2, window_size: Vec2, margin: f32) -> Vec2 {
+    let mut direction = Vec2::ZERO;
+    if cursor.x < margin {
+        direction.x -= 1.0;
+    } else if cursor.x > window_size.x - margin {
+        direction.x += 1.0;
+    }
+    // The cursor's Y axis points down the window.
+    if cursor.y < margin {
+        direction.y += 1.0;
+    } else if cursor.y > window_size.y - margin {
+        direction.y -= 1.0;
+    }
+
+    direction
+}
+
+pub fn edge_scroll_system(
+    time: Res<Time>,
+    config: Option<Res<EdgeScrollConfig>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keyboard: Res<Input<KeyCode>>,
+    mut cameras: Query<(Entity, &mut LookTransform), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    let Some(config) = config.filter(|c| c.enabled) else {
+        return;
+    };
+    if mouse_buttons.get_pressed().next().is_some() || keyboard.get_pressed().next().is_some() {
+        return;
+    }
+    let Some((window, cursor)) = windows
+        .get_single()
+        .ok()
+        .and_then(|w| Some((w, w.cursor_position()?)))
+    else {
+        return;
+    };
+
+    let window_size = Vec2::new(window.width(), window.height());
+    let Some(direction) =
+        edge_scroll_direction(cursor, window_size, config.margin_px).try_normalize()
+    else {
+        return;
+    };
+
+    let distance = config.speed * time.delta_seconds();
+    for (_, mut transform) in cameras.iter_mut().filter(|(entity, _)| {
+        active_cameras.contains(*entity) && active_cameras.has_cursor(*entity)
+    }) {
+        let (Some(look), Some(right)) = (transform.look_direction(), transform.right()) else {
+            continue;
+        };
+        // Pan in the ground plane, even when the camera is looking down at it.
+        let up = transform.up.normalize();
+        let forward = (look - look.dot(up) * up)
+            .try_normalize()
+            .unwrap_or(up.cross(right));
+
+        *transform = transform.translate(distance * (direction.x * right + direction.y * forward));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_scroll_direction() {
+        let size = Vec2::new(800.0, 600.0);
+
+        assert_eq!(
+            edge_scroll_direction(Vec2::new(400.0, 300.0), size, 10.0),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            edge_scroll_direction(Vec2::new(795.0, 300.0), size, 10.0),
+            Vec2::X
+        );
+        assert_eq!(
+            edge_scroll_direction(Vec2::new(2.0, 2.0), size, 10.0),
+            Vec2::new(-1.0, 1.0)
+        );
+        assert_eq!(
+            edge_scroll_direction(Vec2::new(400.0, 599.0), size, 10.0),
+            -Vec2::Y
+        );
+    }
+}
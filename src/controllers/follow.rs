@@ -0,0 +1,83 @@
+use crate::{ControllableCamera, LookTransform, LookTransformSystemSet, REFERENCE_FPS};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    time::Time, transform::components::GlobalTransform,
+};
+
+pub struct FollowCameraPlugin;
+
+impl Plugin for FollowCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FollowCamera>().add_systems(
+            Update,
+            follow_camera_system.in_set(LookTransformSystemSet::LookTransformInput),
+        );
+    }
+}
+
+/// Keeps the camera's `eye` at a fixed `offset` from another entity's `GlobalTransform`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct FollowCamera {
+    pub target_entity: Entity,
+    pub offset: Vec3,
+    /// Like `Smoother`'s lag weight, but only smooths the followed position. Set to `0.0` to follow rigidly.
+    pub lag: f32,
+    /// If `true`, the camera looks at the entity. Otherwise, the camera keeps its current look direction.
+    pub look_at_target: bool,
+    lerp_position: Option<Vec3>,
+}
+
+impl Default for FollowCamera {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Vec3::new(0.0, 5.0, 10.0), 0.8)
+    }
+}
+
+impl FollowCamera {
+    pub fn new(target_entity: Entity, offset: Vec3, lag: f32) -> Self {
+        Self {
+            target_entity,
+            offset,
+            lag,
+            look_at_target: true,
+            lerp_position: None,
+        }
+    }
+
+    /// Forgets the smoothed position, so the camera snaps to the entity on the next frame.
+    pub fn reset(&mut self) {
+        self.lerp_position = None;
+    }
+}
+
+pub fn follow_camera_system(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform>,
+    mut cameras: Query<(&mut FollowCamera, &mut LookTransform), ControllableCamera>,
+) {
+    let dt = time.delta_seconds();
+    for (mut follow, mut transform) in cameras.iter_mut() {
+        let Ok(target) = targets.get(follow.target_entity) else {
+            continue;
+        };
+        let goal = target.translation();
+
+        let lag = follow.lag.powf(dt * REFERENCE_FPS);
+        let position = follow
+            .lerp_position
+            .map_or(goal, |old| old.lerp(goal, 1.0 - lag));
+        follow.lerp_position = Some(position);
+
+        let eye = position + follow.offset;
+        if follow.look_at_target {
+            transform.target = position;
+        } else {
+            let delta = eye - transform.eye;
+            transform.target += delta;
+        }
+        transform.eye = eye;
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use super::{
+    gamepad::{GamepadCameraController, GamepadCameraReader},
+    input_config::{invert_axes, KeyboardInputConfig, SensitivityCurve},
+};
+use crate::{
+    ActiveCameras, ControllableCamera, LookAngles, LookTransform, LookTransformBundle,
+    LookTransformSystemSet, PitchYawRoll, Smoother, SpringSmoother,
+};
 
 use bevy::{
     app::prelude::*,
@@ -27,12 +34,20 @@ impl FpsCameraPlugin {
 impl Plugin for FpsCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
+            .register_type::<FpsCameraController>()
+            .register_type::<GamepadCameraController>()
             .add_systems(PreUpdate, on_controller_enabled_changed)
-            .add_systems(Update, control_system)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
             .add_event::<ControlEvent>();
 
         if !self.override_input_system {
-            app.add_systems(Update, default_input_map);
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
         }
     }
 }
@@ -41,6 +56,7 @@ impl Plugin for FpsCameraPlugin {
 pub struct FpsCameraBundle {
     controller: FpsCameraController,
     look_transform: LookTransformBundle,
+    angles: PitchYawRoll,
     transform: Transform,
 }
 
@@ -48,27 +64,49 @@ impl FpsCameraBundle {
     pub fn new(controller: FpsCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
         // Make sure the transform is consistent with the controller to start.
         let transform = Transform::from_translation(eye).looking_at(target, up);
+        let look_transform = LookTransform::new(eye, target, up);
 
         Self {
             controller,
             look_transform: LookTransformBundle {
-                transform: LookTransform::new(eye, target, up),
+                transform: look_transform,
                 smoother: Smoother::new(controller.smoothing_weight),
             },
+            angles: PitchYawRoll::from_look_transform(&look_transform),
             transform,
         }
     }
 }
 
 /// Your typical first-person camera controller.
+///
+/// Input turns the camera's [`PitchYawRoll`], which the bundle adds, and the `LookTransform` is computed from it each
+/// frame, so read it for the current angles. The controller never changes the `roll`, so set it for effects like leaning
+/// around corners. If something else turns the `LookTransform`, like a `CameraTransition`, the angles are read back from
+/// it.
 #[derive(Clone, Component, Copy, Debug, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[reflect(Component, Default, Debug)]
 pub struct FpsCameraController {
+    /// Whether to process input or ignore it
     pub enabled: bool,
+    /// Radians per second of rotation for each pixel of mouse motion on each axis (yaw, pitch). Typically `0.05..0.5`.
     pub mouse_rotate_sensitivity: Vec2,
+    /// Units per second of movement while a movement key is held.
     pub translate_sensitivity: f32,
+    /// The greater, the slower to follow input. In `[0.0, 1.0)`.
     pub smoothing_weight: f32,
+    /// The lowest the camera may look, in radians below the horizon (negative). At least `-PI / 2`.
+    pub min_pitch: f32,
+    /// The highest the camera may look, in radians above the horizon. At most `PI / 2`.
+    pub max_pitch: f32,
+    /// Shapes the mouse rotation speed, e.g. for finer control when moving slowly.
+    pub sensitivity_curve: SensitivityCurve,
+    /// Reverses horizontal rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_x: bool,
+    /// Reverses vertical rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_y: bool,
+    pub keyboard: KeyboardInputConfig,
 }
 
 impl Default for FpsCameraController {
@@ -78,6 +116,12 @@ impl Default for FpsCameraController {
             mouse_rotate_sensitivity: Vec2::splat(0.2),
             translate_sensitivity: 2.0,
             smoothing_weight: 0.9,
+            min_pitch: -std::f32::consts::FRAC_PI_2,
+            max_pitch: std::f32::consts::FRAC_PI_2,
+            sensitivity_curve: SensitivityCurve::Linear,
+            invert_x: false,
+            invert_y: false,
+            keyboard: KeyboardInputConfig::default(),
         }
     }
 }
@@ -94,14 +138,20 @@ pub fn default_input_map(
     mut events: EventWriter<ControlEvent>,
     keyboard: Res<Input<KeyCode>>,
     mut mouse_motion_events: EventReader<MouseMotion>,
-    controllers: Query<&FpsCameraController>,
+    gamepad: GamepadCameraReader,
+    controllers: Query<(Entity, &FpsCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
 ) {
     // Can only control one camera at a time.
-    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
-        controller
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
     } else {
         return;
     };
+    let has_cursor = active_cameras.has_cursor(entity);
     let FpsCameraController {
         translate_sensitivity,
         mouse_rotate_sensitivity,
@@ -109,58 +159,87 @@ pub fn default_input_map(
     } = *controller;
 
     let mut cursor_delta = Vec2::ZERO;
-    for event in mouse_motion_events.read() {
+    for event in mouse_motion_events.read().filter(|_| has_cursor) {
         cursor_delta += event.delta;
     }
 
     events.send(ControlEvent::Rotate(
-        mouse_rotate_sensitivity * cursor_delta,
+        mouse_rotate_sensitivity * controller.sensitivity_curve.apply(cursor_delta),
     ));
 
-    for (key, dir) in [
-        (KeyCode::W, Vec3::Z),
-        (KeyCode::A, Vec3::X),
-        (KeyCode::S, -Vec3::Z),
-        (KeyCode::D, -Vec3::X),
-        (KeyCode::ShiftLeft, -Vec3::Y),
-        (KeyCode::Space, Vec3::Y),
-    ]
-    .iter()
-    .cloned()
-    {
+    for (key, dir) in controller.keyboard.bindings() {
         if keyboard.pressed(key) {
+            // `TranslateEye` is in (left, up, forward) coordinates.
+            let dir = Vec3::new(-dir.x, dir.y, dir.z);
             events.send(ControlEvent::TranslateEye(translate_sensitivity * dir));
         }
     }
+
+    if let Some(gamepad) = gamepad.read() {
+        // Sticks point up for positive Y, whereas the mouse moves down.
+        let look = gamepad.look_axis;
+        events.send(ControlEvent::Rotate(Vec2::new(look.x, -look.y)));
+
+        let movement = Vec3::new(-gamepad.move_axis.x, gamepad.zoom, gamepad.move_axis.y);
+        if movement != Vec3::ZERO {
+            events.send(ControlEvent::TranslateEye(translate_sensitivity * movement));
+        }
+    }
 }
 
 pub fn control_system(
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&FpsCameraController, &mut LookTransform)>,
+    mut cameras: Query<
+        (
+            Entity,
+            &FpsCameraController,
+            &mut LookTransform,
+            Option<&mut PitchYawRoll>,
+        ),
+        ControllableCamera,
+    >,
     time: Res<Time>,
+    active_cameras: ActiveCameras,
 ) {
     // Can only control one camera at a time.
-    let mut transform = if let Some((_, transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-        transform
-    } else {
-        return;
-    };
+    let (controller, mut transform, stored_angles) =
+        if let Some((_, controller, transform, angles)) = cameras
+            .iter_mut()
+            .find(|c| c.1.enabled && active_cameras.contains(c.0))
+        {
+            (controller, transform, angles)
+        } else {
+            return;
+        };
 
     let look_vector = transform.look_direction().unwrap();
-    let mut look_angles = LookAngles::from_vector(look_vector);
+    let mut angles = match stored_angles.as_deref() {
+        Some(angles) if angles.look_direction().abs_diff_eq(look_vector, 1e-4) => *angles,
+        // Something else turned the camera.
+        Some(angles) => PitchYawRoll {
+            roll: angles.roll,
+            ..PitchYawRoll::from_look_transform(&transform)
+        },
+        None => PitchYawRoll::from_look_transform(&transform),
+    };
 
-    let yaw_rot = Quat::from_axis_angle(Vec3::Y, look_angles.get_yaw());
-    let rot_x = yaw_rot * Vec3::X;
-    let rot_y = yaw_rot * Vec3::Y;
-    let rot_z = yaw_rot * Vec3::Z;
+    let (rot_x, rot_y, rot_z) = match transform.right() {
+        Some(right) => (-right, transform.up, transform.up.cross(right)),
+        // Looking straight along `up`, so take the level axes from the yaw instead.
+        None => {
+            let yaw_rot = Quat::from_rotation_y(angles.yaw);
+            (yaw_rot * Vec3::NEG_X, Vec3::Y, yaw_rot * Vec3::NEG_Z)
+        }
+    };
 
     let dt = time.delta_seconds();
     for event in events.read() {
         match event {
             ControlEvent::Rotate(delta) => {
+                let delta = invert_axes(*delta, controller.invert_x, controller.invert_y);
                 // Rotates with pitch and yaw.
-                look_angles.add_yaw(dt * -delta.x);
-                look_angles.add_pitch(dt * -delta.y);
+                angles.yaw += dt * -delta.x;
+                angles.pitch += dt * -delta.y;
             }
             ControlEvent::TranslateEye(delta) => {
                 // Translates up/down (Y) left/right (X) and forward/back (Z).
@@ -169,7 +248,154 @@ pub fn control_system(
         }
     }
 
-    look_angles.assert_not_looking_up();
+    // `LookAngles` additionally keeps the pitch just short of the poles, and wraps the yaw.
+    let mut look_angles = LookAngles::default();
+    look_angles.set_pitch(
+        angles
+            .pitch
+            .clamp(controller.min_pitch, controller.max_pitch),
+    );
+    look_angles.set_yaw(angles.yaw);
+    angles.pitch = look_angles.get_pitch();
+    angles.yaw = look_angles.get_yaw();
 
-    transform.target = transform.eye + transform.radius() * look_angles.unit_vector();
+    let radius = transform.radius();
+    let oriented =
+        LookTransform::from_pitch_yaw_roll(transform.eye, angles.pitch, angles.yaw, angles.roll);
+    *transform = LookTransform {
+        target: transform.eye + radius * (oriented.target - oriented.eye),
+        ..oriented
+    };
+    if let Some(mut stored_angles) = stored_angles {
+        *stored_angles = angles;
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_pitch_limits() {
+        let controller = FpsCameraController {
+            min_pitch: -0.2,
+            max_pitch: 0.3,
+            ..Default::default()
+        };
+        let transform = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        app.insert_resource(time)
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app.world.spawn((controller, transform)).id();
+
+        // Moving the mouse up looks up.
+        app.world
+            .send_event(ControlEvent::Rotate(Vec2::new(0.0, -10.0)));
+        app.update();
+        let looking_up = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(looking_up.pitch(), 0.3, epsilon = 1e-5);
+
+        app.world
+            .send_event(ControlEvent::Rotate(Vec2::new(0.0, 10.0)));
+        app.update();
+        let looking_down = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(looking_down.pitch(), -0.2, epsilon = 1e-5);
+
+        // Inverted, moving the mouse down looks up.
+        app.world
+            .get_mut::<FpsCameraController>(camera)
+            .unwrap()
+            .invert_y = true;
+        app.world
+            .send_event(ControlEvent::Rotate(Vec2::new(0.0, 10.0)));
+        app.update();
+        let inverted = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(inverted.pitch(), 0.3, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_moves_while_looking_straight_up() {
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        app.insert_resource(time)
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app
+            .world
+            .spawn(FpsCameraBundle::new(
+                FpsCameraController::default(),
+                Vec3::ZERO,
+                Vec3::Y,
+                Vec3::Y,
+            ))
+            .id();
+
+        app.world
+            .send_event(ControlEvent::TranslateEye(Vec3::new(0.0, 0.0, 1.0)));
+        app.update();
+        let look = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(look.eye.abs_diff_eq(Vec3::NEG_Z, 1e-5));
+        // Clamped just short of the pole, so the next frame has proper axes again.
+        assert!(look.pitch() < 0.5 * std::f32::consts::PI);
+        assert!(look.right().is_some());
+    }
+
+    #[test]
+    fn test_drives_pitch_yaw_roll() {
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        app.insert_resource(time)
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let transform = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((
+                FpsCameraController::default(),
+                transform,
+                PitchYawRoll {
+                    roll: 0.1,
+                    ..PitchYawRoll::from_look_transform(&transform)
+                },
+            ))
+            .id();
+
+        // Moving the mouse left turns left, i.e. a positive yaw.
+        app.world
+            .send_event(ControlEvent::Rotate(Vec2::new(-0.5, 0.0)));
+        app.update();
+        let angles = *app.world.get::<PitchYawRoll>(camera).unwrap();
+        assert_relative_eq!(angles.yaw, 0.5, epsilon = 1e-5);
+        assert_relative_eq!(angles.roll, 0.1, epsilon = 1e-5);
+        let look = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(look.yaw(), 0.5, epsilon = 1e-5);
+        assert_relative_eq!(
+            PitchYawRoll::from_look_transform(look).roll,
+            0.1,
+            epsilon = 1e-5
+        );
+
+        // Turning the camera from elsewhere updates the angles.
+        *app.world.get_mut::<LookTransform>(camera).unwrap() =
+            LookTransform::new(Vec3::ZERO, Vec3::X, Vec3::Y);
+        app.update();
+        let angles = *app.world.get::<PitchYawRoll>(camera).unwrap();
+        assert_relative_eq!(angles.yaw, -0.5 * std::f32::consts::PI, epsilon = 1e-5);
+    }
 }
@@ -0,0 +1,285 @@
+use super::input_config::{invert_axes, KeyboardInputConfig, SensitivityCurve};
+use crate::{
+    ActiveCameras, ControllableCamera, LookAngles, LookTransform, LookTransformBundle,
+    LookTransformSystemSet, Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{mouse::MouseMotion, prelude::*},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    transform::components::Transform,
+};
+
+#[derive(Default)]
+pub struct FreeFlyPlugin {
+    pub override_input_system: bool,
+}
+
+impl FreeFlyPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for FreeFlyPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<FreeFlyController>()
+            .add_systems(PreUpdate, on_controller_enabled_changed)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
+            .add_event::<ControlEvent>();
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct FreeFlyBundle {
+    controller: FreeFlyController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl FreeFlyBundle {
+    pub fn new(controller: FreeFlyController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, up);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, up),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// An unconstrained "noclip" camera for editors and debugging.
+///
+/// Unlike the FPS camera, moving forward follows the look direction up and down, and there are dedicated keys to move
+/// along the camera's own up axis.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct FreeFlyController {
+    /// Whether to process input or ignore it
+    pub enabled: bool,
+    /// Units per second of movement while a movement key is held.
+    pub speed: f32,
+    /// Units per second of movement while the `fast` key is also held.
+    pub fast_speed: f32,
+    /// Radians per second of rotation for each pixel of mouse motion.
+    pub sensitivity: f32,
+    /// The greater, the slower to follow input. In `[0.0, 1.0)`.
+    pub smoothing_weight: f32,
+    /// Shapes the mouse rotation speed, e.g. for finer control when moving slowly.
+    pub sensitivity_curve: SensitivityCurve,
+    /// Reverses horizontal rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_x: bool,
+    /// Reverses vertical rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_y: bool,
+    pub keyboard: KeyboardInputConfig,
+}
+
+impl Default for FreeFlyController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed: 5.0,
+            fast_speed: 20.0,
+            sensitivity: 0.2,
+            smoothing_weight: 0.9,
+            sensitivity_curve: SensitivityCurve::Linear,
+            invert_x: false,
+            invert_y: false,
+            keyboard: KeyboardInputConfig::fly(),
+        }
+    }
+}
+
+#[derive(Event)]
+pub enum ControlEvent {
+    Rotate(Vec2),
+    /// Moves the eye along the camera's right (X), up (Y), and look (Z) directions.
+    TranslateEye(Vec3),
+}
+
+define_on_controller_enabled_changed!(FreeFlyController);
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    keyboard: Res<Input<KeyCode>>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    controllers: Query<(Entity, &FreeFlyController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
+    } else {
+        return;
+    };
+    let has_cursor = active_cameras.has_cursor(entity);
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.read().filter(|_| has_cursor) {
+        cursor_delta += event.delta;
+    }
+    if cursor_delta != Vec2::ZERO {
+        events.send(ControlEvent::Rotate(
+            controller.sensitivity * controller.sensitivity_curve.apply(cursor_delta),
+        ));
+    }
+
+    let mut direction = Vec3::ZERO;
+    for (key, dir) in controller.keyboard.bindings() {
+        if keyboard.pressed(key) {
+            direction += dir;
+        }
+    }
+
+    if let Some(direction) = direction.try_normalize() {
+        let speed = if keyboard.pressed(controller.keyboard.fast) {
+            controller.fast_speed
+        } else {
+            controller.speed
+        };
+        events.send(ControlEvent::TranslateEye(speed * direction));
+    }
+}
+
+pub fn control_system(
+    time: Res<Time>,
+    mut events: EventReader<ControlEvent>,
+    mut cameras: Query<(Entity, &FreeFlyController, &mut LookTransform), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
+    } else {
+        return;
+    };
+
+    let Some(look) = transform.look_direction() else {
+        events.clear();
+        return;
+    };
+    // Clamps the pitch, so a camera looking along `up` can turn away and has axes to move along.
+    let mut look_angles = LookAngles::from_vector(look);
+    let radius = transform.radius();
+    transform.target = transform.eye + radius * look_angles.unit_vector();
+
+    let dt = time.delta_seconds();
+    for event in events.read() {
+        match event {
+            ControlEvent::Rotate(delta) => {
+                let delta = invert_axes(*delta, controller.invert_x, controller.invert_y);
+                look_angles.add_yaw(dt * -delta.x);
+                look_angles.add_pitch(dt * -delta.y);
+            }
+            ControlEvent::TranslateEye(delta) => {
+                *transform = transform.translate_local(dt * *delta);
+            }
+        }
+    }
+
+    transform.target = transform.eye + radius * look_angles.unit_vector();
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_fast_forward_follows_look_direction() {
+        let controller = FreeFlyController::default();
+        let start = LookTransform::new(Vec3::ZERO, Vec3::new(0.0, -1.0, -1.0), Vec3::Y);
+
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(controller.keyboard.forward);
+        keyboard.press(controller.keyboard.fast);
+        app.insert_resource(time)
+            .insert_resource(keyboard)
+            .add_event::<MouseMotion>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, (default_input_map, control_system).chain());
+        let camera = app.world.spawn((controller, start)).id();
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        let expected = controller.fast_speed * Vec3::new(0.0, -1.0, -1.0).normalize();
+        assert_relative_eq!(end.eye.y, expected.y, epsilon = 1e-4);
+        assert_relative_eq!(end.eye.z, expected.z, epsilon = 1e-4);
+        assert_relative_eq!(
+            end.look_direction()
+                .unwrap()
+                .dot(start.look_direction().unwrap()),
+            1.0,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_turns_and_moves_while_looking_straight_down() {
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        app.insert_resource(time)
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app
+            .world
+            .spawn((
+                FreeFlyController::default(),
+                LookTransform::new(Vec3::ZERO, Vec3::NEG_Y, Vec3::Y),
+            ))
+            .id();
+
+        app.world
+            .send_event(ControlEvent::Rotate(Vec2::new(0.0, -0.5)));
+        app.world.send_event(ControlEvent::TranslateEye(Vec3::Z));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(end.eye.y < -0.9);
+        assert!(end.right().is_some());
+        assert!(end.pitch() > -std::f32::consts::FRAC_PI_2 + 0.5);
+    }
+}
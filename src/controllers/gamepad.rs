@@ -0,0 +1,142 @@
+use bevy::{
+    ecs::{prelude::*, system::SystemParam},
+    input::{gamepad::*, prelude::*},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+};
+
+/// Insert this resource to drive the built-in camera controllers with a gamepad, alongside the mouse and keyboard.
+///
+/// - Left stick: pan / move
+/// - Right stick: orbit / look
+/// - Shoulder buttons (`LeftTrigger` and `RightTrigger`): zoom, or move down/up for controllers without zoom
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct GamepadCameraController {
+    /// The gamepad to read. If `None`, the connected gamepad with the lowest ID is used.
+    pub gamepad: Option<Gamepad>,
+    /// Scales the left stick, per axis.
+    pub move_sensitivity: Vec2,
+    /// Scales the right stick, per axis, in radians per second at full tilt.
+    pub look_sensitivity: Vec2,
+    /// Zoom speed while a shoulder button is held.
+    pub zoom_sensitivity: f32,
+    /// Left stick deflections shorter than this are ignored.
+    pub move_dead_zone: f32,
+    /// Right stick deflections shorter than this are ignored.
+    pub look_dead_zone: f32,
+}
+
+impl Default for GamepadCameraController {
+    fn default() -> Self {
+        Self {
+            gamepad: None,
+            move_sensitivity: Vec2::ONE,
+            look_sensitivity: Vec2::splat(2.0),
+            zoom_sensitivity: 2.0,
+            move_dead_zone: 0.15,
+            look_dead_zone: 0.15,
+        }
+    }
+}
+
+/// The gamepad state for one frame, already scaled by sensitivity and with dead zones applied.
+///
+/// Stick axes are positive to the right and up.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamepadCameraInput {
+    pub move_axis: Vec2,
+    pub look_axis: Vec2,
+    /// Positive while zooming in (or moving up).
+    pub zoom: f32,
+}
+
+/// Reads `GamepadCameraInput` for the controller input systems.
+#[derive(SystemParam)]
+pub struct GamepadCameraReader<'w> {
+    config: Option<Res<'w, GamepadCameraController>>,
+    gamepads: Res<'w, Gamepads>,
+    axes: Res<'w, Axis<GamepadAxis>>,
+    buttons: Res<'w, Input<GamepadButton>>,
+}
+
+impl<'w> GamepadCameraReader<'w> {
+    /// Returns `None` if there is no `GamepadCameraController` resource or no gamepad is connected.
+    pub fn read(&self) -> Option<GamepadCameraInput> {
+        let config = self.config.as_deref()?;
+        let gamepad = match config.gamepad {
+            Some(gamepad) => self.gamepads.contains(gamepad).then_some(gamepad)?,
+            None => self.gamepads.iter().min_by_key(|g| g.id)?,
+        };
+
+        let stick = |x, y, dead_zone| {
+            let axis = |axis_type| {
+                self.axes
+                    .get(GamepadAxis::new(gamepad, axis_type))
+                    .unwrap_or(0.0)
+            };
+            apply_dead_zone(Vec2::new(axis(x), axis(y)), dead_zone)
+        };
+        let pressed = |button_type| {
+            self.buttons
+                .pressed(GamepadButton::new(gamepad, button_type))
+        };
+
+        let mut zoom = 0.0;
+        if pressed(GamepadButtonType::RightTrigger) {
+            zoom += config.zoom_sensitivity;
+        }
+        if pressed(GamepadButtonType::LeftTrigger) {
+            zoom -= config.zoom_sensitivity;
+        }
+
+        Some(GamepadCameraInput {
+            move_axis: config.move_sensitivity
+                * stick(
+                    GamepadAxisType::LeftStickX,
+                    GamepadAxisType::LeftStickY,
+                    config.move_dead_zone,
+                ),
+            look_axis: config.look_sensitivity
+                * stick(
+                    GamepadAxisType::RightStickX,
+                    GamepadAxisType::RightStickY,
+                    config.look_dead_zone,
+                ),
+            zoom,
+        })
+    }
+}
+
+/// Zeroes deflections inside the dead zone and rescales the rest so the output still ramps smoothly from zero.
+fn apply_dead_zone(stick: Vec2, dead_zone: f32) -> Vec2 {
+    let length = stick.length();
+    if length <= dead_zone || dead_zone >= 1.0 {
+        return Vec2::ZERO;
+    }
+
+    let scaled_length = ((length - dead_zone) / (1.0 - dead_zone)).min(1.0);
+    stick * (scaled_length / length)
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_dead_zone() {
+        assert_eq!(apply_dead_zone(Vec2::new(0.1, 0.0), 0.2), Vec2::ZERO);
+        assert_relative_eq!(apply_dead_zone(Vec2::new(0.0, 1.0), 0.2).y, 1.0);
+        assert_relative_eq!(apply_dead_zone(Vec2::new(0.6, 0.0), 0.2).x, 0.5);
+    }
+}
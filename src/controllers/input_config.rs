@@ -0,0 +1,335 @@
+//! Button and key bindings shared by the built-in controllers' default input maps.
+//!
+//! Each controller's `Default` keeps the bindings listed in the [crate docs](crate), so only set these to rebind, e.g. to
+//! orbit with the right mouse button and pan with the middle one.
+
+use bevy::{input::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect};
+
+/// Which mouse inputs orbit, pan, and zoom.
+///
+/// With `orbit_with_cursor` set, orbiting happens while the cursor moves with all of the configured `orbit_button` and
+/// `orbit_modifier` held, or whenever it moves if neither is configured.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(default)
+)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct MouseInputConfig {
+    /// Whether moving the cursor orbits at all.
+    pub orbit_with_cursor: bool,
+    pub orbit_button: Option<MouseButton>,
+    /// A key that must be held to orbit.
+    pub orbit_modifier: Option<KeyCode>,
+    pub pan_button: Option<MouseButton>,
+    /// Drag to move along the look direction and turn. Only used by the Unreal camera.
+    pub move_button: Option<MouseButton>,
+    /// Zoom with the vertical scroll axis.
+    pub zoom_scroll: bool,
+    /// Orbit with the horizontal scroll axis, e.g. a two-finger swipe on a touchpad, and with the vertical axis too if
+    /// `zoom_scroll` is off.
+    pub orbit_scroll: bool,
+}
+
+/// The orbit camera's bindings: move the mouse to orbit, drag with the right mouse button to pan, and scroll to zoom.
+impl Default for MouseInputConfig {
+    fn default() -> Self {
+        Self {
+            orbit_with_cursor: true,
+            orbit_button: None,
+            orbit_modifier: None,
+            pan_button: Some(MouseButton::Right),
+            move_button: None,
+            zoom_scroll: true,
+            orbit_scroll: false,
+        }
+    }
+}
+
+impl MouseInputConfig {
+    /// Drag with `button` to orbit and scroll to zoom, without panning.
+    pub fn drag_to_orbit(button: MouseButton) -> Self {
+        Self {
+            orbit_with_cursor: true,
+            orbit_button: Some(button),
+            orbit_modifier: None,
+            pan_button: None,
+            ..Default::default()
+        }
+    }
+
+    /// Drag with `button` to pan and scroll to zoom, without orbiting.
+    pub fn drag_to_pan(button: MouseButton) -> Self {
+        Self {
+            orbit_with_cursor: false,
+            orbit_button: None,
+            orbit_modifier: None,
+            pan_button: Some(button),
+            ..Default::default()
+        }
+    }
+
+    /// The Unreal camera's bindings: drag with the left mouse button to move, the right one to turn, and the middle one
+    /// or both left and right to pan.
+    pub fn unreal() -> Self {
+        Self {
+            orbit_with_cursor: true,
+            orbit_button: Some(MouseButton::Right),
+            orbit_modifier: None,
+            pan_button: Some(MouseButton::Middle),
+            move_button: Some(MouseButton::Left),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_orbiting(
+        &self,
+        mouse_buttons: &Input<MouseButton>,
+        keyboard: &Input<KeyCode>,
+    ) -> bool {
+        self.orbit_with_cursor
+            && self.orbit_button.map_or(true, |b| mouse_buttons.pressed(b))
+            && self.orbit_modifier.map_or(true, |k| keyboard.pressed(k))
+    }
+
+    pub fn is_panning(&self, mouse_buttons: &Input<MouseButton>) -> bool {
+        self.pan_button.is_some_and(|b| mouse_buttons.pressed(b))
+    }
+
+    /// Splits a scroll of `(x, y)` into the amount to zoom and the amount to orbit, according to `zoom_scroll` and
+    /// `orbit_scroll`.
+    pub fn split_scroll(&self, scroll: Vec2) -> (f32, Vec2) {
+        let zoom = if self.zoom_scroll { scroll.y } else { 0.0 };
+        let orbit = match (self.orbit_scroll, self.zoom_scroll) {
+            (false, _) => Vec2::ZERO,
+            (true, true) => Vec2::new(scroll.x, 0.0),
+            (true, false) => scroll,
+        };
+
+        (zoom, orbit)
+    }
+}
+
+/// Which keys move the camera. Planar controllers only use the `forward`, `back`, `left`, and `right` keys, and only the
+/// free-fly camera uses `fast`.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(default)
+)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct KeyboardInputConfig {
+    pub forward: KeyCode,
+    pub back: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub up: KeyCode,
+    pub down: KeyCode,
+    /// Held to move faster.
+    pub fast: KeyCode,
+    /// Turns the top-down camera one way, and `rotate_right` the other.
+    pub rotate_left: KeyCode,
+    pub rotate_right: KeyCode,
+    /// Whether the arrow keys also move forward, back, left, and right.
+    pub arrows: bool,
+}
+
+/// The FPS camera's bindings: WASD, with Space for up and Shift for down.
+impl Default for KeyboardInputConfig {
+    fn default() -> Self {
+        Self {
+            forward: KeyCode::W,
+            back: KeyCode::S,
+            left: KeyCode::A,
+            right: KeyCode::D,
+            up: KeyCode::Space,
+            down: KeyCode::ShiftLeft,
+            fast: KeyCode::ShiftLeft,
+            rotate_left: KeyCode::Q,
+            rotate_right: KeyCode::E,
+            arrows: false,
+        }
+    }
+}
+
+impl KeyboardInputConfig {
+    /// The defaults, with the arrow keys too, as used by the strategy cameras.
+    pub fn wasd_and_arrows() -> Self {
+        Self {
+            arrows: true,
+            ..Default::default()
+        }
+    }
+
+    /// The free-fly and Unreal cameras' bindings: WASD, with E for up and Q for down.
+    pub fn fly() -> Self {
+        Self {
+            up: KeyCode::E,
+            down: KeyCode::Q,
+            ..Default::default()
+        }
+    }
+
+    /// Each key with its direction, as (right, up, forward), including the arrow keys if enabled.
+    pub fn bindings(&self) -> impl Iterator<Item = (KeyCode, Vec3)> {
+        let arrows_enabled = self.arrows;
+        let arrows = [
+            (KeyCode::Up, Vec3::Z),
+            (KeyCode::Down, Vec3::NEG_Z),
+            (KeyCode::Left, Vec3::NEG_X),
+            (KeyCode::Right, Vec3::X),
+        ];
+        [
+            (self.forward, Vec3::Z),
+            (self.back, Vec3::NEG_Z),
+            (self.left, Vec3::NEG_X),
+            (self.right, Vec3::X),
+            (self.up, Vec3::Y),
+            (self.down, Vec3::NEG_Y),
+        ]
+        .into_iter()
+        .chain(arrows.into_iter().filter(move |_| arrows_enabled))
+    }
+
+    /// The sum of the held directions on the ground plane, as (right, forward). Each key counts once, even if an arrow
+    /// key is bound to the same direction.
+    pub fn pan_direction(&self, keyboard: &Input<KeyCode>) -> Vec2 {
+        let mut direction = Vec2::ZERO;
+        for (keys, dir) in [
+            ([self.forward, KeyCode::Up], Vec2::Y),
+            ([self.back, KeyCode::Down], -Vec2::Y),
+            ([self.left, KeyCode::Left], -Vec2::X),
+            ([self.right, KeyCode::Right], Vec2::X),
+        ] {
+            if keyboard.pressed(keys[0]) || (self.arrows && keyboard.pressed(keys[1])) {
+                direction += dir;
+            }
+        }
+
+        direction
+    }
+}
+
+/// Maps the mouse's speed to the camera's rotation speed, for fine control when moving the mouse slowly that still allows
+/// fast turns.
+///
+/// The curve sees the length of each frame's mouse motion divided by `SensitivityCurve::FULL_SPEED` pixels, which is in
+/// `[0, 1]` for typical motion, and should map `0.0` to `0.0` and `1.0` to `1.0`. Faster motion is clamped to `1.0` before
+/// the curve and scaled linearly after, so the curve only needs to be defined on `[0, 1]`. A `Custom` curve must be
+/// monotone, or speeding up the mouse would slow down the camera.
+///
+/// `Custom` curves can't be serialized or edited with reflection.
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug)]
+pub enum SensitivityCurve {
+    #[default]
+    Linear,
+    /// Slow near the center of the range and fast at the end.
+    Squared,
+    /// Fast near the center of the range and slow at the end.
+    SquareRoot,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(#[reflect(ignore, default = "linear")] fn(f32) -> f32),
+}
+
+// Stands in for a `Custom` curve when one is built by reflection, which can't see the function.
+fn linear() -> fn(f32) -> f32 {
+    |x| x
+}
+
+impl SensitivityCurve {
+    /// The mouse motion in one frame, in pixels, that maps to `1.0` on the curve.
+    pub const FULL_SPEED: f32 = 100.0;
+
+    /// Evaluates the curve at `x`, which is expected to be in `[0, 1]`.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        match self {
+            Self::Linear => x,
+            Self::Squared => x * x,
+            Self::SquareRoot => x.sqrt(),
+            Self::Custom(curve) => curve(x),
+        }
+    }
+
+    /// Scales a frame's mouse motion, in pixels, by the curve, keeping its direction.
+    pub fn apply(&self, delta: Vec2) -> Vec2 {
+        let x = delta.length() / Self::FULL_SPEED;
+        if x == 0.0 {
+            return Vec2::ZERO;
+        }
+        let y = self.evaluate(x.min(1.0)) * x.max(1.0);
+
+        (y / x) * delta
+    }
+}
+
+/// Flips the axes of a rotation delta for a controller's `invert_x` and `invert_y` settings.
+pub fn invert_axes(delta: Vec2, invert_x: bool, invert_y: bool) -> Vec2 {
+    let sign = |invert| if invert { -1.0 } else { 1.0 };
+
+    Vec2::new(sign(invert_x), sign(invert_y)) * delta
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebound_orbit_and_pan() {
+        let config = MouseInputConfig {
+            orbit_button: Some(MouseButton::Right),
+            orbit_modifier: None,
+            pan_button: Some(MouseButton::Middle),
+            ..Default::default()
+        };
+        let keyboard = Input::<KeyCode>::default();
+        let mut mouse_buttons = Input::<MouseButton>::default();
+
+        mouse_buttons.press(MouseButton::Right);
+        assert!(config.is_orbiting(&mouse_buttons, &keyboard));
+        assert!(!config.is_panning(&mouse_buttons));
+        assert!(MouseInputConfig::default().is_orbiting(&mouse_buttons, &keyboard));
+        assert!(MouseInputConfig::default().is_panning(&mouse_buttons));
+        let pan_only = MouseInputConfig::drag_to_pan(MouseButton::Right);
+        assert!(!pan_only.is_orbiting(&mouse_buttons, &keyboard));
+
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::Up);
+        keyboard.press(KeyCode::D);
+        assert_eq!(
+            KeyboardInputConfig::default().pan_direction(&keyboard),
+            Vec2::X
+        );
+        assert_eq!(
+            KeyboardInputConfig::wasd_and_arrows().pan_direction(&keyboard),
+            Vec2::ONE
+        );
+    }
+
+    #[test]
+    fn test_sensitivity_curves() {
+        let slow = Vec2::new(0.0, 0.25 * SensitivityCurve::FULL_SPEED);
+        assert_eq!(SensitivityCurve::Linear.apply(slow), slow);
+        assert_eq!(SensitivityCurve::Squared.apply(slow), 0.25 * slow);
+        assert_eq!(SensitivityCurve::SquareRoot.apply(slow), 2.0 * slow);
+        assert_eq!(
+            SensitivityCurve::Custom(|x| x * x * x).apply(slow),
+            slow / 16.0
+        );
+
+        // Beyond full speed, every curve continues linearly.
+        let fast = Vec2::new(3.0 * SensitivityCurve::FULL_SPEED, 0.0);
+        assert_eq!(SensitivityCurve::Squared.apply(fast), fast);
+        assert_eq!(SensitivityCurve::Linear.apply(Vec2::ZERO), Vec2::ZERO);
+    }
+}
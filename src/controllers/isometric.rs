@@ -0,0 +1,302 @@
+use super::{
+    edge_scroll::edge_scroll_direction, input_config::KeyboardInputConfig, orbit::orbit_direction,
+};
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    transform::components::Transform,
+    window::{PrimaryWindow, Window},
+};
+
+#[derive(Default)]
+pub struct IsometricCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl IsometricCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for IsometricCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<IsometricCameraController>()
+            .add_systems(PreUpdate, on_controller_enabled_changed)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
+            .add_event::<ControlEvent>();
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct IsometricCameraBundle {
+    controller: IsometricCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl IsometricCameraBundle {
+    /// The `eye` is placed `distance` units from the `target` at the controller's angles.
+    pub fn new(controller: IsometricCameraController, target: Vec3, distance: f32) -> Self {
+        let eye = target + distance * controller.eye_direction();
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, Vec3::Y),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// A camera held at a fixed angle above the XZ plane that pans and zooms, but never rotates.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct IsometricCameraController {
+    /// Whether to process input or ignore it
+    pub enabled: bool,
+    /// Radians above the XZ plane. Defaults to the true isometric angle, about 35.26 degrees.
+    pub elevation_angle: f32,
+    /// Radians around the Y axis, with the same conventions as [`orbit_angles`](super::orbit::orbit_angles).
+    pub azimuth_angle: f32,
+    /// Units per second when panning.
+    pub pan_speed: f32,
+    pub keyboard: KeyboardInputConfig,
+    /// Whether to pan when the cursor is near the edge of the window.
+    pub edge_scroll: bool,
+    /// How close to the edge of the window, in logical pixels, the cursor must be to pan.
+    pub edge_scroll_margin: f32,
+    /// If set, the target settles on the nearest point of a grid with this spacing whenever panning stops.
+    pub grid_snap: Option<f32>,
+    /// Zoom factor per scroll wheel line
+    pub zoom_speed: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub pixels_per_line: f32,
+    /// The greater, the slower to follow input
+    pub smoothing_weight: f32,
+}
+
+impl Default for IsometricCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            elevation_angle: std::f32::consts::FRAC_1_SQRT_2.atan(),
+            azimuth_angle: std::f32::consts::FRAC_PI_4,
+            pan_speed: 10.0,
+            keyboard: KeyboardInputConfig::wasd_and_arrows(),
+            edge_scroll: false,
+            edge_scroll_margin: 10.0,
+            grid_snap: None,
+            zoom_speed: 0.1,
+            min_distance: 2.0,
+            max_distance: 200.0,
+            pixels_per_line: 53.0,
+            smoothing_weight: 0.8,
+        }
+    }
+}
+
+impl IsometricCameraController {
+    /// The unit vector from the target toward the eye.
+    pub fn eye_direction(&self) -> Vec3 {
+        orbit_direction(Vec3::Y, self.elevation_angle, self.azimuth_angle)
+    }
+}
+
+#[derive(Event)]
+pub enum ControlEvent {
+    /// Pans along the camera's (right, forward) directions on the XZ plane.
+    Pan(Vec2),
+    /// Multiplies the distance from the target.
+    Zoom(f32),
+}
+
+define_on_controller_enabled_changed!(IsometricCameraController);
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    keyboard: Res<Input<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    controllers: Query<(Entity, &IsometricCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
+    } else {
+        return;
+    };
+    let has_cursor = active_cameras.has_cursor(entity);
+
+    let mut pan = controller.keyboard.pan_direction(&keyboard);
+
+    if controller.edge_scroll && has_cursor {
+        if let Ok(window) = windows.get_single() {
+            if let Some(cursor) = window.cursor_position() {
+                let window_size = Vec2::new(window.width(), window.height());
+                pan += edge_scroll_direction(cursor, window_size, controller.edge_scroll_margin);
+            }
+        }
+    }
+
+    if let Some(pan) = pan.try_normalize() {
+        events.send(ControlEvent::Pan(controller.pan_speed * pan));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
+        // scale the event magnitude per pixel or per line
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / controller.pixels_per_line,
+        };
+        scalar *= 1.0 - scroll_amount * controller.zoom_speed;
+    }
+    if scalar != 1.0 {
+        events.send(ControlEvent::Zoom(scalar));
+    }
+}
+
+pub fn control_system(
+    time: Res<Time>,
+    mut events: EventReader<ControlEvent>,
+    mut cameras: Query<
+        (Entity, &IsometricCameraController, &mut LookTransform),
+        ControllableCamera,
+    >,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
+    } else {
+        return;
+    };
+
+    let eye_direction = controller.eye_direction();
+    let forward = Vec3::new(-eye_direction.x, 0.0, -eye_direction.z)
+        .try_normalize()
+        .unwrap_or(Vec3::NEG_Z);
+    let right = forward.cross(Vec3::Y);
+
+    let mut target = transform.target;
+    let mut distance = transform.radius();
+    let mut panned = false;
+
+    let dt = time.delta_seconds();
+    for event in events.read() {
+        match event {
+            ControlEvent::Pan(delta) => {
+                target += dt * (delta.x * right + delta.y * forward);
+                panned = true;
+            }
+            ControlEvent::Zoom(scalar) => {
+                distance *= *scalar;
+            }
+        }
+    }
+
+    // Snapping while panning would stop small per-frame movements from ever leaving a grid point.
+    if let (Some(spacing), false) = (controller.grid_snap, panned) {
+        if spacing > 0.0 {
+            target.x = (target.x / spacing).round() * spacing;
+            target.z = (target.z / spacing).round() * spacing;
+        }
+    }
+
+    let distance = distance.clamp(controller.min_distance, controller.max_distance);
+    transform.target = target;
+    transform.eye = target + distance * eye_direction;
+    transform.up = Vec3::Y;
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_pan_keeps_angles_and_snaps_when_stopped() {
+        let controller = IsometricCameraController {
+            azimuth_angle: 0.0,
+            grid_snap: Some(1.0),
+            ..Default::default()
+        };
+        let bundle = IsometricCameraBundle::new(controller, Vec3::ZERO, 10.0);
+
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(100));
+        app.insert_resource(time)
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app.world.spawn(bundle).id();
+
+        // With zero azimuth the camera looks toward -Z, so panning right moves along +X.
+        app.world.send_event(ControlEvent::Pan(Vec2::new(3.0, 0.0)));
+        app.update();
+        let panned = *app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(panned.target.x, 0.3, epsilon = 1e-5);
+        assert_relative_eq!(panned.radius(), 10.0, epsilon = 1e-4);
+        assert_relative_eq!(
+            panned
+                .look_direction()
+                .unwrap()
+                .dot(-controller.eye_direction()),
+            1.0,
+            epsilon = 1e-5
+        );
+
+        app.update();
+        let snapped = app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(snapped.target, Vec3::ZERO);
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use super::{
+    gamepad::{GamepadCameraController, GamepadCameraReader},
+    input_config::{invert_axes, MouseInputConfig, SensitivityCurve},
+    touch::{touch_camera_system, TouchCameraConfig},
+};
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
 
 use bevy::{
     app::prelude::*,
@@ -11,31 +19,54 @@ use bevy::{
     prelude::ReflectDefault,
     reflect::Reflect,
     time::Time,
-    transform::components::Transform,
+    transform::components::{GlobalTransform, Transform},
+    window::{PrimaryWindow, Window},
 };
 
 #[derive(Default)]
 pub struct OrbitCameraPlugin {
     pub override_input_system: bool,
+    /// Applied to each [`OrbitCameraController`] as it's added, replacing its sensitivities.
+    pub sensitivity_profile: Option<SensitivityProfile>,
 }
 
 impl OrbitCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            sensitivity_profile: None,
         }
     }
+
+    pub fn with_sensitivity_profile(mut self, profile: SensitivityProfile) -> Self {
+        self.sensitivity_profile = Some(profile);
+        self
+    }
 }
 
 impl Plugin for OrbitCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
+            .register_type::<OrbitCameraController>()
+            .register_type::<GamepadCameraController>()
+            .register_type::<TouchCameraConfig>()
             .add_systems(PreUpdate, on_controller_enabled_changed)
-            .add_systems(Update, control_system)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
             .add_event::<ControlEvent>();
 
+        if let Some(profile) = self.sensitivity_profile {
+            app.add_systems(PreUpdate, apply_sensitivity_profile(profile));
+        }
+
         if !self.override_input_system {
-            app.add_systems(Update, default_input_map);
+            app.add_systems(
+                Update,
+                (default_input_map, touch_camera_system)
+                    .in_set(LookTransformSystemSet::LookTransformInput),
+            );
         }
     }
 }
@@ -74,6 +105,56 @@ pub struct OrbitCameraController {
     pub mouse_wheel_zoom_sensitivity: f32,
     pub pixels_per_line: f32,
     pub smoothing_weight: f32,
+    /// The closest the eye may zoom toward the target. Unbounded (except for a small epsilon) if `None`.
+    pub min_radius: Option<f32>,
+    /// The farthest the eye may zoom away from the target. Unbounded if `None`.
+    pub max_radius: Option<f32>,
+    /// The lowest elevation of the eye above the target, in radians relative to the plane perpendicular to `up`. Must not
+    /// be greater than `max_pitch`. Values beyond the poles are clamped to just short of them.
+    pub min_pitch: f32,
+    /// The highest elevation of the eye above the target, in radians. Set equal to `min_pitch` for a fixed pitch.
+    pub max_pitch: f32,
+    /// Keeps the eye at or above the plane through the target perpendicular to `up`, so the scene is never seen from
+    /// below. Narrows `min_pitch` and `max_pitch` to at most `[0, PI / 2]`.
+    pub constrain_to_upper_hemisphere: bool,
+    /// Keeps the eye at or below the plane through the target, like `constrain_to_upper_hemisphere`. Narrows the pitch
+    /// limits to at most `[-PI / 2, 0]`.
+    pub constrain_to_lower_hemisphere: bool,
+    /// Shapes the mouse rotation speed, e.g. for finer control when moving slowly.
+    pub sensitivity_curve: SensitivityCurve,
+    /// Scales rotation by the distance to the target, divided by `reference_distance`, like many modelling tools. The
+    /// rotation speed is unchanged at `reference_distance`, and slower when zoomed in for finer control up close.
+    ///
+    /// This works against `orbit_sensitivity_distance_scale`, and cancels out [`OrbitSensitivityScaling::Linear`]
+    /// entirely, so set one or the other.
+    pub distance_proportional_sensitivity: bool,
+    /// The distance at which `distance_proportional_sensitivity` and `orbit_sensitivity_distance_scale` leave rotation
+    /// unscaled. Rotation isn't scaled at all if it isn't positive.
+    pub reference_distance: f32,
+    /// Divides rotation by a function of the distance to the target. See [`OrbitSensitivityScaling`]. Leave
+    /// `distance_proportional_sensitivity` off when setting this.
+    pub orbit_sensitivity_distance_scale: OrbitSensitivityScaling,
+    /// Reverses horizontal rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_x: bool,
+    /// Reverses vertical rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_y: bool,
+    pub mouse: MouseInputConfig,
+    /// If set, the target follows this entity's `GlobalTransform`, and the eye moves along with it. Panning is ignored,
+    /// since the target is pinned to the entity; change `orbit_offset` instead.
+    pub orbit_entity: Option<Entity>,
+    /// The target's offset from `orbit_entity`, in world space, e.g. to orbit a little above its pivot.
+    pub orbit_offset: Vec3,
+    pub orbit_mode: OrbitMode,
+    pub pan_mode: PanMode,
+    /// Zooms toward the point under the cursor, like `PanZoom2dController`, by shifting the target along with the radius.
+    /// The point is taken on the plane through the target facing the camera, and the cursor is measured from the centre
+    /// of the primary window. Ignored with an `orbit_entity`, which pins the target.
+    pub zoom_to_cursor: bool,
+    /// The vertical field of view of the camera's projection, in radians, for finding the point under the cursor.
+    pub fov: f32,
+    /// Keeps the target at this world `Y` coordinate, e.g. sea level, so the camera orbits over a fixed horizontal plane.
+    /// The whole camera moves up or down to put the target there, keeping its look direction and radius.
+    pub horizon_lock: Option<f32>,
 }
 
 impl Default for OrbitCameraController {
@@ -85,10 +166,163 @@ impl Default for OrbitCameraController {
             smoothing_weight: 0.8,
             enabled: true,
             pixels_per_line: 53.0,
+            min_radius: None,
+            max_radius: None,
+            min_pitch: -MAX_PITCH,
+            max_pitch: MAX_PITCH,
+            constrain_to_upper_hemisphere: false,
+            constrain_to_lower_hemisphere: false,
+            sensitivity_curve: SensitivityCurve::Linear,
+            distance_proportional_sensitivity: false,
+            reference_distance: 10.0,
+            orbit_sensitivity_distance_scale: OrbitSensitivityScaling::None,
+            invert_x: false,
+            invert_y: false,
+            mouse: MouseInputConfig::default(),
+            orbit_entity: None,
+            orbit_offset: Vec3::ZERO,
+            orbit_mode: OrbitMode::Standard,
+            pan_mode: PanMode::Translate,
+            zoom_to_cursor: false,
+            fov: std::f32::consts::FRAC_PI_4,
+            horizon_lock: None,
         }
     }
 }
 
+/// How an [`OrbitCameraController`] divides its rotation speed by the distance to the target.
+///
+/// Each factor is relative to the controller's `reference_distance`, so rotation is unchanged there.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum OrbitSensitivityScaling {
+    /// A drag turns the camera by the same angle at every zoom level.
+    #[default]
+    None,
+    /// Divides by the radius. Twice as far away, a drag turns half as far, which feels steady when flying around a large
+    /// scene, but sluggish zoomed out and twitchy right up close.
+    Linear,
+    /// Divides by `ln(1 + radius)`, like CAD tools such as Fusion 360. Rotation slows noticeably from close up to mid
+    /// range, then barely changes further out, so it feels much the same at every zoom level without the extremes of
+    /// `Linear`.
+    Logarithmic,
+}
+
+impl OrbitSensitivityScaling {
+    /// The factor to divide rotation by at `radius`, `1.0` at `reference_distance`.
+    pub fn factor(self, radius: f32, reference_distance: f32) -> f32 {
+        let factor = match self {
+            Self::None => 1.0,
+            Self::Linear => radius / reference_distance,
+            Self::Logarithmic => radius.ln_1p() / reference_distance.ln_1p(),
+        };
+        if factor > 0.0 && factor.is_finite() {
+            factor
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Which end of the `LookTransform` an [`OrbitCameraController`] orbits around.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum OrbitMode {
+    /// The eye circles the target.
+    #[default]
+    Standard,
+    /// Outside-in: the target circles the eye, so the camera turns in place, e.g. for a first-person game's character
+    /// camera. Dragging turns the view the same way as in `Standard`. The radius, pitch, and hemisphere limits apply to
+    /// the target as seen from the eye, and `orbit_entity`, `orbit_offset`, and `horizon_lock` place the eye instead of
+    /// the target. `zoom_to_cursor` is ignored, since zooming only moves the target along the look direction.
+    ReverseOrbit,
+}
+
+impl OrbitMode {
+    /// The point orbited around.
+    pub fn pivot(self, transform: &LookTransform) -> Vec3 {
+        match self {
+            Self::Standard => transform.target,
+            Self::ReverseOrbit => transform.eye,
+        }
+    }
+
+    /// The `orbit_angles` of whichever end of `transform` moves around the pivot.
+    pub fn angles(self, transform: &LookTransform) -> (f32, f32) {
+        match self {
+            Self::Standard => orbit_angles(transform.eye, transform.target, transform.up),
+            Self::ReverseOrbit => orbit_angles(transform.target, transform.eye, transform.up),
+        }
+    }
+}
+
+/// What panning an [`OrbitCameraController`] moves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum PanMode {
+    /// Slides the eye and the target together in the view plane, keeping the look direction.
+    #[default]
+    Translate,
+    /// Moves only the target, keeping the eye in place and the radius the same, so the camera turns toward the new
+    /// target. This moves the centre of later orbits without moving the viewpoint, which can be surprising, but is handy
+    /// for picking a new pivot from where the camera already is.
+    PivotAdjust,
+    /// Moves only the eye, "trucking" the camera while it stays focused on the target, e.g. for architectural
+    /// walkthroughs. Unlike the other modes, this doesn't move the target, and the radius changes as a side effect, within
+    /// `min_radius` and `max_radius`.
+    PreserveTarget,
+}
+
+/// Preset mouse sensitivities for an [`OrbitCameraController`], so they don't all need tuning by hand.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum SensitivityProfile {
+    /// The controller's defaults.
+    #[default]
+    Normal,
+    /// Slower, for careful viewport work in an editor.
+    Precise,
+    /// Twice as fast, for getting around large scenes.
+    Fast,
+    /// `Normal` times a multiplier, e.g. from a game's sensitivity slider.
+    Game(f32),
+}
+
+impl SensitivityProfile {
+    /// Overwrites the controller's rotate, translate, and zoom sensitivities.
+    pub fn apply(self, controller: &mut OrbitCameraController) {
+        let scale = match self {
+            Self::Normal => 1.0,
+            Self::Precise => 0.4,
+            Self::Fast => 2.0,
+            Self::Game(multiplier) => multiplier,
+        };
+        let normal = OrbitCameraController::default();
+        controller.mouse_rotate_sensitivity = scale * normal.mouse_rotate_sensitivity;
+        controller.mouse_translate_sensitivity = scale * normal.mouse_translate_sensitivity;
+        controller.mouse_wheel_zoom_sensitivity = scale * normal.mouse_wheel_zoom_sensitivity;
+    }
+}
+
+/// A system that applies `profile` to each newly added [`OrbitCameraController`]. Added by
+/// [`OrbitCameraPlugin::with_sensitivity_profile`].
+pub fn apply_sensitivity_profile(
+    profile: SensitivityProfile,
+) -> impl FnMut(Query<&mut OrbitCameraController, Added<OrbitCameraController>>) {
+    move |mut controllers| {
+        for mut controller in controllers.iter_mut() {
+            profile.apply(&mut controller);
+        }
+    }
+}
+
+/// Things can get weird if we are parallel to the UP vector.
+const MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
 #[derive(Event)]
 pub enum ControlEvent {
     Orbit(Vec2),
@@ -98,81 +332,183 @@ pub enum ControlEvent {
 
 define_on_controller_enabled_changed!(OrbitCameraController);
 
+#[allow(clippy::too_many_arguments)]
 pub fn default_input_map(
+    time: Res<Time>,
     mut events: EventWriter<ControlEvent>,
     mut mouse_wheel_reader: EventReader<MouseWheel>,
     mut mouse_motion_events: EventReader<MouseMotion>,
     mouse_buttons: Res<Input<MouseButton>>,
     keyboard: Res<Input<KeyCode>>,
-    controllers: Query<&OrbitCameraController>,
+    gamepad: GamepadCameraReader,
+    controllers: Query<(Entity, &OrbitCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
 ) {
     // Can only control one camera at a time.
-    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
-        controller
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
     } else {
         return;
     };
+    let has_cursor = active_cameras.has_cursor(entity);
     let OrbitCameraController {
         mouse_rotate_sensitivity,
         mouse_translate_sensitivity,
         mouse_wheel_zoom_sensitivity,
         pixels_per_line,
+        sensitivity_curve,
+        mouse,
         ..
     } = *controller;
 
     let mut cursor_delta = Vec2::ZERO;
-    for event in mouse_motion_events.read() {
+    // Still drain the events while the cursor is outside of the viewport.
+    for event in mouse_motion_events.read().filter(|_| has_cursor) {
         cursor_delta += event.delta;
     }
 
-    events.send(ControlEvent::Orbit(mouse_rotate_sensitivity * cursor_delta));
+    if mouse.is_orbiting(&mouse_buttons, &keyboard) {
+        events.send(ControlEvent::Orbit(
+            mouse_rotate_sensitivity * sensitivity_curve.apply(cursor_delta),
+        ));
+    }
 
-    if mouse_buttons.pressed(MouseButton::Right) {
+    if mouse.is_panning(&mouse_buttons) {
         events.send(ControlEvent::TranslateTarget(
             mouse_translate_sensitivity * cursor_delta,
         ));
     }
 
     let mut scalar = 1.0;
-    for event in mouse_wheel_reader.read() {
+    let mut scroll_orbit = Vec2::ZERO;
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
         // scale the event magnitude per pixel or per line
-        let scroll_amount = match event.unit {
-            MouseScrollUnit::Line => event.y,
-            MouseScrollUnit::Pixel => event.y / pixels_per_line,
+        let scroll = match event.unit {
+            MouseScrollUnit::Line => Vec2::new(event.x, event.y),
+            MouseScrollUnit::Pixel => Vec2::new(event.x, event.y) / pixels_per_line,
         };
-        scalar *= 1.0 - scroll_amount * mouse_wheel_zoom_sensitivity;
+        let (zoom, orbit) = mouse.split_scroll(scroll);
+        scalar *= 1.0 - zoom * mouse_wheel_zoom_sensitivity;
+        scroll_orbit += orbit;
+    }
+    if scroll_orbit != Vec2::ZERO {
+        // Orbit as if the cursor moved a line's worth of pixels per line scrolled.
+        events.send(ControlEvent::Orbit(
+            mouse_rotate_sensitivity * pixels_per_line * scroll_orbit,
+        ));
+    }
+
+    if let Some(gamepad) = gamepad.read() {
+        // Sticks point up for positive Y, whereas the mouse moves down.
+        let look = gamepad.look_axis;
+        events.send(ControlEvent::Orbit(Vec2::new(look.x, -look.y)));
+
+        // Pan the target in the direction the stick is pushed.
+        let pan = gamepad.move_axis;
+        events.send(ControlEvent::TranslateTarget(Vec2::new(-pan.x, pan.y)));
+
+        scalar *= 1.0 - gamepad.zoom * time.delta_seconds();
     }
+
     events.send(ControlEvent::Zoom(scalar));
 }
 
 pub fn control_system(
     time: Res<Time>,
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&OrbitCameraController, &mut LookTransform, &Transform)>,
+    mut cameras: Query<(Entity, &OrbitCameraController, &mut LookTransform), ControllableCamera>,
+    entities: Query<&GlobalTransform>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    active_cameras: ActiveCameras,
 ) {
-    // Can only control one camera at a time.
-    let (mut transform, scene_transform) =
-        if let Some((_, transform, scene_transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-            (transform, scene_transform)
-        } else {
-            return;
+    for (_, controller, mut transform) in cameras.iter_mut() {
+        let Some(entity) = controller.orbit_entity else {
+            continue;
+        };
+        let Ok(entity_transform) = entities.get(entity) else {
+            continue;
         };
+        let delta = entity_transform.translation() + controller.orbit_offset
+            - controller.orbit_mode.pivot(&transform);
+        if delta != Vec3::ZERO {
+            *transform = transform.translate(delta);
+        }
+    }
+
+    // Can only control one camera at a time.
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
+    } else {
+        return;
+    };
 
-    let mut look_angles = LookAngles::from_vector(-transform.look_direction().unwrap());
+    let mode = controller.orbit_mode;
+    let (mut pitch, mut yaw) = mode.angles(&transform);
     let mut radius_scalar = 1.0;
     let radius = transform.radius();
 
+    // `factor` falls back to `1.0` for a degenerate `reference_distance`.
+    let orbit_scale = if controller.distance_proportional_sensitivity {
+        OrbitSensitivityScaling::Linear.factor(radius, controller.reference_distance)
+    } else {
+        1.0
+    } / controller
+        .orbit_sensitivity_distance_scale
+        .factor(radius, controller.reference_distance);
+
     let dt = time.delta_seconds();
     for event in events.read() {
         match event {
             ControlEvent::Orbit(delta) => {
-                look_angles.add_yaw(dt * -delta.x);
-                look_angles.add_pitch(dt * delta.y);
+                let delta =
+                    orbit_scale * invert_axes(*delta, controller.invert_x, controller.invert_y);
+                yaw += dt * -delta.x;
+                // Raising the target looks up, where raising the eye looks down.
+                pitch += match mode {
+                    OrbitMode::Standard => dt * delta.y,
+                    OrbitMode::ReverseOrbit => dt * -delta.y,
+                };
             }
+            ControlEvent::TranslateTarget(_) if controller.orbit_entity.is_some() => {}
             ControlEvent::TranslateTarget(delta) => {
-                let right_dir = scene_transform.rotation * -Vec3::X;
-                let up_dir = scene_transform.rotation * Vec3::Y;
-                transform.target += dt * delta.x * right_dir + dt * delta.y * up_dir;
+                let (Some(right_dir), Some(up_dir)) = (transform.right(), transform.local_up())
+                else {
+                    continue;
+                };
+                let pan = dt * delta.x * -right_dir + dt * delta.y * up_dir;
+                match controller.pan_mode {
+                    PanMode::Translate => *transform = transform.translate(pan),
+                    PanMode::PivotAdjust => {
+                        // Keep the eye where it is by turning the orbit angles as much as the target moved.
+                        let (old_pitch, old_yaw) = mode.angles(&transform);
+                        let Some(dir) = (transform.target + pan - transform.eye).try_normalize()
+                        else {
+                            continue;
+                        };
+                        transform.target = transform.eye + radius * dir;
+                        let (new_pitch, new_yaw) = mode.angles(&transform);
+                        pitch += new_pitch - old_pitch;
+                        yaw += new_yaw - old_yaw;
+                    }
+                    PanMode::PreserveTarget => {
+                        // The eye is only written at the end, so carry the move in the orbit angles and radius.
+                        let (old_pitch, old_yaw) = mode.angles(&transform);
+                        let old_radius = transform.radius();
+                        transform.eye += pan;
+                        let (new_pitch, new_yaw) = mode.angles(&transform);
+                        pitch += new_pitch - old_pitch;
+                        yaw += new_yaw - old_yaw;
+                        if old_radius > 0.0 {
+                            radius_scalar *= transform.radius() / old_radius;
+                        }
+                    }
+                }
             }
             ControlEvent::Zoom(scalar) => {
                 radius_scalar *= scalar;
@@ -180,8 +516,477 @@ pub fn control_system(
         }
     }
 
-    look_angles.assert_not_looking_up();
+    let mut min_pitch = controller.min_pitch.max(-MAX_PITCH);
+    let mut max_pitch = controller.max_pitch.min(MAX_PITCH);
+    if controller.constrain_to_upper_hemisphere {
+        min_pitch = min_pitch.max(0.0);
+    }
+    if controller.constrain_to_lower_hemisphere {
+        max_pitch = max_pitch.min(0.0);
+    }
+    // Clamped as an angle, before converting back to a position, so the eye can rest exactly on the plane.
+    let pitch = pitch.clamp(min_pitch, max_pitch.max(min_pitch));
+
+    let min_radius = controller.min_radius.unwrap_or(0.001);
+    let max_radius = controller.max_radius.unwrap_or(1000000.0);
+    let new_radius = (radius_scalar * radius).clamp(min_radius, max_radius);
+    let zoom_to_cursor = controller.zoom_to_cursor && mode == OrbitMode::Standard;
+    if zoom_to_cursor && controller.orbit_entity.is_none() && new_radius != radius {
+        if let Some(shift) = cursor_zoom_shift(&transform, &windows, controller.fov, new_radius) {
+            transform.target += shift;
+        }
+    }
+    let offset = new_radius * orbit_direction(transform.up, pitch, yaw);
+    match mode {
+        OrbitMode::Standard => {
+            if let Some(height) = controller.horizon_lock {
+                transform.target.y = height;
+            }
+            transform.eye = transform.target + offset;
+        }
+        OrbitMode::ReverseOrbit => {
+            if let Some(height) = controller.horizon_lock {
+                transform.eye.y = height;
+            }
+            transform.target = transform.eye + offset;
+        }
+    }
+}
+
+/// How far to move the target so the point under the cursor stays in place while the radius changes to `new_radius`.
+fn cursor_zoom_shift(
+    transform: &LookTransform,
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    fov: f32,
+    new_radius: f32,
+) -> Option<Vec3> {
+    let window = windows.get_single().ok()?;
+    let cursor = window.cursor_position()?;
+    let (right, up) = (transform.right()?, transform.local_up()?);
+    let radius = transform.radius();
+    if window.height() <= 0.0 || radius <= 0.0 {
+        return None;
+    }
+
+    let units_per_pixel = 2.0 * radius * (0.5 * fov).tan() / window.height();
+    let from_center = cursor - 0.5 * Vec2::new(window.width(), window.height());
+    // The cursor's Y axis points down the window.
+    let cursor_offset = units_per_pixel * (from_center.x * right - from_center.y * up);
+
+    Some(cursor_offset * (1.0 - new_radius / radius))
+}
+
+/// Returns the (pitch, yaw) of `eye` around `target`, in radians. Pitch is the elevation above the plane perpendicular to
+/// `up`. Yaw is measured in that plane, and is zero when `up` is `Vec3::Y` and the eye is on the +Z side of the target.
+pub fn orbit_angles(eye: Vec3, target: Vec3, up: Vec3) -> (f32, f32) {
+    let (forward, side) = orbit_basis(up);
+    let up = up.normalize();
+    let v = (eye - target).try_normalize().unwrap_or(forward);
+
+    let pitch = v.dot(up).clamp(-1.0, 1.0).asin();
+    let yaw = v.dot(side).atan2(v.dot(forward));
+
+    (pitch, yaw)
+}
+
+/// The unit vector from the target toward the eye for the given `orbit_angles`.
+pub fn orbit_direction(up: Vec3, pitch: f32, yaw: f32) -> Vec3 {
+    let (forward, side) = orbit_basis(up);
+    let horizontal = yaw.cos() * forward + yaw.sin() * side;
+
+    pitch.cos() * horizontal + pitch.sin() * up.normalize()
+}
+
+/// Two axes spanning the plane perpendicular to `up`. These are `Vec3::Z` and `Vec3::X` when `up` is `Vec3::Y`.
+fn orbit_basis(up: Vec3) -> (Vec3, Vec3) {
+    let rotation = Quat::from_rotation_arc(Vec3::Y, up.normalize());
+
+    (rotation * Vec3::Z, rotation * Vec3::X)
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AllCameraControllersDisabled, CameraTransition};
+
+    use approx::assert_relative_eq;
+
+    fn run_control_system(
+        controller: OrbitCameraController,
+        transform: LookTransform,
+        events: impl IntoIterator<Item = ControlEvent>,
+    ) -> LookTransform {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app.world.spawn((controller, transform)).id();
+        for event in events {
+            app.world.send_event(event);
+        }
+        app.update();
+
+        *app.world.get::<LookTransform>(camera).unwrap()
+    }
+
+    #[test]
+    fn test_sensitivity_profile_applies_to_new_controllers() {
+        let mut app = App::new();
+        app.add_systems(
+            PreUpdate,
+            apply_sensitivity_profile(SensitivityProfile::Game(3.0)),
+        );
+        let camera = app.world.spawn(OrbitCameraController::default()).id();
+        app.update();
 
-    let new_radius = (radius_scalar * radius).min(1000000.0).max(0.001);
-    transform.eye = transform.target + new_radius * look_angles.unit_vector();
+        let normal = OrbitCameraController::default();
+        let controller = *app.world.get::<OrbitCameraController>(camera).unwrap();
+        assert_eq!(
+            controller.mouse_rotate_sensitivity,
+            3.0 * normal.mouse_rotate_sensitivity
+        );
+        assert_eq!(
+            controller.mouse_translate_sensitivity,
+            3.0 * normal.mouse_translate_sensitivity
+        );
+        assert_relative_eq!(
+            controller.mouse_wheel_zoom_sensitivity,
+            3.0 * normal.mouse_wheel_zoom_sensitivity
+        );
+
+        // Later tweaks are kept.
+        app.world
+            .get_mut::<OrbitCameraController>(camera)
+            .unwrap()
+            .mouse_wheel_zoom_sensitivity = 1.0;
+        app.update();
+        let controller = app.world.get::<OrbitCameraController>(camera).unwrap();
+        assert_eq!(controller.mouse_wheel_zoom_sensitivity, 1.0);
+
+        let mut precise = normal;
+        SensitivityProfile::Precise.apply(&mut precise);
+        assert!(precise.mouse_rotate_sensitivity.x < normal.mouse_rotate_sensitivity.x);
+    }
+
+    #[test]
+    fn test_reverse_orbit_moves_target_around_eye() {
+        let controller = OrbitCameraController {
+            orbit_mode: OrbitMode::ReverseOrbit,
+            max_pitch: 0.0,
+            ..Default::default()
+        };
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let start = LookTransform::new(eye, eye + Vec3::new(0.0, 4.0, -4.0), Vec3::Y);
+
+        // The target is held to the eye's level and pulled in, while the eye stays put.
+        let end = run_control_system(controller, start, [ControlEvent::Zoom(0.5)]);
+        assert_eq!(end.eye, eye);
+        assert!(end
+            .target
+            .abs_diff_eq(eye + Vec3::new(0.0, 0.0, -8f32.sqrt()), 1e-4));
+    }
+
+    #[test]
+    fn test_radius_limits_keep_look_direction() {
+        let controller = OrbitCameraController {
+            min_radius: Some(5.0),
+            max_radius: Some(10.0),
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(1.0, 1.0, 1.0), Vec3::ZERO, Vec3::Y);
+        let start_dir = start.look_direction().unwrap();
+
+        let zoomed_in = run_control_system(controller, start, [ControlEvent::Zoom(0.5)]);
+        assert_relative_eq!(zoomed_in.radius(), 5.0, epsilon = 1e-4);
+        assert_relative_eq!(
+            zoomed_in.look_direction().unwrap().dot(start_dir),
+            1.0,
+            epsilon = 1e-5
+        );
+
+        let zoomed_out = run_control_system(controller, start, [ControlEvent::Zoom(100.0)]);
+        assert_relative_eq!(zoomed_out.radius(), 10.0, epsilon = 1e-4);
+        assert_relative_eq!(
+            zoomed_out.look_direction().unwrap().dot(start_dir),
+            1.0,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_follows_orbit_entity() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let entity = app
+            .world
+            .spawn(GlobalTransform::from_translation(Vec3::new(
+                0.0, 0.0, -10.0,
+            )))
+            .id();
+        let controller = OrbitCameraController {
+            orbit_entity: Some(entity),
+            orbit_offset: Vec3::Y,
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let camera = app.world.spawn((controller, start)).id();
+
+        app.world
+            .send_event(ControlEvent::TranslateTarget(Vec2::new(100.0, 0.0)));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(end.target.abs_diff_eq(Vec3::new(0.0, 1.0, -10.0), 1e-5));
+        assert!(end.eye.abs_diff_eq(Vec3::new(0.0, 1.0, -5.0), 1e-4));
+    }
+
+    #[test]
+    fn test_orbit_angles_round_trip_with_z_up() {
+        let target = Vec3::new(1.0, 2.0, 3.0);
+        let eye = target + Vec3::new(2.0, -1.0, 3.0);
+
+        let (pitch, yaw) = orbit_angles(eye, target, Vec3::Z);
+        let radius = (eye - target).length();
+        let rebuilt = target + radius * orbit_direction(Vec3::Z, pitch, yaw);
+
+        assert_relative_eq!(pitch, (3.0 / radius).asin(), epsilon = 1e-5);
+        assert_relative_eq!(rebuilt.x, eye.x, epsilon = 1e-4);
+        assert_relative_eq!(rebuilt.y, eye.y, epsilon = 1e-4);
+        assert_relative_eq!(rebuilt.z, eye.z, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_fixed_pitch() {
+        let controller = OrbitCameraController {
+            min_pitch: 0.5,
+            max_pitch: 0.5,
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 3.0, 4.0), Vec3::ZERO, Vec3::Y);
+
+        let end = run_control_system(
+            controller,
+            start,
+            [ControlEvent::Orbit(Vec2::new(1.0, 1.0))],
+        );
+        let (pitch, _) = orbit_angles(end.eye, end.target, end.up);
+        assert_relative_eq!(pitch, 0.5, epsilon = 1e-5);
+        assert_relative_eq!(end.radius(), 5.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_hemisphere_constraints() {
+        let below = LookTransform::new(Vec3::new(0.0, -3.0, 4.0), Vec3::ZERO, Vec3::Y);
+        let upper = OrbitCameraController {
+            constrain_to_upper_hemisphere: true,
+            ..Default::default()
+        };
+
+        let end = run_control_system(upper, below, []);
+        assert_relative_eq!(end.eye.y, 0.0, epsilon = 1e-5);
+        assert_relative_eq!(end.radius(), 5.0, epsilon = 1e-4);
+
+        let lower = OrbitCameraController {
+            constrain_to_lower_hemisphere: true,
+            ..Default::default()
+        };
+        assert!(run_control_system(lower, below, []).abs_diff_eq(&below, 1e-5));
+    }
+
+    #[test]
+    fn test_distance_proportional_sensitivity() {
+        let yaw_after_orbit = |radius: f32, reference_distance: f32| {
+            let controller = OrbitCameraController {
+                distance_proportional_sensitivity: true,
+                reference_distance,
+                ..Default::default()
+            };
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .add_event::<ControlEvent>()
+                .add_systems(Update, control_system);
+            let start = LookTransform::new(Vec3::new(0.0, 0.0, radius), Vec3::ZERO, Vec3::Y);
+            let camera = app.world.spawn((controller, start)).id();
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.1));
+            app.world
+                .send_event(ControlEvent::Orbit(Vec2::new(-1.0, 0.0)));
+            app.update();
+
+            let end = app.world.get::<LookTransform>(camera).unwrap();
+            orbit_angles(end.eye, end.target, end.up).1
+        };
+
+        assert_relative_eq!(yaw_after_orbit(10.0, 10.0), 0.1, epsilon = 1e-5);
+        assert_relative_eq!(yaw_after_orbit(5.0, 10.0), 0.05, epsilon = 1e-5);
+        // A degenerate reference distance leaves rotation alone.
+        assert_relative_eq!(yaw_after_orbit(5.0, 0.0), 0.1, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_orbit_sensitivity_scaling() {
+        let reference = 10.0;
+        for scaling in [
+            OrbitSensitivityScaling::None,
+            OrbitSensitivityScaling::Linear,
+            OrbitSensitivityScaling::Logarithmic,
+        ] {
+            assert_relative_eq!(scaling.factor(reference, reference), 1.0);
+        }
+        assert_relative_eq!(OrbitSensitivityScaling::None.factor(40.0, reference), 1.0);
+        assert_relative_eq!(OrbitSensitivityScaling::Linear.factor(40.0, reference), 4.0);
+        assert_relative_eq!(
+            OrbitSensitivityScaling::Logarithmic.factor(40.0, reference),
+            41f32.ln() / 11f32.ln()
+        );
+        // A degenerate reference distance leaves rotation alone.
+        assert_relative_eq!(OrbitSensitivityScaling::Logarithmic.factor(5.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_horizon_lock() {
+        let controller = OrbitCameraController {
+            horizon_lock: Some(0.0),
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 6.0, 4.0), Vec3::new(0.0, 3.0, 0.0), Vec3::Y);
+
+        let end = run_control_system(
+            controller,
+            start,
+            [ControlEvent::TranslateTarget(Vec2::new(0.0, 100.0))],
+        );
+        assert!(end.target.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(end.eye.abs_diff_eq(Vec3::new(0.0, 3.0, 4.0), 1e-4));
+    }
+
+    #[test]
+    fn test_zoom_to_cursor() {
+        let controller = OrbitCameraController {
+            zoom_to_cursor: true,
+            fov: std::f32::consts::FRAC_PI_2,
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        // The cursor is 50 pixels right of the centre, which is 10 units at the target with a 90 degree field of view.
+        let mut window = Window::default();
+        window.resolution.set(200.0, 100.0);
+        window.set_cursor_position(Some(Vec2::new(150.0, 50.0)));
+        app.world.spawn((window, PrimaryWindow));
+        let camera = app.world.spawn((controller, start)).id();
+        app.world.send_event(ControlEvent::Zoom(0.5));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(end.target.abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-4));
+        assert!(end.eye.abs_diff_eq(Vec3::new(5.0, 0.0, 5.0), 1e-4));
+    }
+
+    #[test]
+    fn test_pivot_adjust_keeps_eye() {
+        let controller = OrbitCameraController {
+            pan_mode: PanMode::PivotAdjust,
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app.world.spawn((controller, start)).id();
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(1.0));
+        app.world
+            .send_event(ControlEvent::TranslateTarget(Vec2::new(-1.0, 0.0)));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(end.eye.abs_diff_eq(start.eye, 1e-4));
+        assert_relative_eq!(end.radius(), 5.0, epsilon = 1e-4);
+        assert!(end.target.x > 0.5);
+    }
+
+    #[test]
+    fn test_preserve_target_trucks_eye() {
+        let controller = OrbitCameraController {
+            pan_mode: PanMode::PreserveTarget,
+            max_radius: Some(5.5),
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app.world.spawn((controller, start)).id();
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(1.0));
+        app.world
+            .send_event(ControlEvent::TranslateTarget(Vec2::new(-3.0, 0.0)));
+        app.update();
+
+        // The eye moves right, still looking at the target, but no farther than `max_radius`.
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(end.target, Vec3::ZERO);
+        assert_relative_eq!(end.radius(), 5.5, epsilon = 1e-4);
+        assert_relative_eq!(end.eye.x / end.eye.z, 3.0 / 5.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_input_skips_transitioning_and_disabled_cameras() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Input<MouseButton>>()
+            .init_resource::<Input<KeyCode>>()
+            .init_resource::<Gamepads>()
+            .init_resource::<Axis<GamepadAxis>>()
+            .init_resource::<Input<GamepadButton>>()
+            .add_event::<MouseMotion>()
+            .add_event::<MouseWheel>()
+            .add_event::<ControlEvent>()
+            .add_systems(Update, (default_input_map, control_system).chain());
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        // Its bindings would ignore the mouse motion, since no button is held.
+        let skipped = OrbitCameraController {
+            mouse: MouseInputConfig::drag_to_orbit(MouseButton::Left),
+            ..Default::default()
+        };
+        app.world
+            .spawn((skipped, start, CameraTransition::default()));
+        app.world
+            .spawn((skipped, start, AllCameraControllersDisabled));
+        let camera = app
+            .world
+            .spawn((OrbitCameraController::default(), start))
+            .id();
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+        app.world.send_event(MouseMotion {
+            delta: Vec2::new(10.0, 0.0),
+        });
+        app.update();
+
+        assert!(app.world.get::<LookTransform>(camera).unwrap().eye.x.abs() > 1e-3);
+    }
 }
@@ -0,0 +1,216 @@
+use super::{
+    input_config::{invert_axes, MouseInputConfig, SensitivityCurve},
+    orbit::orbit_direction,
+};
+use crate::{ActiveCameras, ControllableCamera, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    transform::components::GlobalTransform,
+};
+
+#[derive(Default)]
+pub struct OrbitAroundEntityPlugin {
+    pub override_input_system: bool,
+}
+
+impl OrbitAroundEntityPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for OrbitAroundEntityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<OrbitAroundEntity>().add_systems(
+            Update,
+            orbit_around_entity_system.in_set(LookTransformSystemSet::LookTransformInput),
+        );
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                default_input_map
+                    .before(orbit_around_entity_system)
+                    .in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+/// Orbits the camera around another entity, following it as it moves. Unlike the orbit camera, the target is taken from
+/// the entity's `GlobalTransform` every frame.
+///
+/// The `pitch` and `yaw` follow the same conventions as [`orbit_angles`](super::orbit::orbit_angles) with `Vec3::Y` as
+/// up. With the default input map, drag with the left mouse button to orbit and scroll to zoom.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct OrbitAroundEntity {
+    pub entity: Entity,
+    pub radius: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub min_radius: Option<f32>,
+    pub max_radius: Option<f32>,
+    pub mouse_rotate_sensitivity: Vec2,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    /// Shapes the mouse rotation speed, e.g. for finer control when moving slowly.
+    pub sensitivity_curve: SensitivityCurve,
+    /// Reverses horizontal rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_x: bool,
+    /// Reverses vertical rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_y: bool,
+    pub mouse: MouseInputConfig,
+}
+
+impl Default for OrbitAroundEntity {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, 5.0)
+    }
+}
+
+impl OrbitAroundEntity {
+    pub fn new(entity: Entity, radius: f32) -> Self {
+        Self {
+            entity,
+            radius,
+            pitch: 0.5,
+            yaw: 0.0,
+            min_radius: None,
+            max_radius: None,
+            mouse_rotate_sensitivity: Vec2::splat(0.08),
+            mouse_wheel_zoom_sensitivity: 0.2,
+            pixels_per_line: 53.0,
+            sensitivity_curve: SensitivityCurve::Linear,
+            invert_x: false,
+            invert_y: false,
+            mouse: MouseInputConfig::drag_to_orbit(MouseButton::Left),
+        }
+    }
+}
+
+/// Things can get weird if we are parallel to the UP vector.
+const MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+pub fn default_input_map(
+    time: Res<Time>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keyboard: Res<Input<KeyCode>>,
+    mut cameras: Query<(Entity, &mut OrbitAroundEntity), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.read() {
+        cursor_delta += event.delta;
+    }
+    let mut scroll = Vec::new();
+    for event in mouse_wheel_reader.read() {
+        scroll.push((event.unit, Vec2::new(event.x, event.y)));
+    }
+
+    let dt = time.delta_seconds();
+    for (_, mut orbit) in cameras.iter_mut().filter(|(entity, _)| {
+        active_cameras.contains(*entity) && active_cameras.has_cursor(*entity)
+    }) {
+        let mut orbit_delta = Vec2::ZERO;
+        if orbit.mouse.is_orbiting(&mouse_buttons, &keyboard) {
+            orbit_delta += orbit.sensitivity_curve.apply(cursor_delta);
+        }
+
+        for &(unit, amount) in &scroll {
+            // scale the event magnitude per pixel or per line
+            let amount = match unit {
+                MouseScrollUnit::Line => amount,
+                MouseScrollUnit::Pixel => amount / orbit.pixels_per_line,
+            };
+            let (zoom, scroll_orbit) = orbit.mouse.split_scroll(amount);
+            orbit.radius *= 1.0 - zoom * orbit.mouse_wheel_zoom_sensitivity;
+            // Orbit as if the cursor moved a line's worth of pixels per line scrolled.
+            orbit_delta += orbit.pixels_per_line * scroll_orbit;
+        }
+
+        let delta = orbit.mouse_rotate_sensitivity
+            * invert_axes(orbit_delta, orbit.invert_x, orbit.invert_y);
+        orbit.yaw -= dt * delta.x;
+        orbit.pitch += dt * delta.y;
+    }
+}
+
+pub fn orbit_around_entity_system(
+    targets: Query<&GlobalTransform>,
+    mut cameras: Query<(&mut OrbitAroundEntity, &mut LookTransform), ControllableCamera>,
+) {
+    for (mut orbit, mut transform) in cameras.iter_mut() {
+        let Ok(target) = targets.get(orbit.entity) else {
+            continue;
+        };
+
+        orbit.pitch = orbit.pitch.clamp(-MAX_PITCH, MAX_PITCH);
+        let min_radius = orbit.min_radius.unwrap_or(0.001);
+        let max_radius = orbit.max_radius.unwrap_or(1000000.0);
+        orbit.radius = orbit.radius.clamp(min_radius, max_radius);
+
+        let target = target.translation();
+        *transform = LookTransform::new(
+            target + orbit.radius * orbit_direction(Vec3::Y, orbit.pitch, orbit.yaw),
+            target,
+            Vec3::Y,
+        );
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_follows_entity_within_radius_limits() {
+        let mut app = App::new();
+        app.add_systems(Update, orbit_around_entity_system);
+
+        let target = Vec3::new(1.0, 2.0, 3.0);
+        let entity = app
+            .world
+            .spawn(GlobalTransform::from_translation(target))
+            .id();
+        let camera = app
+            .world
+            .spawn((
+                OrbitAroundEntity {
+                    max_radius: Some(4.0),
+                    ..OrbitAroundEntity::new(entity, 10.0)
+                },
+                LookTransform::default(),
+            ))
+            .id();
+        app.update();
+
+        let transform = app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(transform.target, target);
+        assert_relative_eq!(transform.radius(), 4.0, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,328 @@
+use super::input_config::{KeyboardInputConfig, MouseInputConfig};
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    log::error,
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{OrthographicProjection, Projection},
+    time::Time,
+    transform::components::Transform,
+};
+
+#[derive(Default)]
+pub struct OrthographicCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl OrthographicCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for OrthographicCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<OrthographicCameraController>()
+            .add_systems(
+                PreUpdate,
+                (on_controller_enabled_changed, check_projection_system),
+            )
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
+            .add_event::<ControlEvent>();
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+/// Spawn this alongside an orthographic camera, e.g. a `Camera2dBundle`, or a `Camera3dBundle` with an orthographic
+/// `Projection`.
+#[derive(Bundle)]
+pub struct OrthographicCameraBundle {
+    controller: OrthographicCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl OrthographicCameraBundle {
+    pub fn new(
+        controller: OrthographicCameraController,
+        eye: Vec3,
+        target: Vec3,
+        up: Vec3,
+    ) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, up);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, up),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// Pans an orthographic camera in its view plane and zooms by changing `OrthographicProjection::scale` instead of moving
+/// the `eye`.
+///
+/// The `Smoother` only smooths the pan; the scale changes immediately.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct OrthographicCameraController {
+    /// Whether to process input or ignore it
+    pub enabled: bool,
+    /// Scale factor per scroll wheel line
+    pub zoom_speed: f32,
+    /// Screen pixels per second when panning with the keyboard, at any scale
+    pub pan_speed: f32,
+    pub min_scale: f32,
+    pub max_scale: f32,
+    pub pixels_per_line: f32,
+    /// The greater, the slower to follow input
+    pub smoothing_weight: f32,
+    /// Only the pan bindings and `zoom_scroll` apply.
+    pub mouse: MouseInputConfig,
+    pub keyboard: KeyboardInputConfig,
+}
+
+impl Default for OrthographicCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            zoom_speed: 0.1,
+            pan_speed: 500.0,
+            min_scale: 0.1,
+            max_scale: 10.0,
+            pixels_per_line: 53.0,
+            smoothing_weight: 0.8,
+            mouse: MouseInputConfig::drag_to_pan(MouseButton::Left),
+            keyboard: KeyboardInputConfig::wasd_and_arrows(),
+        }
+    }
+}
+
+#[derive(Event)]
+pub enum ControlEvent {
+    /// Pans along the camera's (right, up) directions by this many screen pixels, assuming the default
+    /// `ScalingMode::WindowSize(1.0)`.
+    Pan(Vec2),
+    /// Multiplies the projection scale.
+    Zoom(f32),
+}
+
+define_on_controller_enabled_changed!(OrthographicCameraController);
+
+#[allow(clippy::type_complexity)]
+fn check_projection_system(
+    cameras: Query<
+        (Entity, Option<&OrthographicProjection>, Option<&Projection>),
+        Added<OrthographicCameraController>,
+    >,
+) {
+    for (entity, orthographic, projection) in cameras.iter() {
+        if orthographic_scale(orthographic, projection).is_none() {
+            error!(
+                "OrthographicCameraController on {:?}, which doesn't have an orthographic projection",
+                entity
+            );
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn default_input_map(
+    time: Res<Time>,
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keyboard: Res<Input<KeyCode>>,
+    controllers: Query<(Entity, &OrthographicCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
+    } else {
+        return;
+    };
+    let has_cursor = active_cameras.has_cursor(entity);
+    let OrthographicCameraController {
+        zoom_speed,
+        pan_speed,
+        pixels_per_line,
+        ..
+    } = *controller;
+
+    let pan = controller.keyboard.pan_direction(&keyboard);
+    let mut pan = pan.normalize_or_zero() * pan_speed * time.delta_seconds();
+
+    let mouse_delta: Vec2 = mouse_motion_events
+        .read()
+        .filter(|_| has_cursor)
+        .map(|event| event.delta)
+        .sum();
+    if controller.mouse.is_panning(&mouse_buttons) {
+        // Drag the scene along with the cursor. The cursor's Y axis points down the window.
+        pan += Vec2::new(-mouse_delta.x, mouse_delta.y);
+    }
+    if pan != Vec2::ZERO {
+        events.send(ControlEvent::Pan(pan));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
+        // scale the event magnitude per pixel or per line
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / pixels_per_line,
+        };
+        if controller.mouse.zoom_scroll {
+            scalar *= 1.0 - scroll_amount * zoom_speed;
+        }
+    }
+    if scalar != 1.0 {
+        events.send(ControlEvent::Zoom(scalar));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn control_system(
+    mut events: EventReader<ControlEvent>,
+    mut cameras: Query<
+        (
+            Entity,
+            &OrthographicCameraController,
+            &mut LookTransform,
+            Option<&mut OrthographicProjection>,
+            Option<&mut Projection>,
+        ),
+        ControllableCamera,
+    >,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let Some((_, controller, mut transform, orthographic, mut projection)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    else {
+        return;
+    };
+    // `check_projection_system` has already complained if there's no orthographic projection.
+    let Some(mut scale) = orthographic_scale(orthographic.as_deref(), projection.as_deref()) else {
+        return;
+    };
+    let (Some(right), Some(up)) = (transform.right(), transform.local_up()) else {
+        return;
+    };
+
+    let mut zoomed = false;
+    for event in events.read() {
+        match event {
+            ControlEvent::Pan(delta) => {
+                // Preserve the eye-target offset.
+                *transform = transform.translate(scale * (delta.x * right + delta.y * up));
+            }
+            ControlEvent::Zoom(scalar) => {
+                scale = (scale * scalar).clamp(controller.min_scale, controller.max_scale);
+                zoomed = true;
+            }
+        }
+    }
+
+    // Only write the projection when it changes, so the camera doesn't recompute it every frame.
+    if zoomed {
+        if let Some(mut orthographic) = orthographic {
+            orthographic.scale = scale;
+        } else if let Some(Projection::Orthographic(orthographic)) = projection.as_deref_mut() {
+            orthographic.scale = scale;
+        }
+    }
+}
+
+fn orthographic_scale(
+    orthographic: Option<&OrthographicProjection>,
+    projection: Option<&Projection>,
+) -> Option<f32> {
+    match (orthographic, projection) {
+        (Some(orthographic), _) => Some(orthographic.scale),
+        (None, Some(Projection::Orthographic(orthographic))) => Some(orthographic.scale),
+        _ => None,
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_zoom_scales_projection_and_pan_follows_scale() {
+        let controller = OrthographicCameraController {
+            max_scale: 3.0,
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        app.add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app
+            .world
+            .spawn((controller, start, OrthographicProjection::default()))
+            .id();
+
+        app.world.send_event(ControlEvent::Zoom(2.0));
+        app.update();
+        app.world.send_event(ControlEvent::Zoom(2.0));
+        app.world
+            .send_event(ControlEvent::Pan(Vec2::new(10.0, 0.0)));
+        app.update();
+
+        let scale = app
+            .world
+            .get::<OrthographicProjection>(camera)
+            .unwrap()
+            .scale;
+        assert_relative_eq!(scale, 3.0);
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(end.eye.abs_diff_eq(Vec3::new(30.0, 0.0, 10.0), 1e-5));
+        assert!(end.target.abs_diff_eq(Vec3::new(30.0, 0.0, 0.0), 1e-5));
+    }
+}
@@ -0,0 +1,288 @@
+use super::input_config::MouseInputConfig;
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    transform::components::Transform,
+    window::{PrimaryWindow, Window},
+};
+
+#[derive(Default)]
+pub struct PanZoom2dPlugin {
+    pub override_input_system: bool,
+}
+
+impl PanZoom2dPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for PanZoom2dPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<PanZoom2dController>()
+            .add_systems(PreUpdate, on_controller_enabled_changed)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
+            .add_event::<ControlEvent>();
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct PanZoom2dBundle {
+    controller: PanZoom2dController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl PanZoom2dBundle {
+    /// The `eye` is placed `distance` units in front of the `target`, on `+Z`.
+    pub fn new(controller: PanZoom2dController, target: Vec3, distance: f32) -> Self {
+        let eye = target + distance * Vec3::Z;
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, Vec3::Y),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// A perspective camera over a 2D world on the XY plane, which pans by dragging and zooms toward the cursor.
+///
+/// The camera is assumed to face `Vec3::NEG_Z` with `+Y` up, like Bevy's 2D cameras, so world `+Y` is up the screen
+/// while the cursor's `+Y` is down the window. The `eye` always stays directly in front of the `target`; only its
+/// distance along `Z` changes when zooming.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct PanZoom2dController {
+    /// Whether to process input or ignore it
+    pub enabled: bool,
+    pub mouse: MouseInputConfig,
+    /// Zoom factor per scroll wheel line
+    pub zoom_speed: f32,
+    /// The closest the `eye` gets to the `target`.
+    pub min_zoom: f32,
+    /// The farthest the `eye` gets from the `target`.
+    pub max_zoom: f32,
+    /// The vertical field of view of the camera's projection, in radians, for converting between pixels and world units.
+    pub fov: f32,
+    pub pixels_per_line: f32,
+    /// The greater, the slower to follow input
+    pub smoothing_weight: f32,
+}
+
+impl Default for PanZoom2dController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mouse: MouseInputConfig::drag_to_pan(MouseButton::Left),
+            zoom_speed: 0.1,
+            min_zoom: 1.0,
+            max_zoom: 1000.0,
+            fov: std::f32::consts::FRAC_PI_4,
+            pixels_per_line: 53.0,
+            smoothing_weight: 0.8,
+        }
+    }
+}
+
+#[derive(Event)]
+pub enum ControlEvent {
+    /// Drags the world by this many window pixels, so the point under the cursor follows the cursor.
+    Pan(Vec2),
+    /// Multiplies the distance from the target, keeping the world point under `cursor` in place. The `cursor` is in
+    /// window coordinates; if it's `None`, zooms toward the center of the window.
+    Zoom { scalar: f32, cursor: Option<Vec2> },
+}
+
+define_on_controller_enabled_changed!(PanZoom2dController);
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    controllers: Query<(Entity, &PanZoom2dController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
+    } else {
+        return;
+    };
+    let has_cursor = active_cameras.has_cursor(entity);
+    let PanZoom2dController {
+        mouse,
+        zoom_speed,
+        pixels_per_line,
+        ..
+    } = *controller;
+
+    let cursor_delta: Vec2 = mouse_motion_events
+        .read()
+        .filter(|_| has_cursor)
+        .map(|event| event.delta)
+        .sum();
+    if mouse.is_panning(&mouse_buttons) && cursor_delta != Vec2::ZERO {
+        events.send(ControlEvent::Pan(cursor_delta));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
+        // scale the event magnitude per pixel or per line
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / pixels_per_line,
+        };
+        scalar *= 1.0 - scroll_amount * zoom_speed;
+    }
+    if scalar != 1.0 {
+        let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+        events.send(ControlEvent::Zoom { scalar, cursor });
+    }
+}
+
+pub fn control_system(
+    mut events: EventReader<ControlEvent>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(Entity, &PanZoom2dController, &mut LookTransform), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
+    } else {
+        return;
+    };
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let window_size = Vec2::new(window.width(), window.height());
+
+    let mut target = transform.target;
+    let mut distance = transform.eye.z - target.z;
+
+    for event in events.read() {
+        let units_per_pixel = units_per_pixel(distance, controller.fov, window_size.y);
+        match event {
+            ControlEvent::Pan(delta) => {
+                // The cursor's Y axis points down the window, but world Y points up.
+                target.x -= units_per_pixel * delta.x;
+                target.y += units_per_pixel * delta.y;
+            }
+            ControlEvent::Zoom { scalar, cursor } => {
+                let new_distance =
+                    (distance * scalar).clamp(controller.min_zoom, controller.max_zoom);
+                if let Some(cursor) = cursor.filter(|_| distance > 0.0) {
+                    let from_center = cursor - 0.5 * window_size;
+                    let cursor_offset = units_per_pixel * Vec2::new(from_center.x, -from_center.y);
+                    // Shrinking the view around the target would move the cursor's world point toward the target by
+                    // this much, so move the target the other way.
+                    let shift = cursor_offset * (1.0 - new_distance / distance);
+                    target.x += shift.x;
+                    target.y += shift.y;
+                }
+                distance = new_distance;
+            }
+        }
+    }
+
+    transform.target = target;
+    transform.eye = target + distance * Vec3::Z;
+}
+
+/// How many world units one window pixel covers at `distance` from the camera.
+fn units_per_pixel(distance: f32, fov: f32, window_height: f32) -> f32 {
+    if window_height <= 0.0 {
+        return 0.0;
+    }
+    2.0 * distance * (0.5 * fov).tan() / window_height
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_zoom_keeps_point_under_cursor() {
+        let controller = PanZoom2dController::default();
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        app.add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let mut window = Window::default();
+        window.resolution.set(800.0, 600.0);
+        app.world.spawn((window, PrimaryWindow));
+        let camera = app.world.spawn((controller, start)).id();
+
+        // The cursor is in the top-right quadrant of the window.
+        let cursor = Vec2::new(600.0, 150.0);
+        let world_under_cursor = |app: &App| {
+            let t = app.world.get::<LookTransform>(camera).unwrap();
+            let units = units_per_pixel(t.eye.z - t.target.z, controller.fov, 600.0);
+            t.target.truncate() + units * Vec2::new(200.0, 150.0)
+        };
+        let before = world_under_cursor(&app);
+
+        app.world.send_event(ControlEvent::Zoom {
+            scalar: 0.5,
+            cursor: Some(cursor),
+        });
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(end.eye.z, 5.0);
+        assert_eq!(end.eye.truncate(), end.target.truncate());
+        let after = world_under_cursor(&app);
+        assert_relative_eq!(before.x, after.x, epsilon = 1e-5);
+        assert_relative_eq!(before.y, after.y, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,194 @@
+use super::orbit::{self, ControlEvent, OrbitCameraController};
+use crate::{ActiveCameras, ControllableCamera, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    hierarchy::{Children, HierarchyQueryExt},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::primitives::Aabb,
+    transform::components::GlobalTransform,
+};
+
+/// Re-pivots [`ScreenSpacePivotOrbit`] cameras. Add it alongside the
+/// [`OrbitCameraPlugin`](super::orbit::OrbitCameraPlugin).
+pub struct ScreenSpacePivotOrbitPlugin;
+
+impl Plugin for ScreenSpacePivotOrbitPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ScreenSpacePivotOrbit>().add_systems(
+            Update,
+            screen_space_pivot_system
+                .after(orbit::default_input_map)
+                .before(orbit::control_system)
+                .in_set(LookTransformSystemSet::LookTransformInput),
+        );
+    }
+}
+
+/// Makes an orbit camera orbit around whatever is in the center of the screen, like Blender's viewport, instead of a
+/// `target` that has to be set explicitly.
+///
+/// Each frame that the camera gets `ControlEvent::Orbit` input, [`screen_space_pivot_system`] casts the look ray against
+/// the world-space `Aabb`s of the `depth_test_entity` and its descendants, or of every entity with an `Aabb` if it's
+/// `None`, and moves the `target` to the nearest hit. The `eye` and look direction stay put, so only the `radius`
+/// changes, and then the orbit input turns the camera around the new `target`. If the ray misses, the `target` is kept.
+/// Cameras with an `orbit_entity` are left alone.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct ScreenSpacePivotOrbit {
+    pub depth_test_entity: Option<Entity>,
+}
+
+impl ScreenSpacePivotOrbit {
+    pub fn new(depth_test_entity: Option<Entity>) -> Self {
+        Self { depth_test_entity }
+    }
+}
+
+pub fn screen_space_pivot_system(
+    mut events: EventReader<ControlEvent>,
+    mut cameras: Query<
+        (
+            Entity,
+            &OrbitCameraController,
+            &ScreenSpacePivotOrbit,
+            &mut LookTransform,
+        ),
+        ControllableCamera,
+    >,
+    boxes: Query<(Entity, &Aabb, &GlobalTransform)>,
+    children: Query<&Children>,
+    active_cameras: ActiveCameras,
+) {
+    if !events
+        .read()
+        .any(|event| matches!(event, ControlEvent::Orbit(_)))
+    {
+        return;
+    }
+
+    // Like the orbit camera, only one camera is controlled at a time.
+    let Some((_, controller, pivot, mut transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    else {
+        return;
+    };
+    if controller.orbit_entity.is_some() {
+        return;
+    }
+    let Some(ray) = transform.as_ray() else {
+        return;
+    };
+
+    let hit = match pivot.depth_test_entity {
+        Some(root) => {
+            let entities = std::iter::once(root).chain(children.iter_descendants(root));
+            entities
+                .filter_map(|entity| boxes.get(entity).ok())
+                .filter_map(|(_, aabb, global)| ray_aabb_distance(ray, aabb, global))
+                .reduce(f32::min)
+        }
+        None => boxes
+            .iter()
+            .filter_map(|(_, aabb, global)| ray_aabb_distance(ray, aabb, global))
+            .reduce(f32::min),
+    };
+    if let Some(distance) = hit {
+        transform.target = ray.get_point(distance);
+    }
+}
+
+/// How far along `ray` it first hits `aabb`, which is in the local space of `transform`, or where it leaves the box if
+/// it starts inside. `None` if it misses, or the box is behind it, or the hit is right at the origin.
+fn ray_aabb_distance(ray: Ray, aabb: &Aabb, transform: &GlobalTransform) -> Option<f32> {
+    let world_to_local = transform.affine().inverse();
+    let origin = world_to_local.transform_point3(ray.origin);
+    // Not normalized, so distances along it are the same as along the world ray.
+    let direction = world_to_local.transform_vector3(ray.direction);
+
+    let min = Vec3::from(aabb.min());
+    let max = Vec3::from(aabb.max());
+    let t1 = (min - origin) / direction;
+    let t2 = (max - origin) / direction;
+    let near = t1.min(t2).max_element();
+    let far = t1.max(t2).min_element();
+    if near.is_nan() || far.is_nan() || far < near.max(0.0) {
+        return None;
+    }
+
+    let distance = if near > 0.0 { near } else { far };
+    (distance > 0.0).then_some(distance)
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::transform::components::Transform;
+
+    #[test]
+    fn test_pivots_on_nearest_box_under_screen_center() {
+        let mut app = App::new();
+        app.add_event::<ControlEvent>()
+            .add_systems(Update, screen_space_pivot_system);
+        let unit_box = Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(1.0));
+        let far = app
+            .world
+            .spawn((
+                unit_box,
+                GlobalTransform::from(Transform::from_xyz(0.0, 0.0, -10.0)),
+            ))
+            .id();
+        app.world.spawn((
+            unit_box,
+            GlobalTransform::from(Transform::from_xyz(0.0, 0.0, -5.0).with_scale(Vec3::splat(2.0))),
+        ));
+        let camera = app
+            .world
+            .spawn((
+                OrbitCameraController::default(),
+                ScreenSpacePivotOrbit::default(),
+                LookTransform::new(Vec3::ZERO, Vec3::new(0.0, 0.0, -20.0), Vec3::Y),
+            ))
+            .id();
+        let target = |app: &App| app.world.get::<LookTransform>(camera).unwrap().target;
+
+        // Without orbit input, nothing changes.
+        app.world.send_event(ControlEvent::Zoom(0.5));
+        app.update();
+        assert_eq!(target(&app), Vec3::new(0.0, 0.0, -20.0));
+
+        app.world.send_event(ControlEvent::Orbit(Vec2::X));
+        app.update();
+        assert_relative_eq!(target(&app).z, -3.0, epsilon = 1e-5);
+
+        // Only depth test the far box.
+        app.world
+            .get_mut::<ScreenSpacePivotOrbit>(camera)
+            .unwrap()
+            .depth_test_entity = Some(far);
+        app.world.get_mut::<LookTransform>(camera).unwrap().target = Vec3::new(0.0, 0.0, -20.0);
+        app.world.send_event(ControlEvent::Orbit(Vec2::X));
+        app.update();
+        assert_relative_eq!(target(&app).z, -9.0, epsilon = 1e-5);
+
+        // A miss keeps the target.
+        app.world.get_mut::<LookTransform>(camera).unwrap().target = Vec3::new(0.0, 20.0, 0.0);
+        app.world.send_event(ControlEvent::Orbit(Vec2::X));
+        app.update();
+        assert_eq!(target(&app), Vec3::new(0.0, 20.0, 0.0));
+    }
+}
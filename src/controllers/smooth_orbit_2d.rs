@@ -0,0 +1,157 @@
+use crate::{
+    ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    transform::components::{GlobalTransform, Transform},
+};
+
+pub struct SmoothOrbit2dPlugin;
+
+impl Plugin for SmoothOrbit2dPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SmoothOrbit2dController>().add_systems(
+            Update,
+            smooth_orbit_2d_system.in_set(LookTransformSystemSet::LookTransformInput),
+        );
+    }
+}
+
+#[derive(Bundle)]
+pub struct SmoothOrbit2dBundle {
+    controller: SmoothOrbit2dController,
+    look_transform: LookTransformBundle<SpringSmoother>,
+    transform: Transform,
+}
+
+impl SmoothOrbit2dBundle {
+    /// Looks down at `target` on the XY plane from `distance` units along `Vec3::Z`.
+    pub fn new(controller: SmoothOrbit2dController, target: Vec3, distance: f32) -> Self {
+        let eye = target + distance * Vec3::Z;
+        let transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, Vec3::Y),
+                smoother: SpringSmoother::new(controller.stiffness, controller.damping),
+            },
+            transform,
+        }
+    }
+}
+
+/// An overhead camera for top-down 2D games that follows an entity on the XY plane, looking toward `Vec3::NEG_Z`.
+///
+/// The camera's `SpringSmoother` gives it momentum, so it glides to a halt after the entity stops. `stiffness` and
+/// `damping` are copied to the spring every frame, so they can be tuned at runtime.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct SmoothOrbit2dController {
+    pub follow_entity: Option<Entity>,
+    /// How far ahead of the entity, in the direction it's moving, the camera centres, so more of what's coming is in view.
+    pub lead_distance: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_position: Option<Vec2>,
+}
+
+impl Default for SmoothOrbit2dController {
+    fn default() -> Self {
+        let spring = SpringSmoother::critically_damped(20.0);
+
+        Self {
+            follow_entity: None,
+            lead_distance: 2.0,
+            stiffness: spring.stiffness,
+            damping: spring.damping,
+            last_position: None,
+        }
+    }
+}
+
+pub fn smooth_orbit_2d_system(
+    entities: Query<&GlobalTransform>,
+    mut cameras: Query<
+        (
+            &mut SmoothOrbit2dController,
+            &mut LookTransform,
+            Option<&mut SpringSmoother>,
+        ),
+        ControllableCamera,
+    >,
+) {
+    for (mut controller, mut transform, spring) in cameras.iter_mut() {
+        if let Some(mut spring) = spring {
+            spring.stiffness = controller.stiffness;
+            spring.damping = controller.damping;
+        }
+
+        let Some(Ok(entity_transform)) = controller.follow_entity.map(|e| entities.get(e)) else {
+            continue;
+        };
+        let position = entity_transform.translation().truncate();
+        let heading = controller
+            .last_position
+            .and_then(|last| (position - last).try_normalize())
+            .unwrap_or(Vec2::ZERO);
+        controller.last_position = Some(position);
+
+        let goal = position + controller.lead_distance * heading;
+        let distance = transform.radius();
+        transform.eye = goal.extend(transform.eye.z);
+        transform.target = transform.eye + distance * Vec3::NEG_Z;
+        transform.up = Vec3::Y;
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_follows_entity_with_lead() {
+        let mut app = App::new();
+        app.add_systems(Update, smooth_orbit_2d_system);
+        let player = app
+            .world
+            .spawn(GlobalTransform::from_translation(Vec3::new(3.0, 4.0, 0.0)))
+            .id();
+        let controller = SmoothOrbit2dController {
+            follow_entity: Some(player),
+            lead_distance: 1.0,
+            ..Default::default()
+        };
+        let camera = app
+            .world
+            .spawn(SmoothOrbit2dBundle::new(controller, Vec3::ZERO, 10.0))
+            .id();
+
+        app.update();
+        let look = |app: &App| *app.world.get::<LookTransform>(camera).unwrap();
+        assert!(look(&app).eye.abs_diff_eq(Vec3::new(3.0, 4.0, 10.0), 1e-5));
+        assert!(look(&app)
+            .target
+            .abs_diff_eq(Vec3::new(3.0, 4.0, 0.0), 1e-5));
+
+        // Moving right leads the camera to the right.
+        *app.world.get_mut::<GlobalTransform>(player).unwrap() =
+            GlobalTransform::from_translation(Vec3::new(5.0, 4.0, 0.0));
+        app.update();
+        assert!(look(&app).eye.abs_diff_eq(Vec3::new(6.0, 4.0, 10.0), 1e-5));
+    }
+}
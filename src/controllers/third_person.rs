@@ -0,0 +1,280 @@
+//! A third-person camera on a "spring arm" behind a character, which shortens when something gets between the camera and
+//! the character.
+//!
+//! This crate doesn't depend on a physics engine, so it can't detect obstructions itself. Instead, each frame, the
+//! [`SpringArm`] component describes the ray from the character to where the camera would like to be. To enable
+//! collision avoidance, add a system that casts that ray with your physics engine of choice and stores the distance to
+//! the first hit:
+//!
+//! ```no_run
+//! # use bevy::prelude::*;
+//! use smooth_bevy_cameras::controllers::third_person::{SpringArm, ThirdPersonCameraSystem};
+//!
+//! fn cast_spring_arms(mut arms: Query<&mut SpringArm>) {
+//!     for mut arm in arms.iter_mut() {
+//!         // e.g. with bevy_rapier3d, cast from `arm.origin` along `arm.direction` up to `arm.max_length`, filtered by
+//!         // `arm.collision_mask`.
+//!         arm.hit_distance = None;
+//!     }
+//! }
+//!
+//! # let mut app = App::new();
+//! app.add_systems(
+//!     Update,
+//!     cast_spring_arms
+//!         .after(ThirdPersonCameraSystem::ComputeArm)
+//!         .before(ThirdPersonCameraSystem::ApplyArm),
+//! );
+//! ```
+//!
+//! Without such a system, the arm always stays at full length.
+
+use crate::{
+    ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    transform::components::{GlobalTransform, Transform},
+};
+
+pub struct ThirdPersonCameraPlugin;
+
+impl Plugin for ThirdPersonCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ThirdPersonCameraController>()
+            .register_type::<SpringArm>()
+            .configure_sets(
+                Update,
+                (
+                    ThirdPersonCameraSystem::ComputeArm,
+                    ThirdPersonCameraSystem::ApplyArm,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    compute_arm_system.in_set(ThirdPersonCameraSystem::ComputeArm),
+                    third_person_camera_system.in_set(ThirdPersonCameraSystem::ApplyArm),
+                )
+                    .in_set(LookTransformSystemSet::LookTransformInput),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ThirdPersonCameraSystem {
+    /// Updates the ray of each [`SpringArm`].
+    ComputeArm,
+    /// Moves each camera along its [`SpringArm`], shortened to the `hit_distance`.
+    ApplyArm,
+}
+
+#[derive(Bundle)]
+pub struct ThirdPersonCameraBundle {
+    controller: ThirdPersonCameraController,
+    spring_arm: SpringArm,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl ThirdPersonCameraBundle {
+    pub fn new(controller: ThirdPersonCameraController, smoothing_weight: f32) -> Self {
+        Self {
+            controller,
+            spring_arm: SpringArm::default(),
+            look_transform: LookTransformBundle {
+                transform: LookTransform::default(),
+                smoother: Smoother::new(smoothing_weight),
+            },
+            transform: Transform::default(),
+        }
+    }
+}
+
+/// Keeps the camera `arm_length` units behind and above `target_entity`, looking at it.
+///
+/// "Behind" is opposite the entity's forward (`-Z`) direction, projected onto the XZ plane.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct ThirdPersonCameraController {
+    pub target_entity: Entity,
+    /// The offset from the entity's origin to the point the arm pivots around and the camera looks at, e.g. the
+    /// character's head.
+    pub target_offset: Vec3,
+    /// The length of the arm when nothing is in the way.
+    pub arm_length: f32,
+    /// The arm never gets shorter than this, even if an obstruction is closer.
+    pub min_arm_length: f32,
+    /// Radians above the horizontal.
+    pub arm_elevation: f32,
+    /// Passed through to [`SpringArm::collision_mask`] for filtering the raycast.
+    pub collision_mask: u32,
+    /// Units per second that the arm extends once an obstruction is gone. The arm shortens instantly, so the camera
+    /// never goes through the obstruction.
+    pub arm_return_speed: f32,
+    current_arm_length: Option<f32>,
+}
+
+impl Default for ThirdPersonCameraController {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, 5.0)
+    }
+}
+
+impl ThirdPersonCameraController {
+    pub fn new(target_entity: Entity, arm_length: f32) -> Self {
+        Self {
+            target_entity,
+            target_offset: Vec3::new(0.0, 1.5, 0.0),
+            arm_length,
+            min_arm_length: 0.5,
+            arm_elevation: 0.3,
+            collision_mask: u32::MAX,
+            arm_return_speed: 5.0,
+            current_arm_length: None,
+        }
+    }
+}
+
+/// The ray from the pivot to the desired camera position, and whatever it hit. See the [module docs](self).
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct SpringArm {
+    pub origin: Vec3,
+    /// A unit vector.
+    pub direction: Vec3,
+    pub max_length: f32,
+    pub collision_mask: u32,
+    /// The distance from `origin` to the first obstruction, if any. Cleared after the camera is moved.
+    pub hit_distance: Option<f32>,
+}
+
+pub fn compute_arm_system(
+    targets: Query<&GlobalTransform>,
+    mut cameras: Query<(&ThirdPersonCameraController, &mut SpringArm)>,
+) {
+    for (controller, mut arm) in cameras.iter_mut() {
+        let Ok(target) = targets.get(controller.target_entity) else {
+            continue;
+        };
+
+        let forward = target.forward();
+        let behind = Vec3::new(-forward.x, 0.0, -forward.z)
+            .try_normalize()
+            .unwrap_or(Vec3::Z);
+        let elevation = controller.arm_elevation;
+
+        arm.origin = target.translation() + controller.target_offset;
+        arm.direction = (elevation.cos() * behind + elevation.sin() * Vec3::Y).normalize();
+        arm.max_length = controller.arm_length;
+        arm.collision_mask = controller.collision_mask;
+    }
+}
+
+pub fn third_person_camera_system(
+    time: Res<Time>,
+    mut cameras: Query<
+        (
+            &mut ThirdPersonCameraController,
+            &mut SpringArm,
+            &mut LookTransform,
+        ),
+        ControllableCamera,
+    >,
+) {
+    let dt = time.delta_seconds();
+    for (mut controller, mut arm, mut transform) in cameras.iter_mut() {
+        if arm.direction == Vec3::ZERO {
+            // The target entity hasn't been found yet.
+            continue;
+        }
+
+        let desired = arm
+            .hit_distance
+            .take()
+            .map_or(controller.arm_length, |hit| hit.min(controller.arm_length))
+            .max(controller.min_arm_length);
+        let length = match controller.current_arm_length {
+            Some(current) if current < desired => {
+                (current + controller.arm_return_speed * dt).min(desired)
+            }
+            _ => desired,
+        };
+        controller.current_arm_length = Some(length);
+
+        transform.target = arm.origin;
+        transform.eye = arm.origin + length * arm.direction;
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[derive(Resource)]
+    struct Obstruction(Option<f32>);
+
+    fn fake_raycast(obstruction: Res<Obstruction>, mut arms: Query<&mut SpringArm>) {
+        for mut arm in arms.iter_mut() {
+            arm.hit_distance = obstruction.0;
+        }
+    }
+
+    #[test]
+    fn test_arm_shortens_and_springs_back() {
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(100));
+        app.insert_resource(time)
+            .insert_resource(Obstruction(None))
+            .add_plugins(ThirdPersonCameraPlugin)
+            .add_systems(
+                Update,
+                fake_raycast
+                    .after(ThirdPersonCameraSystem::ComputeArm)
+                    .before(ThirdPersonCameraSystem::ApplyArm),
+            );
+
+        let character = app.world.spawn(GlobalTransform::IDENTITY).id();
+        let controller = ThirdPersonCameraController {
+            target_offset: Vec3::ZERO,
+            arm_elevation: 0.0,
+            ..ThirdPersonCameraController::new(character, 5.0)
+        };
+        let camera = app
+            .world
+            .spawn(ThirdPersonCameraBundle::new(controller, 0.0))
+            .id();
+        let eye = |app: &App| app.world.get::<LookTransform>(camera).unwrap().eye;
+
+        // The character faces -Z, so the camera is behind it on +Z.
+        app.update();
+        assert_relative_eq!(eye(&app).z, 5.0, epsilon = 1e-5);
+
+        app.world.resource_mut::<Obstruction>().0 = Some(2.0);
+        app.update();
+        assert_relative_eq!(eye(&app).z, 2.0, epsilon = 1e-5);
+
+        app.world.resource_mut::<Obstruction>().0 = None;
+        app.update();
+        assert_relative_eq!(eye(&app).z, 2.5, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,304 @@
+use super::input_config::KeyboardInputConfig;
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::{prelude::*, Rect},
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    transform::components::Transform,
+};
+
+#[derive(Default)]
+pub struct TopDownCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl TopDownCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for TopDownCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<TopDownCameraController>()
+            .add_systems(PreUpdate, on_controller_enabled_changed)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
+            .add_event::<ControlEvent>();
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct TopDownCameraBundle {
+    controller: TopDownCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl TopDownCameraBundle {
+    pub fn new(controller: TopDownCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, up);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, up),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// A strategy game camera that pans over the XZ plane, rotates around the vertical axis, and zooms toward its target.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct TopDownCameraController {
+    /// Whether to process input or ignore it
+    pub enabled: bool,
+    pub config: TopDownCameraControllerConfig,
+    /// The greater, the slower to follow input
+    pub smoothing_weight: f32,
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug)]
+pub struct TopDownCameraControllerConfig {
+    /// Units per second when panning with the keyboard
+    pub pan_speed: f32,
+    /// Radians per second when rotating with the `rotate_left`/`rotate_right` keys, Q/E by default
+    pub rotate_speed: f32,
+    /// Zoom factor per scroll wheel line
+    pub zoom_speed: f32,
+    pub min_height: f32,
+    pub max_height: f32,
+    /// If set, the target is kept inside this rectangle of the XZ plane, where the rectangle's Y axis is world Z.
+    pub bounds: Option<Rect>,
+    pub pixels_per_line: f32,
+    pub keyboard: KeyboardInputConfig,
+}
+
+impl Default for TopDownCameraControllerConfig {
+    fn default() -> Self {
+        Self {
+            pan_speed: 10.0,
+            rotate_speed: 1.5,
+            zoom_speed: 0.1,
+            min_height: 2.0,
+            max_height: 100.0,
+            bounds: None,
+            pixels_per_line: 53.0,
+            keyboard: KeyboardInputConfig::wasd_and_arrows(),
+        }
+    }
+}
+
+impl Default for TopDownCameraController {
+    fn default() -> Self {
+        Self::new(TopDownCameraControllerConfig::default())
+    }
+}
+
+impl TopDownCameraController {
+    pub fn new(config: TopDownCameraControllerConfig) -> Self {
+        Self {
+            enabled: true,
+            config,
+            smoothing_weight: 0.8,
+        }
+    }
+}
+
+#[derive(Event)]
+pub enum ControlEvent {
+    /// Pans along the camera's (right, forward) directions on the XZ plane.
+    Pan(Vec2),
+    /// Rotates around the vertical axis through the target.
+    Rotate(f32),
+    /// Multiplies the distance from the target.
+    Zoom(f32),
+}
+
+define_on_controller_enabled_changed!(TopDownCameraController);
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    keyboard: Res<Input<KeyCode>>,
+    controllers: Query<(Entity, &TopDownCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
+    } else {
+        return;
+    };
+    let has_cursor = active_cameras.has_cursor(entity);
+    let TopDownCameraControllerConfig {
+        pan_speed,
+        rotate_speed,
+        zoom_speed,
+        pixels_per_line,
+        keyboard: keyboard_config,
+        ..
+    } = controller.config;
+
+    let pan = keyboard_config.pan_direction(&keyboard);
+    if pan != Vec2::ZERO {
+        events.send(ControlEvent::Pan(pan_speed * pan.normalize()));
+    }
+
+    let mut rotate = 0.0;
+    if keyboard.pressed(keyboard_config.rotate_left) {
+        rotate -= rotate_speed;
+    }
+    if keyboard.pressed(keyboard_config.rotate_right) {
+        rotate += rotate_speed;
+    }
+    if rotate != 0.0 {
+        events.send(ControlEvent::Rotate(rotate));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
+        // scale the event magnitude per pixel or per line
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / pixels_per_line,
+        };
+        scalar *= 1.0 - scroll_amount * zoom_speed;
+    }
+    if scalar != 1.0 {
+        events.send(ControlEvent::Zoom(scalar));
+    }
+}
+
+pub fn control_system(
+    time: Res<Time>,
+    mut events: EventReader<ControlEvent>,
+    mut cameras: Query<(Entity, &TopDownCameraController, &mut LookTransform), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
+    } else {
+        return;
+    };
+    let config = controller.config;
+
+    let mut offset = transform.eye - transform.target;
+    let mut target = transform.target;
+
+    let dt = time.delta_seconds();
+    for event in events.read() {
+        match event {
+            ControlEvent::Pan(delta) => {
+                let forward = Vec3::new(-offset.x, 0.0, -offset.z)
+                    .try_normalize()
+                    .unwrap_or(Vec3::NEG_Z);
+                let right = forward.cross(Vec3::Y);
+                target += dt * (delta.x * right + delta.y * forward);
+            }
+            ControlEvent::Rotate(angle) => {
+                offset = Quat::from_rotation_y(dt * angle) * offset;
+            }
+            ControlEvent::Zoom(scalar) => {
+                offset *= *scalar;
+            }
+        }
+    }
+
+    if let Some(bounds) = config.bounds {
+        let xz = Vec2::new(target.x, target.z).clamp(bounds.min, bounds.max);
+        target.x = xz.x;
+        target.z = xz.y;
+    }
+
+    // Keep the eye's height in range by sliding it along the eye-target line.
+    let height = target.y + offset.y;
+    let clamped_height = height.clamp(config.min_height, config.max_height);
+    if clamped_height != height && offset.y.abs() > f32::EPSILON {
+        offset *= (clamped_height - target.y) / offset.y;
+    }
+
+    transform.target = target;
+    transform.eye = target + offset;
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_height_and_bounds() {
+        let controller = TopDownCameraController::new(TopDownCameraControllerConfig {
+            min_height: 5.0,
+            max_height: 20.0,
+            bounds: Some(Rect::new(-10.0, -10.0, 10.0, 10.0)),
+            ..Default::default()
+        });
+        let start = LookTransform::new(Vec3::new(0.0, 10.0, 10.0), Vec3::ZERO, Vec3::Y);
+
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        app.insert_resource(time)
+            .add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app.world.spawn((controller, start)).id();
+
+        app.world
+            .send_event(ControlEvent::Pan(Vec2::new(0.0, 100.0)));
+        app.world.send_event(ControlEvent::Zoom(0.1));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(end.target.z, -10.0);
+        assert_relative_eq!(end.eye.y, 5.0, epsilon = 1e-4);
+        assert_relative_eq!(end.eye.z - end.target.z, 5.0, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,159 @@
+use super::orbit::{ControlEvent, OrbitCameraController};
+use crate::{ActiveCameras, ControllableCamera};
+
+use bevy::{
+    ecs::prelude::*,
+    input::touch::{Touch, Touches},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+};
+
+/// Insert this resource to control the orbit camera with touch gestures, alongside the mouse.
+///
+/// - One finger drag: Orbit
+/// - Two finger drag: Pan
+/// - Two finger pinch: Zoom
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct TouchCameraConfig {
+    pub orbit_sensitivity: f32,
+    pub pan_sensitivity: f32,
+    pub zoom_sensitivity: f32,
+}
+
+impl Default for TouchCameraConfig {
+    fn default() -> Self {
+        Self {
+            orbit_sensitivity: 0.08,
+            pan_sensitivity: 0.1,
+            zoom_sensitivity: 1.0,
+        }
+    }
+}
+
+/// Gated like the mouse: only the camera the orbit camera's `default_input_map` would pick responds, and not while the
+/// [`CameraInputLock`](crate::CameraInputLock) is set.
+pub fn touch_camera_system(
+    config: Option<Res<TouchCameraConfig>>,
+    touches: Res<Touches>,
+    mut events: EventWriter<ControlEvent>,
+    controllers: Query<(Entity, &OrbitCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    let Some(config) = config else {
+        return;
+    };
+    // Can only control one camera at a time.
+    let Some((entity, _)) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    else {
+        return;
+    };
+    if !active_cameras.has_cursor(entity) {
+        return;
+    }
+
+    let pressed: Vec<&Touch> = touches.iter().collect();
+    match pressed.as_slice() {
+        [touch] => {
+            events.send(ControlEvent::Orbit(
+                config.orbit_sensitivity * touch.delta(),
+            ));
+        }
+        [a, b] => {
+            let (pan, distance_ratio) = pinch(
+                [a.previous_position(), b.previous_position()],
+                [a.position(), b.position()],
+            );
+            events.send(ControlEvent::TranslateTarget(config.pan_sensitivity * pan));
+            events.send(ControlEvent::Zoom(
+                distance_ratio.powf(config.zoom_sensitivity),
+            ));
+        }
+        _ => (),
+    }
+}
+
+/// Returns the movement of the centroid of two touches and the ratio of their previous to current distance, which is
+/// less than `1.0` when the fingers spread apart.
+fn pinch(previous: [Vec2; 2], current: [Vec2; 2]) -> (Vec2, f32) {
+    let previous_centroid = 0.5 * (previous[0] + previous[1]);
+    let current_centroid = 0.5 * (current[0] + current[1]);
+
+    let previous_distance = previous[0].distance(previous[1]);
+    let current_distance = current[0].distance(current[1]);
+    let distance_ratio = if previous_distance > 0.0 && current_distance > 0.0 {
+        previous_distance / current_distance
+    } else {
+        1.0
+    };
+
+    (current_centroid - previous_centroid, distance_ratio)
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::CameraInputLock;
+
+    use approx::assert_relative_eq;
+    use bevy::{
+        app::prelude::*,
+        input::touch::{touch_screen_input_system, TouchInput, TouchPhase},
+    };
+
+    #[test]
+    fn test_pinch_spread_zooms_in() {
+        let (pan, ratio) = pinch(
+            [Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0)],
+            [Vec2::new(-2.0, 1.0), Vec2::new(2.0, 1.0)],
+        );
+
+        assert_eq!(pan, Vec2::new(0.0, 1.0));
+        assert_relative_eq!(ratio, 0.5);
+    }
+
+    #[test]
+    fn test_input_lock_blocks_touches() {
+        let mut app = App::new();
+        app.init_resource::<Touches>()
+            .insert_resource(TouchCameraConfig::default())
+            .add_event::<TouchInput>()
+            .add_event::<ControlEvent>()
+            .add_systems(
+                Update,
+                (touch_screen_input_system, touch_camera_system).chain(),
+            );
+        app.world.spawn(OrbitCameraController::default());
+        let drag = |app: &mut App, phase, x| {
+            app.world.send_event(TouchInput {
+                phase,
+                position: Vec2::new(x, 0.0),
+                force: None,
+                id: 0,
+            });
+            app.update();
+            app.world
+                .resource_mut::<Events<ControlEvent>>()
+                .drain()
+                .filter(|event| matches!(event, ControlEvent::Orbit(_)))
+                .count()
+        };
+
+        drag(&mut app, TouchPhase::Started, 0.0);
+        app.insert_resource(CameraInputLock(true));
+        assert_eq!(drag(&mut app, TouchPhase::Moved, 10.0), 0);
+        app.insert_resource(CameraInputLock(false));
+        assert_eq!(drag(&mut app, TouchPhase::Moved, 20.0), 1);
+    }
+}
@@ -0,0 +1,354 @@
+//! A trackball camera rotates the view as if dragging a virtual sphere around the target, so it can tumble freely over the
+//! poles without gimbal lock. The camera's `up` vector rolls along with the rotation.
+//!
+//! Prefer the trackball for inspecting 3D models, where no direction is special and the user wants to look at every side.
+//! Prefer the [orbit camera](super::orbit) for scenes with a ground plane, where keeping the horizon level matters more
+//! than reaching the poles.
+
+use super::input_config::{invert_axes, MouseInputConfig};
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformBundle, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    transform::components::Transform,
+    window::{PrimaryWindow, Window},
+};
+
+#[derive(Default)]
+pub struct TrackballCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl TrackballCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for TrackballCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<TrackballCameraController>()
+            .add_systems(PreUpdate, on_controller_enabled_changed)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
+            .add_event::<ControlEvent>();
+
+        if !self.override_input_system {
+            app.add_systems(
+                Update,
+                trackball_input_system.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct TrackballCameraBundle {
+    controller: TrackballCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl TrackballCameraBundle {
+    pub fn new(controller: TrackballCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, up);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, up),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// A camera that tumbles around the target like a trackball. See the [module docs](self).
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct TrackballCameraController {
+    pub enabled: bool,
+    /// Scales the rotation angle of a drag. At `1.0`, dragging from the center of the virtual sphere to its edge turns it
+    /// by 90 degrees.
+    pub rotate_sensitivity: f32,
+    /// Reverses horizontal rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_x: bool,
+    /// Reverses vertical rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_y: bool,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    pub smoothing_weight: f32,
+    pub min_radius: Option<f32>,
+    pub max_radius: Option<f32>,
+    /// Only the orbit bindings and `zoom_scroll` apply.
+    pub mouse: MouseInputConfig,
+}
+
+impl Default for TrackballCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rotate_sensitivity: 1.0,
+            invert_x: false,
+            invert_y: false,
+            mouse_wheel_zoom_sensitivity: 0.2,
+            pixels_per_line: 53.0,
+            smoothing_weight: 0.8,
+            min_radius: None,
+            max_radius: None,
+            mouse: MouseInputConfig::drag_to_orbit(MouseButton::Left),
+        }
+    }
+}
+
+#[derive(Event)]
+pub enum ControlEvent {
+    /// Rotates the trackball, in the camera's frame: +X is right, +Y is up, and +Z points from the target toward the
+    /// eye.
+    Rotate(Quat),
+    Zoom(f32),
+}
+
+define_on_controller_enabled_changed!(TrackballCameraController);
+
+/// By default, left mouse drag rotates the trackball and the mouse wheel zooms.
+#[allow(clippy::too_many_arguments)]
+pub fn trackball_input_system(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keyboard: Res<Input<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    controllers: Query<(Entity, &TrackballCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
+    mut last_cursor: Local<Option<Vec2>>,
+) {
+    // Can only control one camera at a time.
+    let (entity, controller) = if let Some(found) = controllers
+        .iter()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
+    } else {
+        return;
+    };
+    let has_cursor = active_cameras.has_cursor(entity);
+
+    let window = windows.get_single().ok().filter(|_| has_cursor);
+    let cursor = window.and_then(|w| w.cursor_position());
+    if let (Some(window), Some(cursor)) = (window, cursor) {
+        if controller.mouse.is_orbiting(&mouse_buttons, &keyboard) {
+            if let Some(previous) = *last_cursor {
+                let size = Vec2::new(window.width(), window.height());
+                // Mirror the drag around where it started.
+                let drag = invert_axes(cursor - previous, controller.invert_x, controller.invert_y);
+                let rotation = trackball_rotation(
+                    previous,
+                    previous + drag,
+                    size,
+                    controller.rotate_sensitivity,
+                );
+                if rotation != Quat::IDENTITY {
+                    events.send(ControlEvent::Rotate(rotation));
+                }
+            }
+            *last_cursor = Some(cursor);
+        } else {
+            *last_cursor = None;
+        }
+    } else {
+        *last_cursor = None;
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
+        // scale the event magnitude per pixel or per line
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / controller.pixels_per_line,
+        };
+        if controller.mouse.zoom_scroll {
+            scalar *= 1.0 - scroll_amount * controller.mouse_wheel_zoom_sensitivity;
+        }
+    }
+    if scalar != 1.0 {
+        events.send(ControlEvent::Zoom(scalar));
+    }
+}
+
+/// The rotation, in the camera's frame, that drags the point under `previous` on the virtual sphere to the point under
+/// `current`. Cursor positions are in window pixels with the origin at the top left, as Bevy reports them.
+pub fn trackball_rotation(
+    previous: Vec2,
+    current: Vec2,
+    window_size: Vec2,
+    sensitivity: f32,
+) -> Quat {
+    let from = project_to_sphere(previous, window_size);
+    let to = project_to_sphere(current, window_size);
+
+    let axis = from.cross(to);
+    let Some(axis) = axis.try_normalize() else {
+        return Quat::IDENTITY;
+    };
+    let angle = from.dot(to).clamp(-1.0, 1.0).acos();
+
+    Quat::from_axis_angle(axis, sensitivity * angle)
+}
+
+/// Maps a cursor position onto a unit sphere filling the smaller window dimension. Points outside of the sphere land on
+/// its silhouette, which rolls the view around the look direction.
+fn project_to_sphere(cursor: Vec2, window_size: Vec2) -> Vec3 {
+    let half_size = 0.5 * window_size;
+    let scale = half_size.min_element().max(1.0);
+    let p = Vec2::new(cursor.x - half_size.x, half_size.y - cursor.y) / scale;
+
+    let length_squared = p.length_squared();
+    if length_squared <= 1.0 {
+        p.extend((1.0 - length_squared).sqrt())
+    } else {
+        (p / length_squared.sqrt()).extend(0.0)
+    }
+}
+
+pub fn control_system(
+    mut events: EventReader<ControlEvent>,
+    mut cameras: Query<
+        (Entity, &TrackballCameraController, &mut LookTransform),
+        ControllableCamera,
+    >,
+    active_cameras: ActiveCameras,
+) {
+    // Can only control one camera at a time.
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
+    } else {
+        return;
+    };
+
+    let (Some(right), Some(up)) = (transform.right(), transform.local_up()) else {
+        events.clear();
+        return;
+    };
+    let mut offset = transform.eye - transform.target;
+    let back = offset.normalize();
+    let camera_frame = Quat::from_mat3(&Mat3::from_cols(right, up, back));
+
+    let mut rotation = Quat::IDENTITY;
+    let mut radius_scalar = 1.0;
+    for event in events.read() {
+        match event {
+            ControlEvent::Rotate(delta) => {
+                rotation = *delta * rotation;
+            }
+            ControlEvent::Zoom(scalar) => {
+                radius_scalar *= scalar;
+            }
+        }
+    }
+
+    // Dragging the sphere turns the scene, so the camera turns the opposite way.
+    let world_rotation = camera_frame * rotation.inverse() * camera_frame.inverse();
+    offset = world_rotation * offset;
+
+    let min_radius = controller.min_radius.unwrap_or(0.001);
+    let max_radius = controller.max_radius.unwrap_or(1000000.0);
+    let new_radius = (radius_scalar * offset.length()).clamp(min_radius, max_radius);
+
+    transform.eye = transform.target + new_radius * offset.normalize();
+    transform.up = world_rotation * up;
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_drag_right_turns_camera_left() {
+        let size = Vec2::new(200.0, 100.0);
+        let rotation =
+            trackball_rotation(Vec2::new(100.0, 50.0), Vec2::new(125.0, 50.0), size, 1.0);
+
+        // Dragging from the center to halfway toward the sphere's edge turns it by 30 degrees about the camera's up.
+        let (axis, angle) = rotation.to_axis_angle();
+        assert_relative_eq!(axis.y, 1.0, epsilon = 1e-5);
+        assert_relative_eq!(angle, std::f32::consts::FRAC_PI_6, epsilon = 1e-5);
+
+        let mut app = App::new();
+        app.add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app
+            .world
+            .spawn((
+                TrackballCameraController::default(),
+                LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y),
+            ))
+            .id();
+        app.world.send_event(ControlEvent::Rotate(rotation));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(end.radius(), 5.0, epsilon = 1e-4);
+        assert_relative_eq!(end.eye.x, -5.0 * 0.5, epsilon = 1e-4);
+        assert_relative_eq!(end.up.y, 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_rolls_up_over_the_pole() {
+        let mut app = App::new();
+        app.add_event::<ControlEvent>()
+            .add_systems(Update, control_system);
+        let camera = app
+            .world
+            .spawn((
+                TrackballCameraController::default(),
+                LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y),
+            ))
+            .id();
+
+        // Tumble 120 degrees about the camera's right axis, past the pole that would stop an orbit camera.
+        app.world
+            .send_event(ControlEvent::Rotate(Quat::from_rotation_x(
+                -2.0 * std::f32::consts::FRAC_PI_3,
+            )));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        let up = end.up.normalize();
+        assert_relative_eq!(end.radius(), 5.0, epsilon = 1e-4);
+        assert_relative_eq!(up.dot(end.look_direction().unwrap()), 0.0, epsilon = 1e-5);
+        assert!(end.eye.z < 0.0);
+        assert!(up.y < 0.0);
+    }
+}
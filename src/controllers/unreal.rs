@@ -1,4 +1,11 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use super::{
+    gamepad::{GamepadCameraController, GamepadCameraReader},
+    input_config::{invert_axes, KeyboardInputConfig, MouseInputConfig, SensitivityCurve},
+};
+use crate::{
+    ActiveCameras, ControllableCamera, LookAngles, LookTransform, LookTransformBundle,
+    LookTransformSystemSet, Smoother, SpringSmoother,
+};
 
 use bevy::{
     app::prelude::*,
@@ -30,11 +37,19 @@ impl UnrealCameraPlugin {
 impl Plugin for UnrealCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
+            .register_type::<UnrealCameraController>()
+            .register_type::<GamepadCameraController>()
             .add_systems(PreUpdate, on_controller_enabled_changed)
-            .add_systems(Update, control_system)
+            .add_systems(
+                Update,
+                control_system.in_set(LookTransformSystemSet::LookTransformInput),
+            )
             .add_event::<ControlEvent>();
         if !self.override_input_system {
-            app.add_systems(Update, default_input_map);
+            app.add_systems(
+                Update,
+                default_input_map.in_set(LookTransformSystemSet::LookTransformInput),
+            );
         }
     }
 }
@@ -79,7 +94,7 @@ pub struct UnrealCameraController {
     /// How many units per frame when translating using scroll wheel
     pub wheel_translate_sensitivity: f32,
 
-    /// How many units per frame when translating using W/S/Q/E
+    /// How many units per frame when translating using the keyboard, W/S/Q/E by default
     /// Updated with scroll wheel while dragging with any mouse button
     pub keyboard_mvmt_sensitivity: f32,
 
@@ -88,6 +103,19 @@ pub struct UnrealCameraController {
 
     /// The greater, the slower to follow input
     pub smoothing_weight: f32,
+
+    /// Shapes the mouse rotation speed, e.g. for finer control when moving slowly.
+    pub sensitivity_curve: SensitivityCurve,
+    /// Reverses horizontal rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_x: bool,
+    /// Reverses vertical rotation, for players who prefer it. Read every frame, so it can be toggled at runtime.
+    pub invert_y: bool,
+
+    /// Move while dragging with `move_button`, turn with `orbit_button`, and pan with `pan_button` or with both of the
+    /// others.
+    pub mouse: MouseInputConfig,
+    /// Move along the look direction with `forward`/`back`, and pan with `left`/`right`/`up`/`down`.
+    pub keyboard: KeyboardInputConfig,
 }
 
 impl Default for UnrealCameraController {
@@ -100,6 +128,11 @@ impl Default for UnrealCameraController {
             keyboard_mvmt_sensitivity: 10.0,
             keyboard_mvmt_wheel_sensitivity: 5.0,
             smoothing_weight: 0.7,
+            sensitivity_curve: SensitivityCurve::Linear,
+            invert_x: false,
+            invert_y: false,
+            mouse: MouseInputConfig::unreal(),
+            keyboard: KeyboardInputConfig::fly(),
         }
     }
 }
@@ -113,73 +146,69 @@ pub enum ControlEvent {
 
 define_on_controller_enabled_changed!(UnrealCameraController);
 
+#[allow(clippy::too_many_arguments)]
 pub fn default_input_map(
     mut events: EventWriter<ControlEvent>,
     mut mouse_wheel_reader: EventReader<MouseWheel>,
     mut mouse_motion_events: EventReader<MouseMotion>,
     keyboard: Res<Input<KeyCode>>,
     mouse_buttons: Res<Input<MouseButton>>,
-    mut controllers: Query<&mut UnrealCameraController>,
+    gamepad: GamepadCameraReader,
+    mut controllers: Query<(Entity, &mut UnrealCameraController), ControllableCamera>,
+    active_cameras: ActiveCameras,
 ) {
     // Can only control one camera at a time.
-    let mut controller = if let Some(controller) = controllers.iter_mut().find(|c| c.enabled) {
-        controller
+    let (entity, mut controller) = if let Some(found) = controllers
+        .iter_mut()
+        .find(|(entity, c)| c.enabled && active_cameras.contains(*entity))
+    {
+        found
     } else {
         return;
     };
+    let has_cursor = active_cameras.has_cursor(entity);
     let UnrealCameraController {
         rotate_sensitivity: mouse_rotate_sensitivity,
         mouse_translate_sensitivity,
         wheel_translate_sensitivity,
         mut keyboard_mvmt_sensitivity,
         keyboard_mvmt_wheel_sensitivity,
+        mouse,
+        keyboard: keys,
         ..
     } = *controller;
 
-    let left_pressed = mouse_buttons.pressed(MouseButton::Left);
-    let right_pressed = mouse_buttons.pressed(MouseButton::Right);
-    let middle_pressed = mouse_buttons.pressed(MouseButton::Middle);
+    let button_pressed = |button: Option<MouseButton>| {
+        has_cursor && button.is_some_and(|b| mouse_buttons.pressed(b))
+    };
+    let left_pressed = button_pressed(mouse.move_button);
+    let right_pressed = button_pressed(mouse.orbit_button);
+    let middle_pressed = button_pressed(mouse.pan_button);
 
     let mut cursor_delta = Vec2::ZERO;
-    for event in mouse_motion_events.read() {
+    for event in mouse_motion_events.read().filter(|_| has_cursor) {
         cursor_delta += event.delta;
     }
 
     let mut wheel_delta = 0.0;
-    for event in mouse_wheel_reader.read() {
+    for event in mouse_wheel_reader.read().filter(|_| has_cursor) {
         wheel_delta += event.x + event.y;
     }
 
     let mut panning_dir = Vec2::ZERO;
     let mut translation_dir = Vec2::ZERO; // y is forward/backward axis, x is rotation around Z
 
-    for key in keyboard.get_pressed() {
-        match key {
-            KeyCode::E => {
-                panning_dir.y += 1.0;
-            }
-
-            KeyCode::Q => {
-                panning_dir.y -= 1.0;
-            }
-
-            KeyCode::A => {
-                panning_dir.x -= 1.0;
-            }
-
-            KeyCode::D => {
-                panning_dir.x += 1.0;
-            }
-
-            KeyCode::S => {
-                translation_dir.y -= 1.0;
-            }
-
-            KeyCode::W => {
-                translation_dir.y += 1.0;
-            }
-
-            _ => {}
+    for (key, pan, forward) in [
+        (keys.up, Vec2::Y, 0.0),
+        (keys.down, Vec2::NEG_Y, 0.0),
+        (keys.left, Vec2::NEG_X, 0.0),
+        (keys.right, Vec2::X, 0.0),
+        (keys.back, Vec2::ZERO, -1.0),
+        (keys.forward, Vec2::ZERO, 1.0),
+    ] {
+        if keyboard.pressed(key) {
+            panning_dir += pan;
+            translation_dir.y += forward;
         }
     }
 
@@ -216,10 +245,21 @@ pub fn default_input_map(
 
     if !left_pressed && !middle_pressed && right_pressed {
         events.send(ControlEvent::Rotate(
-            mouse_rotate_sensitivity * cursor_delta,
+            mouse_rotate_sensitivity * controller.sensitivity_curve.apply(cursor_delta),
         ));
     }
 
+    if let Some(gamepad) = gamepad.read() {
+        // Sticks point up for positive Y, whereas the mouse moves down.
+        let look = gamepad.look_axis;
+        if look != Vec2::ZERO {
+            events.send(ControlEvent::Rotate(Vec2::new(look.x, -look.y)));
+        }
+
+        panning += keyboard_mvmt_sensitivity * Vec2::new(gamepad.move_axis.x, gamepad.zoom);
+        locomotion.y += keyboard_mvmt_sensitivity * gamepad.move_axis.y;
+    }
+
     if panning.length_squared() > 0.0 {
         events.send(ControlEvent::TranslateEye(panning));
     }
@@ -232,11 +272,15 @@ pub fn default_input_map(
 pub fn control_system(
     time: Res<Time>,
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&UnrealCameraController, &mut LookTransform)>,
+    mut cameras: Query<(Entity, &UnrealCameraController, &mut LookTransform), ControllableCamera>,
+    active_cameras: ActiveCameras,
 ) {
     // Can only control one camera at a time.
-    let mut transform = if let Some((_, transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-        transform
+    let (controller, mut transform) = if let Some((_, controller, transform)) = cameras
+        .iter_mut()
+        .find(|c| c.1.enabled && active_cameras.contains(c.0))
+    {
+        (controller, transform)
     } else {
         return;
     };
@@ -256,17 +300,19 @@ pub fn control_system(
                 transform.eye += dt * delta.y * look_vector;
             }
             ControlEvent::Rotate(delta) => {
+                let delta = invert_axes(*delta, controller.invert_x, controller.invert_y);
                 // Rotates with pitch and yaw.
                 look_angles.add_yaw(dt * -delta.x);
                 look_angles.add_pitch(dt * -delta.y);
             }
             ControlEvent::TranslateEye(delta) => {
-                let yaw_rot = Quat::from_axis_angle(Vec3::Y, look_angles.get_yaw());
-                let rot_x = yaw_rot * Vec3::X;
+                let Some(right) = transform.right() else {
+                    continue;
+                };
 
                 // Translates up/down and left/right (X).
                 let up = transform.up;
-                transform.eye -= dt * delta.x * rot_x - dt * delta.y * up;
+                transform.eye += dt * delta.x * right + dt * delta.y * up;
             }
         }
     }
@@ -0,0 +1,277 @@
+use crate::{
+    ActiveCameras, ControllableCamera, LookTransform, LookTransformSystemSet, REFERENCE_FPS,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+};
+
+/// Zooms [`CameraZoom`] cameras with the scroll wheel.
+pub struct CameraZoomPlugin;
+
+impl Plugin for CameraZoomPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraZoom>()
+            .register_type::<ZoomTarget>()
+            .add_systems(
+                Update,
+                camera_zoom_system.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+    }
+}
+
+/// Adds scroll wheel zoom to any camera, by moving the `eye` along the look direction.
+///
+/// This works alongside a controller, e.g. an FPS camera, that doesn't zoom by itself. For a controller that does, turn
+/// its zoom off first, like the orbit camera's `MouseInputConfig::zoom_scroll`, or the two add up.
+///
+/// With `smooth`, scrolling only changes the camera's [`ZoomTarget`], which is inserted on the first scroll, and the
+/// radius eases toward it by `smoothing_weight`. Otherwise the radius changes immediately, and only the camera's
+/// `Smoother` smooths it.
+#[derive(Clone, Component, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraZoom {
+    /// The fraction of the radius zoomed per scroll wheel line.
+    pub speed: f32,
+    /// Scroll up to zoom out instead of in.
+    pub invert: bool,
+    pub smooth: bool,
+    /// The greater, the slower the radius follows the `ZoomTarget`, like a `Smoother`'s lag weight.
+    pub smoothing_weight: f32,
+    pub min_radius: f32,
+    pub max_radius: f32,
+    pub pixels_per_line: f32,
+    pub mode: ZoomMode,
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        Self {
+            speed: 0.2,
+            invert: false,
+            smooth: false,
+            smoothing_weight: 0.8,
+            min_radius: 0.1,
+            max_radius: 1000.0,
+            pixels_per_line: 53.0,
+            mode: ZoomMode::Continuous,
+        }
+    }
+}
+
+/// How far each scroll wheel line zooms a [`CameraZoom`].
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug)]
+pub enum ZoomMode {
+    /// Scales the radius by `speed` per line.
+    #[default]
+    Continuous,
+    /// Moves to the next of a list of radii, e.g. overview, mid, close, and detail views, per scroll event, regardless
+    /// of how far it scrolled. The steps are ordered from closest to farthest, and override `min_radius` and
+    /// `max_radius`. The first scroll starts from the step nearest the current radius.
+    Stepped { steps: Vec<f32> },
+}
+
+/// The index of the radius in `steps` closest to `radius`, or `None` if there are no steps.
+pub fn snap_to_nearest_step(steps: &[f32], radius: f32) -> Option<usize> {
+    steps
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - radius).abs().total_cmp(&(*b - radius).abs()))
+        .map(|(index, _)| index)
+}
+
+/// The radius a smooth [`CameraZoom`] is easing toward.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct ZoomTarget {
+    pub radius: f32,
+}
+
+pub fn camera_zoom_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut cameras: Query<
+        (
+            Entity,
+            &CameraZoom,
+            &mut LookTransform,
+            Option<&mut ZoomTarget>,
+        ),
+        ControllableCamera,
+    >,
+    active_cameras: ActiveCameras,
+) {
+    let scroll: Vec<_> = mouse_wheel_reader.read().map(|e| (e.unit, e.y)).collect();
+
+    let dt = time.delta_seconds();
+    for (entity, zoom, mut transform, target) in cameras
+        .iter_mut()
+        .filter(|(entity, ..)| active_cameras.contains(*entity))
+    {
+        let mut scalar = 1.0;
+        let mut steps_in = 0;
+        if active_cameras.has_cursor(entity) {
+            for &(unit, amount) in &scroll {
+                // scale the event magnitude per pixel or per line
+                let lines = match unit {
+                    MouseScrollUnit::Line => amount,
+                    MouseScrollUnit::Pixel => amount / zoom.pixels_per_line,
+                };
+                let lines = if zoom.invert { -lines } else { lines };
+                scalar *= 1.0 - lines * zoom.speed;
+                if lines != 0.0 {
+                    steps_in += lines.signum() as i32;
+                }
+            }
+        }
+
+        // The radius to zoom to from `radius`, if scrolling moved it at all.
+        let zoomed = |radius: f32| match &zoom.mode {
+            ZoomMode::Continuous => {
+                (scalar != 1.0).then(|| (scalar * radius).clamp(zoom.min_radius, zoom.max_radius))
+            }
+            ZoomMode::Stepped { steps } => {
+                let index = snap_to_nearest_step(steps, radius).filter(|_| steps_in != 0)?;
+                let index = (index as i32 - steps_in).clamp(0, steps.len() as i32 - 1);
+                Some(steps[index as usize])
+            }
+        };
+
+        if !zoom.smooth {
+            if let Some(radius) = zoomed(transform.radius()) {
+                *transform = transform.with_radius(radius);
+            }
+            continue;
+        }
+
+        let goal = match target {
+            Some(mut target) => {
+                if let Some(radius) = zoomed(target.radius) {
+                    target.radius = radius;
+                }
+                target.radius
+            }
+            None => {
+                let Some(radius) = zoomed(transform.radius()) else {
+                    continue;
+                };
+                commands.entity(entity).insert(ZoomTarget { radius });
+                radius
+            }
+        };
+
+        let radius = transform.radius();
+        if radius != goal {
+            let lag_weight = zoom
+                .smoothing_weight
+                .clamp(0.0, 1.0)
+                .powf(dt * REFERENCE_FPS);
+            let new_radius = goal + (radius - goal) * lag_weight;
+            *transform = transform.with_radius(new_radius);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::{input::InputPlugin, math::Vec3};
+    use std::time::Duration;
+
+    #[test]
+    fn test_smooth_zoom_eases_toward_target() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin)
+            .init_resource::<Time>()
+            .add_systems(Update, camera_zoom_system);
+        let camera = app
+            .world
+            .spawn((
+                CameraZoom {
+                    smooth: true,
+                    smoothing_weight: 0.5,
+                    ..Default::default()
+                },
+                LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y),
+            ))
+            .id();
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        };
+
+        // One line in, toward a radius of 8.
+        app.world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: 1.0,
+            window: Entity::PLACEHOLDER,
+        });
+        let radius = |app: &App| app.world.get::<LookTransform>(camera).unwrap().radius();
+        step(&mut app);
+        assert_relative_eq!(app.world.get::<ZoomTarget>(camera).unwrap().radius, 8.0);
+        assert_relative_eq!(radius(&app), 9.0, epsilon = 1e-4);
+        step(&mut app);
+        assert_relative_eq!(radius(&app), 8.5, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_stepped_zoom() {
+        let steps = vec![2.0, 5.0, 10.0, 20.0];
+        assert_eq!(snap_to_nearest_step(&steps, 9.0), Some(2));
+        assert_eq!(snap_to_nearest_step(&steps, 100.0), Some(3));
+        assert_eq!(snap_to_nearest_step(&[], 1.0), None);
+
+        let mut app = App::new();
+        app.add_plugins(InputPlugin)
+            .init_resource::<Time>()
+            .add_systems(Update, camera_zoom_system);
+        let camera = app
+            .world
+            .spawn((
+                CameraZoom {
+                    mode: ZoomMode::Stepped { steps },
+                    ..Default::default()
+                },
+                LookTransform::new(Vec3::new(0.0, 0.0, 9.0), Vec3::ZERO, Vec3::Y),
+            ))
+            .id();
+        let scroll = |app: &mut App, y: f32| {
+            app.world.send_event(MouseWheel {
+                unit: MouseScrollUnit::Pixel,
+                x: 0.0,
+                y,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+            app.world.get::<LookTransform>(camera).unwrap().radius()
+        };
+
+        // However far one event scrolls, it moves a single step from the one nearest the radius.
+        assert_relative_eq!(scroll(&mut app, 5.0), 5.0, epsilon = 1e-4);
+        assert_relative_eq!(scroll(&mut app, 500.0), 2.0, epsilon = 1e-4);
+        assert_relative_eq!(scroll(&mut app, 1.0), 2.0, epsilon = 1e-4);
+        assert_relative_eq!(scroll(&mut app, -1.0), 5.0, epsilon = 1e-4);
+    }
+}
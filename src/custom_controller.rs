@@ -0,0 +1,184 @@
+use crate::{ActiveCameras, ControllableCamera, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    time::Time,
+};
+use std::marker::PhantomData;
+
+/// A camera controller of your own, driven by [`CameraControllerPlugin`] like the built-in ones.
+///
+/// Only [`ActiveCameras`] are updated, and not while a [`CameraTransition`](crate::CameraTransition) is playing or the
+/// camera has an [`AllCameraControllersDisabled`](crate::AllCameraControllersDisabled).
+pub trait CameraController: Component {
+    /// Moves the camera for one frame of `input`, where `dt` is the number of seconds since the last frame.
+    fn update(&mut self, look_transform: &mut LookTransform, input: &CameraInputState, dt: f32);
+}
+
+/// Updates every `T` camera with [`CameraController::update`], in `LookTransformSystemSet::LookTransformInput`.
+pub struct CameraControllerPlugin<T: CameraController> {
+    marker: PhantomData<T>,
+}
+
+impl<T: CameraController> Default for CameraControllerPlugin<T> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: CameraController> CameraControllerPlugin<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: CameraController> Plugin for CameraControllerPlugin<T> {
+    fn build(&self, app: &mut App) {
+        // Shared by every custom controller.
+        if !app.world.contains_resource::<CameraInputState>() {
+            app.init_resource::<CameraInputState>().add_systems(
+                Update,
+                camera_input_state_system.before(LookTransformSystemSet::LookTransformInput),
+            );
+        }
+
+        app.add_systems(
+            Update,
+            camera_controller_system::<T>.in_set(LookTransformSystemSet::LookTransformInput),
+        );
+    }
+}
+
+/// This frame's raw input, gathered for [`CameraController`]s so they don't read it themselves.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct CameraInputState {
+    /// The mouse motion this frame, in pixels.
+    pub mouse_delta: Vec2,
+    /// The scroll wheel motion this frame, in lines. Pixel scrolling is converted at `PIXELS_PER_LINE`.
+    pub scroll: Vec2,
+    pub mouse_buttons: Input<MouseButton>,
+    pub keyboard: Input<KeyCode>,
+}
+
+impl CameraInputState {
+    /// Like the built-in controllers' default `pixels_per_line`.
+    pub const PIXELS_PER_LINE: f32 = 53.0;
+
+    /// The same input with no mouse motion or scrolling, for a camera whose `CameraViewport` doesn't have the cursor.
+    pub fn without_mouse(&self) -> Self {
+        Self {
+            mouse_delta: Vec2::ZERO,
+            scroll: Vec2::ZERO,
+            ..self.clone()
+        }
+    }
+}
+
+pub fn camera_input_state_system(
+    mut state: ResMut<CameraInputState>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+) {
+    state.mouse_delta = mouse_motion_events.read().map(|event| event.delta).sum();
+    state.scroll = mouse_wheel_reader
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => Vec2::new(event.x, event.y),
+            MouseScrollUnit::Pixel => {
+                Vec2::new(event.x, event.y) / CameraInputState::PIXELS_PER_LINE
+            }
+        })
+        .sum();
+    state.mouse_buttons = mouse_buttons.map_or_else(Default::default, |b| b.clone());
+    state.keyboard = keyboard.map_or_else(Default::default, |k| k.clone());
+}
+
+pub fn camera_controller_system<T: CameraController>(
+    time: Res<Time>,
+    input: Res<CameraInputState>,
+    mut cameras: Query<(Entity, &mut T, &mut LookTransform), ControllableCamera>,
+    active_cameras: ActiveCameras,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut controller, mut transform) in cameras.iter_mut() {
+        if !active_cameras.contains(entity) {
+            continue;
+        }
+        if active_cameras.has_cursor(entity) {
+            controller.update(&mut transform, &input, dt);
+        } else {
+            controller.update(&mut transform, &input.without_mouse(), dt);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::input::InputPlugin;
+    use std::time::Duration;
+
+    #[derive(Component)]
+    struct Dolly {
+        speed: f32,
+    }
+
+    impl CameraController for Dolly {
+        fn update(
+            &mut self,
+            look_transform: &mut LookTransform,
+            input: &CameraInputState,
+            dt: f32,
+        ) {
+            if input.keyboard.pressed(KeyCode::W) {
+                *look_transform = look_transform.translate(self.speed * dt * Vec3::NEG_Z);
+            }
+            look_transform.eye.x += input.mouse_delta.x;
+        }
+    }
+
+    #[test]
+    fn test_custom_controller_reads_input_state() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, CameraControllerPlugin::<Dolly>::new()))
+            .init_resource::<Time>();
+        let camera = app
+            .world
+            .spawn((
+                Dolly { speed: 2.0 },
+                LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y),
+            ))
+            .id();
+
+        app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::W);
+        app.world.send_event(MouseMotion {
+            delta: Vec2::new(3.0, 0.0),
+        });
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        app.update();
+
+        let end = app.world.get::<LookTransform>(camera).unwrap();
+        assert!(end.eye.abs_diff_eq(Vec3::new(3.0, 0.0, -1.0), 1e-5));
+        assert!(end.target.abs_diff_eq(Vec3::new(0.0, 0.0, -2.0), 1e-5));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    adaptive_smoother_system, Inactive, LookTransform, LookTransformSystem, LookTransformSystemSet,
+    Smoother, SpringSmoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    diagnostic::{Diagnostic, DiagnosticId, Diagnostics, RegisterDiagnostic},
+    ecs::prelude::*,
+    transform::components::Transform,
+    utils::Instant,
+};
+
+/// Reports how much work the camera sync does each frame to Bevy's `DiagnosticsStore`, e.g. for the
+/// `LogDiagnosticsPlugin`:
+///
+/// - `camera_count`: the cameras with a `LookTransform` and a `Transform` that aren't [`Inactive`].
+/// - `smooth_iterations_per_frame`: how many of those were smoothed, i.e. have an enabled `Smoother` or `SpringSmoother`.
+/// - `look_transform_ms`: the time spent in [`LookTransformSystemSet::LookTransformUpdate`], in milliseconds.
+///
+/// Only the `PostUpdate` sync of the `LookTransformPlugin` is measured. Without this plugin, nothing is measured.
+pub struct LookTransformDiagnosticsPlugin;
+
+impl LookTransformDiagnosticsPlugin {
+    pub const CAMERA_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(157_772_310_106_207_681_336_229_331_002_984_350_001);
+    pub const SMOOTH_ITERATIONS_PER_FRAME: DiagnosticId =
+        DiagnosticId::from_u128(157_772_310_106_207_681_336_229_331_002_984_350_002);
+    pub const LOOK_TRANSFORM_MS: DiagnosticId =
+        DiagnosticId::from_u128(157_772_310_106_207_681_336_229_331_002_984_350_003);
+}
+
+impl Plugin for LookTransformDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::CAMERA_COUNT, "camera_count", 20))
+            .register_diagnostic(Diagnostic::new(
+                Self::SMOOTH_ITERATIONS_PER_FRAME,
+                "smooth_iterations_per_frame",
+                20,
+            ))
+            .register_diagnostic(
+                Diagnostic::new(Self::LOOK_TRANSFORM_MS, "look_transform_ms", 20).with_suffix("ms"),
+            )
+            .init_resource::<LookTransformTimer>()
+            .add_systems(
+                PostUpdate,
+                (
+                    start_look_transform_timer_system
+                        .after(adaptive_smoother_system)
+                        .before(LookTransformSystemSet::LookTransformUpdate),
+                    look_transform_diagnostic_system
+                        .after(LookTransformSystemSet::LookTransformUpdate)
+                        .before(LookTransformSystemSet::LookTransformPostProcess),
+                )
+                    .in_set(LookTransformSystem::Sync),
+            );
+    }
+}
+
+/// When [`LookTransformSystemSet::LookTransformUpdate`] started this frame.
+#[derive(Default, Resource)]
+struct LookTransformTimer(Option<Instant>);
+
+fn start_look_transform_timer_system(mut timer: ResMut<LookTransformTimer>) {
+    timer.0 = Some(Instant::now());
+}
+
+#[allow(clippy::type_complexity)]
+fn look_transform_diagnostic_system(
+    mut diagnostics: Diagnostics,
+    timer: Res<LookTransformTimer>,
+    cameras: Query<
+        (Option<&Smoother>, Option<&SpringSmoother>),
+        (With<LookTransform>, With<Transform>, Without<Inactive>),
+    >,
+) {
+    if let Some(start) = timer.0 {
+        diagnostics.add_measurement(LookTransformDiagnosticsPlugin::LOOK_TRANSFORM_MS, || {
+            start.elapsed().as_secs_f64() * 1000.0
+        });
+    }
+    diagnostics.add_measurement(LookTransformDiagnosticsPlugin::CAMERA_COUNT, || {
+        cameras.iter().len() as f64
+    });
+    diagnostics.add_measurement(
+        LookTransformDiagnosticsPlugin::SMOOTH_ITERATIONS_PER_FRAME,
+        || {
+            cameras
+                .iter()
+                .filter(|(smoother, spring)| {
+                    smoother.is_some_and(|s| s.is_enabled())
+                        || spring.is_some_and(|s| s.is_enabled())
+                })
+                .count() as f64
+        },
+    );
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{LookTransformBundle, LookTransformPlugin};
+
+    use bevy::{diagnostic::DiagnosticsStore, time::Time};
+
+    #[test]
+    fn test_counts_cameras() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_plugins((
+            LookTransformPlugin::default(),
+            LookTransformDiagnosticsPlugin,
+        ));
+        for enabled in [true, true, false] {
+            let mut bundle = LookTransformBundle {
+                transform: LookTransform::default(),
+                smoother: Smoother::new(0.5),
+            };
+            bundle.smoother.set_enabled(enabled);
+            app.world.spawn((bundle, Transform::default()));
+        }
+        app.world
+            .spawn((LookTransform::default(), Transform::default(), Inactive));
+        app.update();
+
+        let store = app.world.resource::<DiagnosticsStore>();
+        let value = |id| store.get(id).unwrap().value().unwrap();
+        assert_eq!(value(LookTransformDiagnosticsPlugin::CAMERA_COUNT), 3.0);
+        assert_eq!(
+            value(LookTransformDiagnosticsPlugin::SMOOTH_ITERATIONS_PER_FRAME),
+            2.0
+        );
+        assert!(value(LookTransformDiagnosticsPlugin::LOOK_TRANSFORM_MS) >= 0.0);
+    }
+}
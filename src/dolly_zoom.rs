@@ -0,0 +1,210 @@
+use crate::LookTransform;
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    log::error,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{PerspectiveProjection, Projection},
+    time::Time,
+};
+
+/// Plays [`DollyZoom`] effects.
+pub struct DollyZoomPlugin;
+
+impl Plugin for DollyZoomPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DollyZoom>()
+            .add_systems(Update, dolly_zoom_system);
+    }
+}
+
+/// The "vertigo" effect: moves the `eye` along the look axis while changing the field of view, so the subject at the
+/// `target` stays the same size on screen while the background stretches or squashes around it.
+///
+/// Both the radius and the field of view reach their end values after `duration` seconds, and then the component removes
+/// itself. In between, the radius moves linearly and the field of view follows it, so the subject doesn't change size
+/// as long as `end_radius` matches `end_fov`; see `DollyZoom::keep_subject_size`.
+///
+/// The camera needs a perspective `Projection` or a `PerspectiveProjection`. Remove any `SmoothFov` while zooming, or it
+/// fights over the field of view.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct DollyZoom {
+    /// Seconds.
+    pub duration: f32,
+    /// Seconds.
+    pub elapsed: f32,
+    /// Radians.
+    pub start_fov: f32,
+    /// Radians.
+    pub end_fov: f32,
+    pub start_radius: f32,
+    pub end_radius: f32,
+}
+
+impl Default for DollyZoom {
+    fn default() -> Self {
+        Self::keep_subject_size(
+            2.0,
+            std::f32::consts::FRAC_PI_4,
+            5.0,
+            std::f32::consts::FRAC_PI_2,
+        )
+    }
+}
+
+impl DollyZoom {
+    /// Zooms from `start_fov` to `end_fov`, starting `start_radius` from the target and ending wherever the subject is
+    /// the same size.
+    pub fn keep_subject_size(
+        duration: f32,
+        start_fov: f32,
+        start_radius: f32,
+        end_fov: f32,
+    ) -> Self {
+        let end_radius = start_radius * (0.5 * start_fov).tan() / (0.5 * end_fov).tan();
+        Self {
+            duration,
+            elapsed: 0.0,
+            start_fov,
+            end_fov,
+            start_radius,
+            end_radius,
+        }
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// The (radius, fov) at the current `progress`.
+    pub fn sample(&self) -> (f32, f32) {
+        let t = self.progress();
+        let radius = self.start_radius + t * (self.end_radius - self.start_radius);
+
+        // The half-height of the view at the target, which stays fixed when the end values match.
+        let start_height = self.start_radius * (0.5 * self.start_fov).tan();
+        let end_height = self.end_radius * (0.5 * self.end_fov).tan();
+        let height = start_height + t * (end_height - start_height);
+        let fov = if radius > 0.0 {
+            2.0 * (height / radius).atan()
+        } else {
+            self.end_fov
+        };
+
+        (radius, fov)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn dolly_zoom_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(
+        Entity,
+        &mut DollyZoom,
+        &mut LookTransform,
+        Option<&mut PerspectiveProjection>,
+        Option<&mut Projection>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut zoom, mut transform, perspective, projection) in cameras.iter_mut() {
+        let is_perspective = perspective.is_some()
+            || matches!(projection.as_deref(), Some(Projection::Perspective(_)));
+        if !is_perspective {
+            error!(
+                "DollyZoom on {:?}, which doesn't have a perspective projection",
+                entity
+            );
+            commands.entity(entity).remove::<DollyZoom>();
+            continue;
+        }
+
+        zoom.elapsed += dt;
+        let (radius, fov) = zoom.sample();
+        *transform = transform.with_radius(radius);
+        if let Some(mut perspective) = perspective {
+            perspective.fov = fov;
+        } else if let Some(mut projection) = projection {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                perspective.fov = fov;
+            }
+        }
+
+        if zoom.is_finished() {
+            commands.entity(entity).remove::<DollyZoom>();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::math::Vec3;
+    use std::time::Duration;
+
+    #[test]
+    fn test_keeps_subject_size_and_finishes() {
+        let zoom = DollyZoom::keep_subject_size(1.0, 1.0, 10.0, 2.0);
+        let subject_size = |radius: f32, fov: f32| radius * (0.5 * fov).tan();
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, dolly_zoom_system);
+        let camera = app
+            .world
+            .spawn((
+                zoom,
+                LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y),
+                Projection::Perspective(PerspectiveProjection {
+                    fov: 1.0,
+                    ..Default::default()
+                }),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.4));
+            app.update();
+
+            let radius = app.world.get::<LookTransform>(camera).unwrap().radius();
+            let Projection::Perspective(p) = app.world.get::<Projection>(camera).unwrap() else {
+                unreachable!();
+            };
+            assert_relative_eq!(
+                subject_size(radius, p.fov),
+                subject_size(10.0, 1.0),
+                epsilon = 1e-4
+            );
+        }
+
+        let Projection::Perspective(p) = app.world.get::<Projection>(camera).unwrap() else {
+            unreachable!();
+        };
+        assert_relative_eq!(p.fov, 2.0, epsilon = 1e-5);
+        assert!(app.world.get::<DollyZoom>(camera).is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    look_transform::is_synced, shake::value_noise, FollowExactly, LookTransform, Smoother,
+    SpringSmoother,
+};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+    transform::components::Transform,
+};
+
+/// Slowly wanders the camera around its `LookTransform`, for idle cameras in menus and cutscenes.
+///
+/// The `eye` and `target` are each offset by smooth noise, up to their amplitude along each world axis, changing
+/// direction about `frequency` times per second. Like [`CameraShake`](crate::CameraShake), the drift is applied to the
+/// scene graph `Transform` after smoothing, so the `LookTransform` itself is never disturbed.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct ProceduralDrift {
+    /// The largest offset of the `eye`, along each axis.
+    pub amplitude_eye: Vec3,
+    /// The largest offset of the `target`, along each axis.
+    pub amplitude_target: Vec3,
+    pub frequency: f32,
+    /// Cameras with different seeds drift differently.
+    pub seed: u64,
+}
+
+impl Default for ProceduralDrift {
+    fn default() -> Self {
+        Self::new(Vec3::splat(0.2), Vec3::splat(0.1), 0.2)
+    }
+}
+
+impl ProceduralDrift {
+    pub fn new(amplitude_eye: Vec3, amplitude_target: Vec3, frequency: f32) -> Self {
+        Self {
+            amplitude_eye,
+            amplitude_target,
+            frequency,
+            seed: 0,
+        }
+    }
+
+    /// The (eye, target) offsets at time `t`, in seconds.
+    pub fn offsets(&self, t: f32) -> (Vec3, Vec3) {
+        let t = t * self.frequency;
+        let noise = |channel| value_noise(self.seed, channel, t);
+
+        (
+            self.amplitude_eye * Vec3::new(noise(0), noise(1), noise(2)),
+            self.amplitude_target * Vec3::new(noise(3), noise(4), noise(5)),
+        )
+    }
+
+    /// Drifts a camera `transform` whose target is `radius` units ahead, at time `t`.
+    pub fn apply(&self, transform: &mut Transform, radius: f32, t: f32) {
+        let (eye_offset, target_offset) = self.offsets(t);
+
+        let forward = transform.forward();
+        let target = transform.translation + radius * forward;
+        transform.translation += eye_offset;
+        if let Some(new_forward) = (target + target_offset - transform.translation).try_normalize()
+        {
+            transform.rotation = Quat::from_rotation_arc(forward, new_forward) * transform.rotation;
+        }
+    }
+}
+
+/// Runs after `look_transform_system`, which rewrites the `Transform` every frame, so the drift doesn't accumulate.
+#[allow(clippy::type_complexity)]
+pub fn procedural_drift_system(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &ProceduralDrift,
+        &LookTransform,
+        &mut Transform,
+        Option<&Smoother>,
+        Option<&SpringSmoother>,
+        Option<&FollowExactly>,
+    )>,
+) {
+    let t = time.elapsed_seconds_wrapped();
+    for (drift, look_transform, mut transform, smoother, spring, follow) in cameras.iter_mut() {
+        let synced = is_synced(smoother, spring, follow.is_some());
+        if synced {
+            drift.apply(&mut transform, look_transform.radius(), t);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drift_stays_within_amplitude() {
+        let drift = ProceduralDrift {
+            seed: 3,
+            ..ProceduralDrift::new(Vec3::new(1.0, 0.0, 0.5), Vec3::ZERO, 1.0)
+        };
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        let mut moved = false;
+        for step in 0..100 {
+            let t = step as f32 * 0.1;
+            let mut transform = Transform::from(look);
+            drift.apply(&mut transform, look.radius(), t);
+
+            let offset = transform.translation - look.eye;
+            assert!(offset.x.abs() <= 1.0 && offset.y == 0.0 && offset.z.abs() <= 0.5);
+            moved |= offset != Vec3::ZERO;
+            // Still looking at the undisturbed target.
+            let to_target = (look.target - transform.translation).normalize();
+            assert!(transform.forward().abs_diff_eq(to_target, 1e-4));
+        }
+        assert!(moved);
+    }
+}
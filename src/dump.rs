@@ -0,0 +1,86 @@
+use crate::{CameraState, LookTransform, Smoother};
+
+use bevy::{app::prelude::*, app::AppExit, ecs::prelude::*};
+
+/// Prints every camera's [`CameraState`] to stdout as RON when the app exits, ready to paste into a scene file after
+/// positioning the camera by hand.
+///
+/// Only cameras with both a `LookTransform` and a `Smoother` are printed. This is a development aid, so it does nothing
+/// in release builds.
+#[derive(Default)]
+pub struct CameraDumpOnExit {
+    entity: Option<Entity>,
+}
+
+impl CameraDumpOnExit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only prints the camera on `entity`.
+    pub fn with_entity(mut self, entity: Entity) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+
+    /// The RON printed for one camera.
+    pub fn format(entity: Entity, look: &LookTransform, smoother: &Smoother) -> String {
+        let state = ron::ser::to_string_pretty(
+            &CameraState::capture(look, smoother),
+            ron::ser::PrettyConfig::default(),
+        )
+        .expect("camera states are always serializable");
+
+        format!("// {entity:?}\n{state}")
+    }
+}
+
+impl Plugin for CameraDumpOnExit {
+    fn build(&self, app: &mut App) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let filter = self.entity;
+        app.add_systems(
+            Last,
+            move |mut exit: EventReader<AppExit>,
+                  cameras: Query<(Entity, &LookTransform, &Smoother)>| {
+                if exit.read().next().is_none() {
+                    return;
+                }
+                for (entity, look, smoother) in cameras.iter() {
+                    if filter.map_or(true, |filter| filter == entity) {
+                        println!("{}", CameraDumpOnExit::format(entity, look, smoother));
+                    }
+                }
+            },
+        );
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_format_round_trips() {
+        let look = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::ZERO, Vec3::Y);
+        let smoother = Smoother::new(0.7);
+
+        let dumped = CameraDumpOnExit::format(Entity::from_raw(3), &look, &smoother);
+        assert!(dumped.starts_with("// 3v0\n"));
+
+        let state: CameraState = ron::from_str(&dumped).unwrap();
+        assert_eq!(state, CameraState::capture(&look, &smoother));
+    }
+}
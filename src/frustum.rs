@@ -0,0 +1,135 @@
+use crate::{LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    math::{prelude::*, Affine3A},
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::{
+        camera::{CameraProjection, PerspectiveProjection, Projection},
+        primitives::{Aabb, Frustum, Sphere},
+    },
+    transform::components::Transform,
+};
+
+/// Keeps each [`CameraFrustum`] up to date with its camera.
+pub struct CameraFrustumPlugin;
+
+impl Plugin for CameraFrustumPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraFrustum>().add_systems(
+            PostUpdate,
+            update_camera_frustum_system.after(LookTransformSystemSet::LookTransformUpdate),
+        );
+    }
+}
+
+/// The view frustum of a `LookTransform`, for game logic like manual culling, AI sight checks, and audio.
+///
+/// Add it to a camera with a `PerspectiveProjection` or `Projection`, and [`update_camera_frustum_system`] recomputes
+/// it every frame. It's independent of the `Frustum` Bevy uses for rendering, and it's computed from the
+/// `LookTransform`, so it is where the camera is heading rather than where a `Smoother` has got to so far.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct CameraFrustum {
+    pub frustum: Frustum,
+}
+
+impl CameraFrustum {
+    pub fn new(transform: &LookTransform, projection: &impl CameraProjection) -> Self {
+        let view_transform = Transform::from(*transform);
+        let view_projection = projection.get_projection_matrix() * transform.to_view_matrix();
+
+        Self {
+            frustum: Frustum::from_view_projection_custom_far(
+                &view_projection,
+                &transform.eye,
+                &view_transform.back(),
+                projection.far(),
+            ),
+        }
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.frustum.intersects_sphere(
+            &Sphere {
+                center: point.into(),
+                radius: 0.0,
+            },
+            true,
+        )
+    }
+
+    /// Whether any part of a world-space `Aabb` may be inside. Like Bevy's culling, this is conservative: boxes near a
+    /// corner of the frustum can intersect without actually being inside.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.frustum
+            .intersects_obb(aabb, &Affine3A::IDENTITY, true, true)
+    }
+}
+
+pub fn update_camera_frustum_system(
+    mut cameras: Query<(
+        &LookTransform,
+        &mut CameraFrustum,
+        Option<&PerspectiveProjection>,
+        Option<&Projection>,
+    )>,
+) {
+    for (transform, mut frustum, perspective, projection) in cameras.iter_mut() {
+        if let Some(perspective) = perspective {
+            *frustum = CameraFrustum::new(transform, perspective);
+        } else if let Some(projection) = projection {
+            *frustum = CameraFrustum::new(transform, projection);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frustum_follows_look_transform() {
+        let mut app = App::new();
+        app.add_systems(Update, update_camera_frustum_system);
+        let camera = app
+            .world
+            .spawn((
+                LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y),
+                PerspectiveProjection {
+                    far: 100.0,
+                    ..Default::default()
+                },
+                CameraFrustum::default(),
+            ))
+            .id();
+        app.update();
+
+        let frustum = app.world.get::<CameraFrustum>(camera).unwrap();
+        assert!(frustum.contains_point(Vec3::ZERO));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, 6.0)));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -200.0)));
+        assert!(!frustum.contains_point(Vec3::new(50.0, 0.0, 0.0)));
+
+        let straddling = Aabb::from_min_max(Vec3::new(-1.0, -1.0, 4.0), Vec3::new(1.0, 1.0, 6.0));
+        assert!(frustum.intersects_aabb(&straddling));
+        let beside = Aabb::from_min_max(Vec3::new(49.0, -1.0, -1.0), Vec3::new(51.0, 1.0, 1.0));
+        assert!(!frustum.intersects_aabb(&beside));
+
+        // Turning the camera around moves the frustum with it.
+        app.world.get_mut::<LookTransform>(camera).unwrap().target = Vec3::new(0.0, 0.0, 10.0);
+        app.update();
+        let frustum = app.world.get::<CameraFrustum>(camera).unwrap();
+        assert!(!frustum.contains_point(Vec3::ZERO));
+        assert!(frustum.contains_point(Vec3::new(0.0, 0.0, 6.0)));
+    }
+}
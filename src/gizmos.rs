@@ -0,0 +1,69 @@
+use crate::{LookTransform, LookTransformSystem, Smoother};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, gizmos::gizmos::Gizmos, math::prelude::*,
+    render::color::Color,
+};
+
+/// Draws every `LookTransform` with gizmos, for debugging camera setups:
+///
+/// - A large sphere at the `eye` and a small sphere at the `target`
+/// - An arrow along the look direction, and a shorter arrow for `up`
+/// - A sphere in a different color where a `Smoother` is actually drawing the camera
+///
+/// A camera's own gizmos are mostly hidden from its view, so look at them from a second camera.
+pub struct LookTransformGizmosPlugin;
+
+impl Plugin for LookTransformGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            look_transform_gizmos_system.after(LookTransformSystem::Sync),
+        );
+    }
+}
+
+const EYE_COLOR: Color = Color::YELLOW;
+const TARGET_COLOR: Color = Color::RED;
+const UP_COLOR: Color = Color::GREEN;
+const SMOOTHED_COLOR: Color = Color::CYAN;
+
+pub fn look_transform_gizmos_system(
+    mut gizmos: Gizmos,
+    cameras: Query<(&LookTransform, Option<&Smoother>)>,
+) {
+    for (transform, smoother) in cameras.iter() {
+        gizmos.sphere(transform.eye, Quat::IDENTITY, 0.2, EYE_COLOR);
+        gizmos.sphere(transform.target, Quat::IDENTITY, 0.1, TARGET_COLOR);
+        arrow(
+            &mut gizmos,
+            transform.eye,
+            transform.target - transform.eye,
+            EYE_COLOR,
+        );
+        arrow(
+            &mut gizmos,
+            transform.eye,
+            0.5 * transform.up.normalize_or_zero(),
+            UP_COLOR,
+        );
+
+        if let Some(smoothed) = smoother.and_then(Smoother::current_transform) {
+            gizmos.sphere(smoothed.eye, Quat::IDENTITY, 0.15, SMOOTHED_COLOR);
+        }
+    }
+}
+
+/// A ray with two short lines at the end for its head.
+fn arrow(gizmos: &mut Gizmos, start: Vec3, vector: Vec3, color: Color) {
+    let Some(dir) = vector.try_normalize() else {
+        return;
+    };
+    let end = start + vector;
+    let head_length = (0.2 * vector.length()).min(0.3);
+    let side = dir.any_orthonormal_vector();
+
+    gizmos.ray(start, vector, color);
+    gizmos.line(end, end + head_length * (side - 2.0 * dir), color);
+    gizmos.line(end, end - head_length * (side + 2.0 * dir), color);
+}
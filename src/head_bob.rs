@@ -0,0 +1,162 @@
+use crate::{look_transform::is_synced, FollowExactly, LookTransform, Smoother, SpringSmoother};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+    transform::components::Transform,
+};
+
+/// Bobs the camera up and down while the player moves, like a walking head.
+///
+/// The bob is added to the scene graph `Transform` after smoothing, so the `LookTransform` itself is never disturbed. Its
+/// height scales with the movement speed, up to `amplitude` at `reference_speed`, and fades in and out as movement starts
+/// and stops. The speed is measured from the horizontal motion of the `eye`, unless set with `HeadBob::force_speed`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct HeadBob {
+    /// Bobs per second.
+    pub frequency: f32,
+    /// The largest vertical offset, in either direction.
+    pub amplitude: f32,
+    /// Set to `false` to fade the bob out, e.g. while the player is airborne.
+    pub active: bool,
+    /// The speed at which the bob reaches its full `amplitude`.
+    pub reference_speed: f32,
+    /// How much of the bob fades in or out per second.
+    pub fade_speed: f32,
+    /// If set, used instead of the measured speed.
+    pub forced_speed: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    phase: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fade: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_eye: Option<Vec3>,
+}
+
+impl Default for HeadBob {
+    fn default() -> Self {
+        Self::new(2.0, 0.05)
+    }
+}
+
+impl HeadBob {
+    pub fn new(frequency: f32, amplitude: f32) -> Self {
+        Self {
+            frequency,
+            amplitude,
+            active: true,
+            reference_speed: 4.0,
+            fade_speed: 4.0,
+            forced_speed: None,
+            phase: 0.0,
+            fade: 0.0,
+            last_eye: None,
+        }
+    }
+
+    /// Uses `speed` instead of measuring it, e.g. from a character controller's velocity.
+    pub fn force_speed(mut self, speed: f32) -> Self {
+        self.forced_speed = Some(speed);
+        self
+    }
+
+    /// Advances the bob by `dt` seconds for a camera whose eye is at `eye`, and returns the vertical offset.
+    pub fn update(&mut self, eye: Vec3, dt: f32) -> f32 {
+        let last_eye = self.last_eye.replace(eye);
+        let speed = match (self.forced_speed, last_eye) {
+            (Some(speed), _) => speed,
+            (None, Some(last_eye)) if dt > 0.0 => {
+                let delta = eye - last_eye;
+                Vec2::new(delta.x, delta.z).length() / dt
+            }
+            _ => 0.0,
+        };
+        let strength = if self.reference_speed > 0.0 {
+            (speed / self.reference_speed).min(1.0)
+        } else {
+            1.0
+        };
+
+        let goal = if self.active && speed > 0.0 { 1.0 } else { 0.0 };
+        let max_step = self.fade_speed * dt;
+        self.fade += (goal - self.fade).clamp(-max_step, max_step);
+        if self.fade > 0.0 {
+            self.phase = (self.phase + dt * self.frequency).fract();
+        } else {
+            self.phase = 0.0;
+        }
+
+        self.fade * strength * self.amplitude * (std::f32::consts::TAU * self.phase).sin()
+    }
+}
+
+/// Runs after `look_transform_system`, which rewrites the `Transform` every frame, so the bob doesn't accumulate.
+#[allow(clippy::type_complexity)]
+pub fn head_bob_system(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &mut HeadBob,
+        &LookTransform,
+        &mut Transform,
+        Option<&Smoother>,
+        Option<&SpringSmoother>,
+        Option<&FollowExactly>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (mut bob, look_transform, mut transform, smoother, spring, follow) in cameras.iter_mut() {
+        let offset = bob.update(look_transform.eye, dt);
+
+        let synced = is_synced(smoother, spring, follow.is_some());
+        if synced {
+            transform.translation += offset * look_transform.up.normalize_or_zero();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_bob_fades_in_and_out() {
+        let mut bob = HeadBob {
+            fade_speed: 2.0,
+            ..HeadBob::new(1.0, 0.1).force_speed(4.0)
+        };
+
+        // A quarter of the way through the first bob, half faded in.
+        let offset = bob.update(Vec3::ZERO, 0.25);
+        assert_relative_eq!(offset, 0.05, epsilon = 1e-5);
+
+        bob.update(Vec3::ZERO, 1.0);
+        bob.active = false;
+        assert_relative_eq!(bob.update(Vec3::ZERO, 0.25), 0.0, epsilon = 1e-5);
+        assert_relative_eq!(bob.fade, 0.5);
+        bob.update(Vec3::ZERO, 0.25);
+        assert_eq!(bob.fade, 0.0);
+    }
+
+    #[test]
+    fn test_measures_horizontal_speed() {
+        let mut bob = HeadBob::new(1.0, 0.1);
+        bob.fade_speed = 4.0;
+
+        bob.update(Vec3::ZERO, 0.25);
+        // Falling doesn't bob.
+        assert_eq!(bob.update(Vec3::new(0.0, -1.0, 0.0), 0.25), 0.0);
+        // Half the reference speed, half the amplitude.
+        let offset = bob.update(Vec3::new(0.5, -1.0, 0.0), 0.25);
+        assert_relative_eq!(offset, 0.05, epsilon = 1e-5);
+    }
+}
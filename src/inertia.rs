@@ -0,0 +1,173 @@
+use crate::{CameraTransition, LookTransform};
+
+use bevy::{
+    ecs::prelude::*,
+    input::{prelude::*, touch::Touches},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+};
+
+/// Lets a camera coast to a stop after a drag is released, instead of stopping abruptly.
+///
+/// While anything else moves the `LookTransform`, e.g. a controller following the mouse, its per-frame motion is
+/// recorded as the velocity. Once it stops being moved and no mouse button or touch is held, it keeps moving at that
+/// velocity, which decays by `friction` per second. Holding still while dragging zeroes the velocity, so nothing coasts.
+///
+/// The `eye` keeps its distance from the `target` while coasting, so an orbit swipe keeps circling instead of drifting
+/// away. Since this only moves the `LookTransform`, a `Smoother` still smooths the result.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct Inertia {
+    /// Units per second.
+    pub velocity_eye: Vec3,
+    /// Units per second.
+    pub velocity_target: Vec3,
+    /// The fraction of the velocity lost per second.
+    pub friction: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_transform: Option<LookTransform>,
+}
+
+impl Default for Inertia {
+    fn default() -> Self {
+        Self::new(4.0)
+    }
+}
+
+impl Inertia {
+    pub fn new(friction: f32) -> Self {
+        Self {
+            velocity_eye: Vec3::ZERO,
+            velocity_target: Vec3::ZERO,
+            friction,
+            last_transform: None,
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.velocity_eye = Vec3::ZERO;
+        self.velocity_target = Vec3::ZERO;
+    }
+
+    pub fn is_coasting(&self) -> bool {
+        self.velocity_eye != Vec3::ZERO || self.velocity_target != Vec3::ZERO
+    }
+
+    /// Moves `transform` for one frame, with `held` set while the user is touching the controls.
+    pub fn update(&mut self, transform: &mut LookTransform, dt: f32, held: bool) {
+        let Some(last) = self.last_transform.replace(*transform) else {
+            return;
+        };
+        if dt <= 0.0 {
+            return;
+        }
+
+        if *transform != last {
+            // Being dragged.
+            self.velocity_eye = (transform.eye - last.eye) / dt;
+            self.velocity_target = (transform.target - last.target) / dt;
+            return;
+        }
+        if held {
+            self.stop();
+            return;
+        }
+        if !self.is_coasting() {
+            return;
+        }
+
+        let radius = transform.radius();
+        transform.target += dt * self.velocity_target;
+        transform.eye += dt * self.velocity_eye;
+        *transform = transform.with_radius(radius);
+        self.last_transform = Some(*transform);
+
+        let decay = (1.0 - self.friction * dt).max(0.0);
+        self.velocity_eye *= decay;
+        self.velocity_target *= decay;
+        if self.velocity_eye.length_squared() < MIN_SPEED_SQUARED
+            && self.velocity_target.length_squared() < MIN_SPEED_SQUARED
+        {
+            self.stop();
+        }
+    }
+}
+
+/// Below this speed, coasting stops, so the `LookTransform` isn't touched every frame forever.
+const MIN_SPEED_SQUARED: f32 = 1e-6;
+
+pub fn inertia_system(
+    time: Res<Time>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    touches: Option<Res<Touches>>,
+    mut cameras: Query<(&mut Inertia, &mut LookTransform, Has<CameraTransition>)>,
+) {
+    let held = mouse_buttons.is_some_and(|b| b.get_pressed().next().is_some())
+        || touches.is_some_and(|t| t.iter().next().is_some());
+
+    let dt = time.delta_seconds();
+    for (mut inertia, mut transform, in_transition) in cameras.iter_mut() {
+        if in_transition {
+            // Don't coast off the end of a transition.
+            inertia.stop();
+            inertia.last_transform = None;
+            continue;
+        }
+
+        // Only write the `LookTransform` when it actually moves.
+        let mut new_transform = *transform;
+        inertia.update(&mut new_transform, dt, held);
+        if new_transform != *transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_coasts_after_release() {
+        let mut inertia = Inertia::new(2.0);
+        let mut transform = LookTransform::default();
+        inertia.update(&mut transform, 0.1, true);
+
+        // Dragged 1 unit in 0.1 seconds.
+        transform.eye.x += 1.0;
+        transform.target.x += 1.0;
+        inertia.update(&mut transform, 0.1, true);
+        assert_relative_eq!(inertia.velocity_target.x, 10.0);
+
+        // Released.
+        inertia.update(&mut transform, 0.1, false);
+        assert_relative_eq!(transform.target.x, 2.0, epsilon = 1e-5);
+        assert_relative_eq!(inertia.velocity_target.x, 8.0, epsilon = 1e-5);
+        assert_relative_eq!(transform.radius(), 5.0, epsilon = 1e-5);
+
+        for _ in 0..100 {
+            inertia.update(&mut transform, 0.1, false);
+        }
+        assert!(!inertia.is_coasting());
+
+        // Holding still stops instead of coasting.
+        transform.target.x += 1.0;
+        inertia.update(&mut transform, 0.1, true);
+        inertia.update(&mut transform, 0.1, true);
+        let held_at = transform;
+        inertia.update(&mut transform, 0.1, false);
+        assert_eq!(transform, held_at);
+    }
+}
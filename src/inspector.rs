@@ -0,0 +1,315 @@
+use crate::{LookTransform, Smoother, REFERENCE_FPS};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::Vec3, reflect::Reflect};
+use bevy_inspector_egui::{
+    egui, inspector_egui_impls::InspectorEguiImpl, reflect_inspector::InspectorUi,
+};
+use std::any::{Any, TypeId};
+
+/// Replaces `bevy-inspector-egui`'s UI for [`Smoother`] with lag weight sliders, each next to a graph of how quickly
+/// the camera closes the distance to its goal with that weight.
+///
+/// The widget is used wherever a `Smoother` is shown, such as in `bevy-inspector-egui`'s `WorldInspectorPlugin`.
+pub struct InspectableSmootherWidgetPlugin;
+
+impl Plugin for InspectableSmootherWidgetPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Smoother>();
+        app.world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .get_mut(TypeId::of::<Smoother>())
+            .unwrap()
+            .insert(InspectorEguiImpl::new(
+                smoother_ui,
+                smoother_ui_readonly,
+                smoother_ui_many,
+            ));
+    }
+}
+
+/// Replaces `bevy-inspector-egui`'s UI for [`LookTransform`] with drag widgets for each axis of the `eye`, `target`,
+/// and `up`, followed by the resulting `radius`, `pitch`, and `yaw`, which are read-only.
+///
+/// Add the [`InspectableSmootherWidgetPlugin`] too for the lag weight sliders.
+pub struct InspectableLookTransformWidgetPlugin;
+
+impl Plugin for InspectableLookTransformWidgetPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LookTransform>();
+        app.world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .get_mut(TypeId::of::<LookTransform>())
+            .unwrap()
+            .insert(InspectorEguiImpl::new(
+                look_transform_ui,
+                look_transform_ui_readonly,
+                look_transform_ui_many,
+            ));
+    }
+}
+
+fn look_transform_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+) -> bool {
+    let transform: &mut LookTransform = value.downcast_mut().unwrap();
+    let mut changed = false;
+
+    egui::Grid::new(id).show(ui, |ui| {
+        for (name, vector) in [
+            ("eye", &mut transform.eye),
+            ("target", &mut transform.target),
+            ("up", &mut transform.up),
+        ] {
+            ui.label(name);
+            ui.horizontal(|ui| {
+                for (prefix, axis) in [
+                    ("x: ", &mut vector.x),
+                    ("y: ", &mut vector.y),
+                    ("z: ", &mut vector.z),
+                ] {
+                    changed |= ui
+                        .add(egui::DragValue::new(axis).speed(0.1).prefix(prefix))
+                        .changed();
+                }
+            });
+            ui.end_row();
+        }
+
+        // Shown after the drag widgets, so they follow them while dragging.
+        derived_rows(ui, transform);
+    });
+
+    changed
+}
+
+fn look_transform_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+) {
+    let transform: &LookTransform = value.downcast_ref().unwrap();
+
+    egui::Grid::new(id).show(ui, |ui| {
+        for (name, vector) in [
+            ("eye", transform.eye),
+            ("target", transform.target),
+            ("up", transform.up),
+        ] {
+            ui.label(name);
+            ui.label(format!("{:.3} {:.3} {:.3}", vector.x, vector.y, vector.z));
+            ui.end_row();
+        }
+
+        derived_rows(ui, transform);
+    });
+}
+
+fn look_transform_ui_many(
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    _id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+    _values: &mut [&mut dyn Reflect],
+    _projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    ui.label("LookTransform doesn't support multi-editing.");
+    false
+}
+
+/// The read-only rows for the values computed from a `LookTransform`, with the angles in degrees.
+fn derived_rows(ui: &mut egui::Ui, transform: &LookTransform) {
+    for (name, value) in [
+        ("radius", format!("{:.3}", transform.radius())),
+        ("pitch", format!("{:.1}°", transform.pitch().to_degrees())),
+        ("yaw", format!("{:.1}°", transform.yaw().to_degrees())),
+    ] {
+        ui.label(name);
+        ui.label(value);
+        ui.end_row();
+    }
+}
+
+/// The graphs cover one second at the reference frame rate.
+const GRAPH_FRAMES: usize = 60;
+const GRAPH_SIZE: egui::Vec2 = egui::vec2(120.0, 40.0);
+
+fn smoother_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let smoother: &mut Smoother = value.downcast_mut().unwrap();
+    let mut changed = false;
+
+    egui::Grid::new(id).show(ui, |ui| {
+        let mut eye_lag_weight = smoother.eye_lag_weight();
+        if lag_weight_row(ui, "eye_lag_weight", &mut eye_lag_weight) {
+            smoother.set_eye_lag_weight(eye_lag_weight);
+            changed = true;
+        }
+        let mut target_lag_weight = smoother.target_lag_weight();
+        if lag_weight_row(ui, "target_lag_weight", &mut target_lag_weight) {
+            smoother.set_target_lag_weight(target_lag_weight);
+            changed = true;
+        }
+        let mut up_lag_weight = smoother.up_lag_weight();
+        if lag_weight_row(ui, "up_lag_weight", &mut up_lag_weight) {
+            smoother.set_up_lag_weight(up_lag_weight);
+            changed = true;
+        }
+
+        ui.label("interpolation");
+        let mut interpolation = smoother.interpolation();
+        if env.ui_for_reflect(&mut interpolation, ui) {
+            smoother.set_interpolation(interpolation);
+            changed = true;
+        }
+        ui.end_row();
+
+        ui.label("enabled");
+        let mut enabled = smoother.is_enabled();
+        if ui.checkbox(&mut enabled, "").changed() {
+            smoother.set_enabled(enabled);
+            changed = true;
+        }
+        ui.end_row();
+    });
+
+    changed
+}
+
+fn smoother_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+) {
+    let smoother: &Smoother = value.downcast_ref().unwrap();
+
+    egui::Grid::new(id).show(ui, |ui| {
+        for (name, lag_weight) in [
+            ("eye_lag_weight", smoother.eye_lag_weight()),
+            ("target_lag_weight", smoother.target_lag_weight()),
+            ("up_lag_weight", smoother.up_lag_weight()),
+        ] {
+            ui.label(name);
+            ui.label(format!("{lag_weight:.3}"));
+            decay_graph(ui, lag_weight);
+            ui.end_row();
+        }
+
+        ui.label("interpolation");
+        ui.label(format!("{:?}", smoother.interpolation()));
+        ui.end_row();
+
+        ui.label("enabled");
+        ui.label(smoother.is_enabled().to_string());
+        ui.end_row();
+    });
+}
+
+fn smoother_ui_many(
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    _id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+    _values: &mut [&mut dyn Reflect],
+    _projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    ui.label("Smoother doesn't support multi-editing.");
+    false
+}
+
+fn lag_weight_row(ui: &mut egui::Ui, name: &str, lag_weight: &mut f32) -> bool {
+    ui.label(name);
+    let changed = ui.add(egui::Slider::new(lag_weight, 0.0..=0.999)).changed();
+    // Drawn after the slider, so the graph follows it while dragging.
+    decay_graph(ui, *lag_weight);
+    ui.end_row();
+
+    changed
+}
+
+fn decay_graph(ui: &mut egui::Ui, lag_weight: f32) {
+    let (response, painter) = ui.allocate_painter(GRAPH_SIZE, egui::Sense::hover());
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+
+    let points = decay_curve(lag_weight)
+        .enumerate()
+        .map(|(frame, remaining)| {
+            egui::pos2(
+                rect.left() + rect.width() * frame as f32 / GRAPH_FRAMES as f32,
+                rect.bottom() - rect.height() * remaining,
+            )
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        ui.visuals().widgets.active.fg_stroke,
+    ));
+}
+
+/// The fraction of the distance to a new goal that a `Smoother` with `lag_weight` has yet to cover, starting at `1.0`
+/// and then after each of `GRAPH_FRAMES` frames at the reference frame rate.
+fn decay_curve(lag_weight: f32) -> impl Iterator<Item = f32> {
+    let start = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+    let goal = LookTransform {
+        eye: Vec3::X,
+        target: Vec3::X + Vec3::NEG_Z,
+        ..start
+    };
+    let mut smoother = Smoother::new(lag_weight);
+    smoother.reset_to(&start);
+
+    std::iter::once(1.0).chain(
+        (0..GRAPH_FRAMES)
+            .map(move |_| 1.0 - smoother.smooth_transform(&goal, 1.0 / REFERENCE_FPS).eye.x),
+    )
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_decay_curve_is_exponential() {
+        let curve: Vec<f32> = decay_curve(0.9).collect();
+
+        assert_eq!(curve.len(), GRAPH_FRAMES + 1);
+        for (frame, remaining) in curve.into_iter().enumerate() {
+            assert_relative_eq!(remaining, 0.9f32.powi(frame as i32), epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_registers_look_transform_widget() {
+        let mut app = App::new();
+        app.add_plugins(InspectableLookTransformWidgetPlugin);
+
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        assert!(registry
+            .get_type_data::<InspectorEguiImpl>(TypeId::of::<LookTransform>())
+            .is_some());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::LookTransform;
+
+use bevy::{ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect};
+use std::collections::VecDeque;
+
+/// Makes the camera replay its `LookTransform` from `delay_seconds` ago, so it trails the action by a fixed time instead
+/// of catching up exponentially.
+///
+/// The delayed transform is what the `Smoother` or `SpringSmoother` smooths, so the two compose: lag first, then smooth.
+/// Cameras without a smoother but with [`FollowExactly`](crate::FollowExactly) get the delayed transform as is, while a
+/// disabled smoother leaves the `Transform` alone as usual. The `LookTransform` itself is left alone, so controllers keep working with the live position.
+#[derive(Clone, Component, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraLag {
+    pub delay_seconds: f32,
+    /// (elapsed seconds, transform), oldest first.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    buffer: VecDeque<(f32, LookTransform)>,
+}
+
+impl Default for CameraLag {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl CameraLag {
+    pub fn new(delay_seconds: f32) -> Self {
+        Self {
+            delay_seconds,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Records `transform` at `now` seconds and returns the recorded transform closest to `delay_seconds` earlier. Until
+    /// that far back has been recorded, this is the oldest one.
+    pub fn update(&mut self, now: f32, transform: LookTransform) -> LookTransform {
+        self.buffer.push_back((now, transform));
+
+        // Keep the newest entry at or before the delayed time, and everything after it.
+        let delayed = now - self.delay_seconds;
+        while self.buffer.len() > 1 && self.buffer[1].0 <= delayed {
+            self.buffer.pop_front();
+        }
+
+        match (self.buffer.front(), self.buffer.get(1)) {
+            (Some(&(t0, _)), Some(&(t1, after))) if t0 < delayed && t1 - delayed < delayed - t0 => {
+                after
+            }
+            (Some(&(_, before)), _) => before,
+            (None, _) => transform,
+        }
+    }
+
+    /// Forgets the recorded transforms, e.g. after teleporting the camera.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        look_transform_system, FollowExactly, LookTransformMoved, LookTransformMovedEpsilon,
+    };
+
+    use bevy::{app::prelude::*, math::Vec3, time::Time, transform::components::Transform};
+    use std::time::Duration;
+
+    fn at(x: f32) -> LookTransform {
+        LookTransform::new(Vec3::new(x, 0.0, 5.0), Vec3::new(x, 0.0, 0.0), Vec3::Y)
+    }
+
+    #[test]
+    fn test_replays_after_delay() {
+        let mut lag = CameraLag::new(0.6);
+        let outputs: Vec<_> = (0..8)
+            .map(|frame| {
+                let now = 0.25 * frame as f32;
+                lag.update(now, at(frame as f32)).eye.x
+            })
+            .collect();
+
+        // Holds the first transform until the delay has nearly been recorded, then trails by the closest number of frames.
+        assert_eq!(outputs, [0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!(lag.buffer.len() <= 4);
+    }
+
+    #[test]
+    fn test_lags_cameras_without_a_smoother() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<LookTransformMovedEpsilon>()
+            .add_event::<LookTransformMoved>()
+            .add_systems(Update, look_transform_system);
+        let camera = app
+            .world
+            .spawn((
+                at(0.0),
+                Transform::default(),
+                CameraLag::new(0.6),
+                FollowExactly,
+            ))
+            .id();
+
+        let xs: Vec<_> = (0..5)
+            .map(|frame| {
+                app.world.get_mut::<LookTransform>(camera).unwrap().eye.x = frame as f32;
+                app.world
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_secs_f32(0.25));
+                app.update();
+                app.world.get::<Transform>(camera).unwrap().translation.x
+            })
+            .collect();
+
+        assert_eq!(xs, [0.0, 0.0, 0.0, 1.0, 2.0]);
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{CameraUpdateSystem, PerspectiveProjection, Projection},
+};
+
+/// Keeps each [`CameraLensParams`] up to date with its camera's projection.
+pub struct CameraLensPlugin;
+
+impl Plugin for CameraLensPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraLensParams>().add_systems(
+            PostUpdate,
+            sync_camera_lens_params_system.after(CameraUpdateSystem),
+        );
+    }
+}
+
+/// A camera's perspective lens, in one place for post-processing passes like SSAO and depth of field.
+///
+/// Add it to a camera with a `PerspectiveProjection`, or a `Projection` that is perspective, and
+/// [`sync_camera_lens_params_system`] copies the projection into it every frame, after Bevy updates the aspect ratio.
+/// An orthographic `Projection` leaves it unchanged.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CameraLensParams {
+    pub near: f32,
+    pub far: f32,
+    /// The vertical field of view, in radians.
+    pub fov: f32,
+    /// Width divided by height.
+    pub aspect: f32,
+}
+
+impl Default for CameraLensParams {
+    fn default() -> Self {
+        Self::from(&PerspectiveProjection::default())
+    }
+}
+
+impl From<&PerspectiveProjection> for CameraLensParams {
+    fn from(projection: &PerspectiveProjection) -> Self {
+        Self {
+            near: projection.near,
+            far: projection.far,
+            fov: projection.fov,
+            aspect: projection.aspect_ratio,
+        }
+    }
+}
+
+pub fn sync_camera_lens_params_system(
+    mut cameras: Query<(
+        &mut CameraLensParams,
+        Option<&PerspectiveProjection>,
+        Option<&Projection>,
+    )>,
+) {
+    for (mut lens, perspective, projection) in cameras.iter_mut() {
+        let perspective = perspective.or(match projection {
+            Some(Projection::Perspective(perspective)) => Some(perspective),
+            _ => None,
+        });
+        if let Some(perspective) = perspective {
+            let params = CameraLensParams::from(perspective);
+            // Only write when something changed, so `Changed<CameraLensParams>` is useful to render code.
+            if *lens != params {
+                *lens = params;
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lens_follows_projection() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_camera_lens_params_system);
+        let projection = PerspectiveProjection {
+            fov: 1.0,
+            aspect_ratio: 2.0,
+            near: 0.5,
+            far: 50.0,
+        };
+        let camera = app
+            .world
+            .spawn((
+                Projection::Perspective(projection.clone()),
+                CameraLensParams::default(),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(
+            *app.world.get::<CameraLensParams>(camera).unwrap(),
+            CameraLensParams {
+                near: 0.5,
+                far: 50.0,
+                fov: 1.0,
+                aspect: 2.0,
+            }
+        );
+    }
+}
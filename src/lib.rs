@@ -15,7 +15,33 @@
 //! will have no effect on the `LookTransform`, only the final `Transform` in
 //! the scene graph.
 //!
-//! ```rust
+//! For a snappier feel, use a [`SpringSmoother`] instead of a `Smoother`. It
+//! simulates a damped spring that may overshoot its goal.
+//!
+//! For a camera that shouldn't be smoothed at all, like one on a cinematic rail,
+//! spawn a [`MinimalLookTransformBundle`] instead, which has no smoother, so the
+//! `Transform` follows the `LookTransform` exactly. Its [`FollowExactly`] marker opts into this,
+//! so a bare `LookTransform` without a smoother leaves the `Transform` alone.
+//!
+//! To smooth less while the camera moves quickly, also add an [`AdaptiveSmoother`], which
+//! adjusts the `Smoother`'s lag weight based on the camera's speed.
+//!
+//! For a camera that trails the action by a fixed time instead, add a
+//! [`CameraLag`]. The delayed transform is then smoothed as usual.
+//!
+//! To ease the lag in as the camera starts moving, or out once input stops, give
+//! the `Smoother` a [`LagWeightCurve`] with `Smoother::with_lag_curve`.
+//!
+//! To stop fast mouse flicks from whipping the view around, add a
+//! [`MaxRotationSpeed`], which caps how fast the look direction turns before smoothing.
+//!
+//! To know how fast the smoothed camera is moving, spawn a `FullLookTransformBundle`, which adds a
+//! [`LookTransformVelocity`] to the usual components.
+//!
+//! For motion blur, add a [`MotionBlurHint`] to a camera to keep its
+//! [`PreviousLookTransform`] from the last frame.
+//!
+//! ```no_run
 //! use bevy::prelude::*;
 //! use smooth_bevy_cameras::{LookTransform, LookTransformBundle, LookTransformPlugin, Smoother};
 //!
@@ -23,9 +49,9 @@
 //!     App::new()
 //!         .add_plugins(DefaultPlugins)
 //!         // Enables the system that synchronizes your `Transform`s and `LookTransform`s.
-//!         .add_plugin(LookTransformPlugin)
-//!         .add_startup_system(setup)
-//!         .add_system(move_camera_system);
+//!         .add_plugins(LookTransformPlugin::default())
+//!         .add_systems(Startup, setup)
+//!         .add_systems(Update, move_camera_system);
 //! }
 //!
 //! fn setup(mut commands: Commands) {
@@ -47,6 +73,86 @@
 //! }
 //! ```
 //!
+//! `LookTransformBundle::looking_at(eye, target, 0.9)` builds the same bundle, with
+//! `Vec3::Y` up.
+//!
+//! For a camera whose `Transform` is already placed, e.g. in an editor, add a
+//! [`MatchTransformOnStart`] so its `LookTransform` starts from that `Transform`
+//! instead of moving the camera on the first frame.
+//!
+//! For worlds with changing gravity, an [`AutoUpVector`] keeps the camera's `up`
+//! opposite the [`WorldGravity`] resource, or aligned with another entity.
+//!
+//! To move cameras from game logic, such as AI or scripted sequences, without
+//! fighting a controller for the `LookTransform`, send [`CameraCommand`]
+//! events instead. To animate the active camera to a new scene's viewpoint,
+//! insert a [`SceneCameraTransition`] resource. For a turntable, add a
+//! [`CameraCircle`], which spins the camera until the next `CameraCommand`.
+//! A [`CameraTransition`] with `blend_controller` set crossfades from the
+//! camera's controller, which keeps running, rather than taking over from it.
+//! To turn a camera toward a point without moving it, add a
+//! [`CameraLookAtAnimation`], which sends [`CameraLookAtFinished`] once it's done.
+//!
+//! To mix two controllers, such as a scripted pan and the player looking around,
+//! give a camera a [`BlendedCameraController`] that blends the `LookTransform`s of two
+//! source entities by weight, and mark the sources [`Inactive`] so they aren't drawn.
+//!
+//! To record a camera flying through a scene and play it back, add a
+//! [`CameraRecorder`] with the `CameraRecorderPlugin`.
+//!
+//! The [`CameraShortcutPlugin`] binds editor-style keys, like F to frame and
+//! Home to reset, to [`CameraShortcut`] events, and sends a `CameraCommand` for
+//! the reset. Rebind them with the [`CameraShortcutConfig`] resource.
+//!
+//! The [`CameraBookmarkPlugin`] saves the active camera to one of nine
+//! [`CameraBookmarks`] with Ctrl and a number key, and moves back to it with the
+//! number key alone.
+//!
+//! # System Ordering
+//!
+//! The `LookTransform` is synchronized to the `Transform` in `PostUpdate`, in
+//! the [`LookTransformSystem::Sync`] set, before Bevy's transform propagation.
+//! So any system in `Update` can modify a `LookTransform` and the camera moves
+//! in the same frame. To modify it from `PostUpdate`, order your system
+//! `.before(LookTransformSystem::Sync)`.
+//!
+//! For finer control, the [`LookTransformSystemSet`] stages order systems
+//! around it: `.after(LookTransformSystemSet::LookTransformInput)` runs after the
+//! built-in controllers, `.before(LookTransformSystemSet::LookTransformUpdate)`
+//! sees the final `LookTransform` before the `Transform` is written, and
+//! `.after(LookTransformSystemSet::LookTransformPostProcess)` sees the `Transform`
+//! once head bob, roll, and drift are applied.
+//!
+//! To order the sync relative to your own `PostUpdate` sets instead, build the
+//! plugin with `LookTransformPlugin::default().in_set(..)`, `.before(..)`, or
+//! `.after(..)`.
+//!
+//! Before version 0.11, synchronization ran in `Update` without any ordering,
+//! which could make the camera lag a frame behind and jitter.
+//!
+//! Cameras that follow physics bodies can use the [`LookTransformFixedPlugin`] instead,
+//! which runs the same systems in `FixedUpdate`, so the camera never sees a body
+//! between physics steps. The tradeoff is that the camera only moves on frames
+//! where a fixed step runs, which looks jittery when the frame rate doesn't
+//! match the fixed rate, unless the camera is interpolated when rendering.
+//!
+//! Physics plugins that write bodies back in `PostUpdate`, like `bevy_rapier`, need
+//! the sync ordered after them, or the camera may see a body's old position on
+//! some frames and jitter. Each frame should run physics, then write the bodies'
+//! `Transform`s, then update each `LookTransform` from them, and finally write
+//! the camera `Transform`, e.g. with
+//! `LookTransformPlugin::default().after(PhysicsSet::Writeback)`. Follow systems
+//! that read a body's `Transform` go in `PostUpdate` too, between the writeback
+//! and `.before(LookTransformSystem::Sync)`.
+//!
+//! To profile the sync in large scenes, add the [`LookTransformDiagnosticsPlugin`],
+//! which reports `camera_count`, `smooth_iterations_per_frame`, and
+//! `look_transform_ms` diagnostics, e.g. for Bevy's `LogDiagnosticsPlugin`.
+//!
+//! To set things up for each camera, like an audio listener, read the
+//! [`CameraAdded`] and [`CameraRemoved`] events, which are sent in `Last` when an
+//! entity gains or loses a `LookTransform`.
+//!
 //! # Look Angles
 //!
 //! When implementing a camera controller, it's often useful to work directly
@@ -73,6 +179,66 @@
 //!
 //! This is how the built-in controllers implement rotation controls.
 //!
+//! # Features
+//!
+//! - `serde`: Implements `Serialize` and `Deserialize` for [`LookTransform`],
+//!   [`Smoother`], and the controller components, so camera state can be saved.
+//!   A `Smoother`'s in-progress interpolation isn't saved; it restarts from the
+//!   loaded `LookTransform`.
+//!   [`CameraState`] is a compact snapshot of a camera for save files.
+//! - `camera_path`: Adds the `CameraPathPlugin`, which loads `CameraPath` assets
+//!   from `.camera_path.ron` files and moves `CameraPathFollower` cameras along them.
+//!   Also adds `CameraRecorder::export_ron` and `import_ron` to save recordings.
+//!   Implies `serde`.
+//! - `camera_dump`: Adds the `CameraDumpOnExit` plugin, which prints each camera's
+//!   `CameraState` as RON when the app exits, in debug builds. Implies `serde`.
+//! - `smooth_fov`: Adds the `SmoothFovPlugin`, which smooths changes to the field
+//!   of view of cameras with a `SmoothFov` component, and widens it as
+//!   `DynamicFovController` cameras speed up, measured by their
+//!   `LookTransformVelocity`. Enables `bevy_render`.
+//! - `debug_gizmos`: Adds the `LookTransformGizmosPlugin`, which draws each
+//!   `LookTransform` and its smoothed position with gizmos. Enables `bevy_gizmos`
+//!   and `bevy_render`.
+//! - `orthographic`: Adds the orthographic camera controller, which zooms by
+//!   changing the projection scale. Enables `bevy_render`.
+//! - `render`: Adds `LookTransform::screen_to_world_ray`, which computes picking rays
+//!   from a `PerspectiveProjection`, `LookTransform::project_point_to_screen`, its
+//!   inverse for placing UI over the world, `LookTransform::frame_aabb`, which fits a
+//!   camera to an `Aabb`, `LookTransform::to_view_projection_matrix` for custom
+//!   render passes, the `DollyZoomPlugin`, which plays
+//!   `DollyZoom` "vertigo" effects, the `CameraFrustumPlugin`, which keeps a
+//!   `CameraFrustum` for visibility queries in game logic, the
+//!   `CameraLensPlugin`, which keeps a `CameraLensParams` for post-processing passes,
+//!   the `CameraZoomFitPlugin`, which smoothly frames entities with `CameraZoomFit`,
+//!   and the `CameraCapturePlugin`, which renders `CameraCapture` cameras to an
+//!   image, e.g. for a minimap: add the plugin, spawn a camera with
+//!   `CameraCapture::new(width, height)` and a lower `Camera::order` than the main
+//!   camera, then show `CameraCapture::target_image` in a `UiImage` or material once
+//!   it's set in `PostUpdate`, the `WorldToScreenPlugin`, which caches each
+//!   camera's projection in a `WorldToScreen` resource for `world_to_screen`, e.g.
+//!   for targeting reticles, and the `ScreenSpacePivotOrbitPlugin`, which lets
+//!   orbit cameras with a `ScreenSpacePivotOrbit` orbit around the `Aabb` under the
+//!   center of the screen. Enables `bevy_render`.
+//! - `animation`: Adds the `BevyAnimationCameraDriverPlugin`, which moves
+//!   `BevyAnimationCameraDriver` cameras along an `AnimationClip`, such as one
+//!   exported from Blender as glTF. The translations of the `camera/eye` and
+//!   `camera/target` bones drive the `eye` and `target`. Enables `bevy_animation`
+//!   and `bevy_asset`.
+//! - `bevy_inspector_egui_integration`: Adds the `InspectableSmootherWidgetPlugin`,
+//!   which shows each `Smoother` lag weight in `bevy-inspector-egui` with a graph
+//!   of how quickly the camera catches up, redrawn as the slider moves, and the
+//!   `InspectableLookTransformWidgetPlugin`, which shows drag widgets for each axis
+//!   of a `LookTransform` with its `radius`, `pitch`, and `yaw` below.
+//! - `bevy_egui`: Sets the `CameraInputLock` while egui wants the pointer, so
+//!   dragging a window doesn't also move the camera.
+//! - `parallel_transform_sync`: Syncs cameras' `Transform`s in parallel, for scenes
+//!   with hundreds of cameras. Enables Bevy's `multi-threaded` feature.
+//!
+//! All of the components, including `LookTransform`, `Smoother`, and the
+//! controllers, implement `Reflect` and are registered by their plugins, so they
+//! can be edited at runtime with reflection-based tools like
+//! `bevy-inspector-egui`'s world inspector.
+//!
 //! # Built-In Controllers
 //!
 //! These plugins depend on the [`LookTransformPlugin`]:
@@ -82,11 +248,29 @@
 //!   - WASD: Translate on the XZ plane
 //!   - Shift/Space: Translate along the Y axis
 //!   - Mouse: Rotate camera
+//!   - Keeps its angles in a [`PitchYawRoll`], e.g. for clamping or a UI
+//! - [`FreeFlyPlugin`](crate::controllers::free_fly::FreeFlyPlugin) +
+//!   [`FreeFlyBundle`](crate::controllers::free_fly::FreeFlyBundle)
+//!   - WASD: Fly along the look direction, unconstrained
+//!   - Q/E: Fly down/up
+//!   - Shift: Fly faster
+//!   - Mouse: Rotate camera
 //! - [`OrbitCameraPlugin`](crate::controllers::orbit::OrbitCameraPlugin) +
 //!   [`OrbitCameraBundle`](crate::controllers::orbit::OrbitCameraBundle)
-//!   - CTRL + mouse drag: Rotate camera
+//!   - Mouse: Rotate camera
 //!   - Right mouse drag: Pan camera
 //!   - Mouse wheel: Zoom
+//!   - With a [`TouchCameraConfig`](crate::controllers::touch::TouchCameraConfig)
+//!     resource: one finger drag to rotate, two finger drag to pan, pinch to zoom
+//!   - With `orbit_entity` set: orbit around a moving entity
+//!   - `OrbitMode::ReverseOrbit`: swing the target around the eye instead, e.g. for
+//!     a character camera that turns in place
+//!   - `OrbitCameraPlugin::with_sensitivity_profile`: preset sensitivities, from
+//!     `Precise` for editors to `Fast`
+//!   - `orbit_sensitivity_distance_scale`: slows rotation when zoomed out,
+//!     `Linear`ly or `Logarithmic`ally like CAD tools
+//!   - With a `ScreenSpacePivotOrbit` and the `render` feature: orbit around
+//!     whatever is in the center of the screen, like Blender
 //! - [`UnrealCameraPlugin`](crate::controllers::unreal::UnrealCameraPlugin) +
 //!   [`UnrealCameraBundle`](crate::controllers::unreal::UnrealCameraBundle)
 //!
@@ -102,11 +286,206 @@
 //!     locomotion and panning speeds
 //!   - While holding no mouse button, use scroll wheel for locomotion
 //!     forward/backward
+//! - [`OrbitAroundEntityPlugin`](crate::controllers::orbit_around_entity::OrbitAroundEntityPlugin) +
+//!   [`OrbitAroundEntity`](crate::controllers::orbit_around_entity::OrbitAroundEntity)
+//!   - Left mouse drag: Rotate camera around a moving entity
+//!   - Mouse wheel: Zoom
+//! - [`TopDownCameraPlugin`](crate::controllers::top_down::TopDownCameraPlugin) +
+//!   [`TopDownCameraBundle`](crate::controllers::top_down::TopDownCameraBundle)
+//!   - WASD/Arrow keys: Pan on the XZ plane
+//!   - Q/E: Rotate around the vertical axis
+//!   - Mouse wheel: Zoom
+//! - [`IsometricCameraPlugin`](crate::controllers::isometric::IsometricCameraPlugin) +
+//!   [`IsometricCameraBundle`](crate::controllers::isometric::IsometricCameraBundle)
+//!   - WASD/Arrow keys: Pan on the XZ plane at a fixed viewing angle
+//!   - Cursor at the window edge: Pan, if `edge_scroll` is enabled
+//!   - Mouse wheel: Zoom
+//! - [`TrackballCameraPlugin`](crate::controllers::trackball::TrackballCameraPlugin) +
+//!   [`TrackballCameraBundle`](crate::controllers::trackball::TrackballCameraBundle)
+//!   - Left mouse drag: Tumble the camera freely around the target
+//!   - Mouse wheel: Zoom
+//! - [`ThirdPersonCameraPlugin`](crate::controllers::third_person::ThirdPersonCameraPlugin) +
+//!   [`ThirdPersonCameraBundle`](crate::controllers::third_person::ThirdPersonCameraBundle)
+//!   - Follows behind a character on a spring arm, which can be shortened by
+//!     your own physics raycast
+//! - [`FollowCameraPlugin`](crate::controllers::follow::FollowCameraPlugin) +
+//!   [`FollowCamera`](crate::controllers::follow::FollowCamera)
+//!   - Follows another entity at a fixed offset
+//! - [`PanZoom2dPlugin`](crate::controllers::pan_zoom_2d::PanZoom2dPlugin) +
+//!   [`PanZoom2dBundle`](crate::controllers::pan_zoom_2d::PanZoom2dBundle)
+//!   - Left mouse drag: Pan on the XY plane
+//!   - Mouse wheel: Zoom toward the cursor
+//! - [`SmoothOrbit2dPlugin`](crate::controllers::smooth_orbit_2d::SmoothOrbit2dPlugin) +
+//!   [`SmoothOrbit2dBundle`](crate::controllers::smooth_orbit_2d::SmoothOrbit2dBundle)
+//!   - Follows an entity on the XY plane with momentum, looking a little ahead
+//! - `OrthographicCameraPlugin` + `OrthographicCameraBundle`, with the `orthographic` feature
+//!   - WASD/Arrow keys or left mouse drag: Pan in the view plane
+//!   - Mouse wheel: Zoom by scaling the projection
+//!
+//! - [`EdgeScrollPlugin`](crate::controllers::edge_scroll::EdgeScrollPlugin) +
+//!   [`EdgeScrollConfig`](crate::controllers::edge_scroll::EdgeScrollConfig)
+//!   - Cursor at the window edge: Pan any camera in its own frame
+//!
+//! - [`CameraZoomPlugin`](crate::controllers::zoom::CameraZoomPlugin) +
+//!   [`CameraZoom`](crate::controllers::zoom::CameraZoom)
+//!   - Mouse wheel: Zoom any camera by moving its eye along the look direction
+//!
+//! Insert a [`GamepadCameraController`](crate::controllers::gamepad::GamepadCameraController)
+//! resource to also drive the FPS, orbit, and Unreal controllers with a gamepad.
+//!
+//! To rebind the mouse buttons or keys, set a controller's [`MouseInputConfig`](crate::controllers::input_config::MouseInputConfig)
+//! or [`KeyboardInputConfig`](crate::controllers::input_config::KeyboardInputConfig).
+//! Controllers that rotate with the mouse also have a `sensitivity_curve`, a
+//! [`SensitivityCurve`](crate::controllers::input_config::SensitivityCurve) for
+//! finer control while the mouse moves slowly, and `invert_x` and `invert_y`
+//! flags, which can be toggled at runtime, e.g. from a settings menu.
+//!
+//! With several cameras, such as a main view and a minimap, add an [`ActiveCamera`] marker
+//! to the one that should respond to input. Without any marker, every camera does.
+//!
+//! For split screen, also give each camera a [`CameraViewport`], so it only
+//! responds to the mouse while the cursor is over its part of the window. The
+//! keyboard and gamepad still go to the `ActiveCamera`.
+//!
+//! In an editor with a viewport per panel, give each camera a [`CameraInputFocus`],
+//! so only the focused one responds. The `ActiveCameraPlugin` focuses whichever
+//! camera the cursor is over, or set `focused` yourself, e.g. on click.
+//!
+//! To keep the mouse for your own UI, set the [`CameraInputLock`] resource to `true`
+//! on each frame the UI wants it. The `bevy_egui` feature sets it whenever egui
+//! wants the pointer.
+//!
+//! To stop every controller on a camera during a cutscene or menu, call
+//! `commands.disable_all(camera)` from the [`CameraCommandsExt`] trait, and `enable_all`
+//! afterwards. `CameraCommand`s and transitions still move the camera.
+//!
+//! To write your own controller, implement [`CameraController`] for a component and
+//! add a [`CameraControllerPlugin`] for it. Its `update` gets a [`CameraInputState`]
+//! with the frame's mouse motion, scrolling, and keys.
+//!
+//! # Web
+//!
+//! The crate doesn't use threads or thread-locals of its own, so it builds for
+//! `wasm32-unknown-unknown` along with the rest of Bevy. To run the examples in a
+//! browser, install the target and `wasm-server-runner`:
+//!
+//! ```sh
+//! rustup target add wasm32-unknown-unknown
+//! cargo install wasm-server-runner
+//! cargo run --target wasm32-unknown-unknown --example simple_orbit
+//! ```
+//!
+//! The repository's `.cargo/config.toml` uses `wasm-server-runner` to run wasm
+//! builds, so `cargo run` serves the example and prints the URL to open.
 
 pub mod controllers;
 
+mod active_camera;
+mod adaptive_smoother;
+#[cfg(feature = "animation")]
+mod animation;
+mod bookmark;
+mod camera_history;
+#[cfg(feature = "camera_path")]
+mod camera_path;
+mod camera_recorder;
+mod camera_state;
+#[cfg(feature = "render")]
+mod capture;
+mod command;
+mod constraint;
+mod controller_blend;
+mod custom_controller;
+mod diagnostics;
+#[cfg(feature = "render")]
+mod dolly_zoom;
+mod drift;
+#[cfg(feature = "camera_dump")]
+mod dump;
+#[cfg(feature = "render")]
+mod frustum;
+#[cfg(feature = "debug_gizmos")]
+mod gizmos;
+mod head_bob;
+mod inertia;
+#[cfg(feature = "bevy_inspector_egui_integration")]
+mod inspector;
+mod lag;
+#[cfg(feature = "render")]
+mod lens;
+mod lifecycle;
 mod look_angles;
+mod look_at_animation;
 mod look_transform;
+mod match_transform;
+mod motion_blur;
+mod pitch_yaw_roll;
+mod roll;
+mod rotation_speed;
+mod shake;
+mod shortcut;
+#[cfg(feature = "smooth_fov")]
+mod smooth_fov;
+mod transition;
+mod turntable;
+mod up_vector;
+mod velocity;
+#[cfg(feature = "render")]
+mod world_to_screen;
+#[cfg(feature = "render")]
+mod zoom_fit;
 
+pub use active_camera::*;
+pub use adaptive_smoother::*;
+#[cfg(feature = "animation")]
+pub use animation::*;
+pub use bookmark::*;
+pub use camera_history::*;
+#[cfg(feature = "camera_path")]
+pub use camera_path::*;
+pub use camera_recorder::*;
+pub use camera_state::*;
+#[cfg(feature = "render")]
+pub use capture::*;
+pub use command::*;
+pub use constraint::*;
+pub use controller_blend::*;
+pub use custom_controller::*;
+pub use diagnostics::*;
+#[cfg(feature = "render")]
+pub use dolly_zoom::*;
+pub use drift::*;
+#[cfg(feature = "camera_dump")]
+pub use dump::*;
+#[cfg(feature = "render")]
+pub use frustum::*;
+#[cfg(feature = "debug_gizmos")]
+pub use gizmos::*;
+pub use head_bob::*;
+pub use inertia::*;
+#[cfg(feature = "bevy_inspector_egui_integration")]
+pub use inspector::*;
+pub use lag::*;
+#[cfg(feature = "render")]
+pub use lens::*;
+pub use lifecycle::*;
 pub use look_angles::*;
+pub use look_at_animation::*;
 pub use look_transform::*;
+pub use match_transform::*;
+pub use motion_blur::*;
+pub use pitch_yaw_roll::*;
+pub use roll::*;
+pub use rotation_speed::*;
+pub use shake::*;
+pub use shortcut::*;
+#[cfg(feature = "smooth_fov")]
+pub use smooth_fov::*;
+pub use transition::*;
+pub use turntable::*;
+pub use up_vector::*;
+pub use velocity::*;
+#[cfg(feature = "render")]
+pub use world_to_screen::*;
+#[cfg(feature = "render")]
+pub use zoom_fit::*;
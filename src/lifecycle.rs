@@ -0,0 +1,72 @@
+use crate::LookTransform;
+
+use bevy::ecs::prelude::*;
+
+/// Sent when an entity gets a `LookTransform`, e.g. to set up an audio listener for each new camera without polling for
+/// `Added<LookTransform>`.
+///
+/// [`camera_lifecycle_system`] sends these in `Last`, so they're seen in the next frame, whichever schedule spawned the
+/// camera.
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub struct CameraAdded {
+    pub entity: Entity,
+}
+
+/// Sent when an entity loses its `LookTransform`, or is despawned with it, like [`CameraAdded`].
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub struct CameraRemoved {
+    pub entity: Entity,
+}
+
+pub fn camera_lifecycle_system(
+    mut added_events: EventWriter<CameraAdded>,
+    mut removed_events: EventWriter<CameraRemoved>,
+    added: Query<Entity, Added<LookTransform>>,
+    mut removed: RemovedComponents<LookTransform>,
+) {
+    removed_events.send_batch(removed.read().map(|entity| CameraRemoved { entity }));
+    added_events.send_batch(added.iter().map(|entity| CameraAdded { entity }));
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::{app::prelude::*, ecs::event::ManualEventReader};
+
+    #[test]
+    fn test_sends_added_and_removed() {
+        let mut app = App::new();
+        app.add_event::<CameraAdded>()
+            .add_event::<CameraRemoved>()
+            .add_systems(Last, camera_lifecycle_system);
+        let mut added = ManualEventReader::<CameraAdded>::default();
+        let mut removed = ManualEventReader::<CameraRemoved>::default();
+
+        let camera = app.world.spawn(LookTransform::default()).id();
+        app.update();
+        let events = app.world.resource::<Events<CameraAdded>>();
+        assert_eq!(
+            added.read(events).copied().collect::<Vec<_>>(),
+            [CameraAdded { entity: camera }]
+        );
+
+        app.update();
+        assert_eq!(added.read(app.world.resource()).count(), 0);
+
+        app.world.despawn(camera);
+        app.update();
+        let events = app.world.resource::<Events<CameraRemoved>>();
+        assert_eq!(
+            removed.read(events).copied().collect::<Vec<_>>(),
+            [CameraRemoved { entity: camera }]
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{EasingFn, LookTransform};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+};
+
+/// Turns a camera to look at `world_point` over `duration` seconds, without moving its `eye`, e.g. to draw the player's
+/// attention to a door that just opened.
+///
+/// [`camera_look_at_animation_system`] eases the `target` from where it was on the first frame to `world_point`, then
+/// sends a [`CameraLookAtFinished`] event and removes this component. Unlike a [`CameraTransition`](crate::CameraTransition),
+/// only the `target` moves, and unlike a [`LookAtConstraint`](crate::LookAtConstraint), the camera is free again
+/// afterwards.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraLookAtAnimation {
+    pub world_point: Vec3,
+    pub duration: f32,
+    pub elapsed: f32,
+    pub easing: EasingFn,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    from: Option<Vec3>,
+}
+
+impl Default for CameraLookAtAnimation {
+    fn default() -> Self {
+        Self::new(Vec3::ZERO, 1.0, EasingFn::default())
+    }
+}
+
+impl CameraLookAtAnimation {
+    pub fn new(world_point: Vec3, duration: f32, easing: EasingFn) -> Self {
+        Self {
+            world_point,
+            duration,
+            elapsed: 0.0,
+            easing,
+            from: None,
+        }
+    }
+
+    /// The linear progress of the animation, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
+
+/// Sent when a [`CameraLookAtAnimation`] finishes, with the camera looking at its `world_point`.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct CameraLookAtFinished {
+    pub entity: Entity,
+    pub world_point: Vec3,
+}
+
+pub fn camera_look_at_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut finished: EventWriter<CameraLookAtFinished>,
+    mut cameras: Query<(Entity, &mut CameraLookAtAnimation, &mut LookTransform)>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut animation, mut transform) in cameras.iter_mut() {
+        let from = *animation.from.get_or_insert(transform.target);
+        animation.elapsed += dt;
+        let t = animation.easing.ease(animation.progress());
+        transform.target = from.lerp(animation.world_point, t);
+
+        if animation.is_finished() {
+            commands.entity(entity).remove::<CameraLookAtAnimation>();
+            finished.send(CameraLookAtFinished {
+                entity,
+                world_point: animation.world_point,
+            });
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::{app::prelude::*, ecs::event::Events};
+
+    #[test]
+    fn test_turns_to_point_and_finishes() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<CameraLookAtFinished>()
+            .add_systems(Update, camera_look_at_animation_system);
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let point = Vec3::new(4.0, 0.0, 0.0);
+        let camera = app
+            .world
+            .spawn((
+                start,
+                CameraLookAtAnimation::new(point, 1.0, EasingFn::Linear),
+            ))
+            .id();
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.5));
+            app.update();
+            *app.world.get::<LookTransform>(camera).unwrap()
+        };
+
+        let halfway = step(&mut app);
+        assert_eq!(halfway.eye, start.eye);
+        assert!(halfway.target.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-5));
+        assert!(app
+            .world
+            .resource::<Events<CameraLookAtFinished>>()
+            .is_empty());
+
+        let end = step(&mut app);
+        assert_eq!(end.eye, start.eye);
+        assert!(end.target.abs_diff_eq(point, 1e-5));
+        assert!(app.world.get::<CameraLookAtAnimation>(camera).is_none());
+        assert_eq!(
+            app.world.resource::<Events<CameraLookAtFinished>>().len(),
+            1
+        );
+    }
+}
@@ -1,44 +1,515 @@
+use crate::{
+    adaptive_smoother_system, apply_camera_commands, auto_up_vector_system,
+    blend_camera_controllers_system, camera_circle_system, camera_controller_blend_system,
+    camera_lifecycle_system, camera_look_at_animation_system, camera_shake_system,
+    camera_transition_system, clamp_rotation_speed_system, clear_camera_input_lock_system,
+    compute_look_transform_velocity_system,
+    controllers::orbit::{orbit_angles, orbit_direction},
+    head_bob_system, inertia_system, look_at_constraint_system, match_transform_on_start_system,
+    procedural_drift_system, record_previous_look_transform_system, restore_controller_pose_system,
+    scene_camera_transition_system, smooth_roll_system, AdaptiveSmoother,
+    AllCameraControllersDisabled, AutoUpVector, BlendedCameraController, CameraAdded, CameraCircle,
+    CameraCommand, CameraConstraint, CameraControllerBlend, CameraInputLock, CameraLag,
+    CameraLookAtAnimation, CameraLookAtFinished, CameraRemoved, CameraShake, CameraTransition,
+    EasingFn, HeadBob, Inactive, Inertia, LookAtConstraint, LookTransformVelocity,
+    MatchTransformOnStart, MaxRotationSpeed, MotionBlurHint, PitchYawRoll, PreviousLookTransform,
+    ProceduralDrift, SceneCameraTransition, SmoothRoll, TurntablePlane, WorldBounds, WorldGravity,
+};
+
+#[cfg(feature = "bevy_egui")]
+use crate::egui_camera_input_lock_system;
+#[cfg(feature = "render")]
+use bevy::render::{
+    camera::{CameraProjection, PerspectiveProjection},
+    primitives::Aabb,
+};
 use bevy::{
-    app::prelude::*, ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
-    transform::components::Transform,
+    app::prelude::*,
+    ecs::{
+        prelude::*,
+        query::QueryItem,
+        schedule::{InternedSystemSet, ScheduleLabel},
+    },
+    log::debug,
+    math::{prelude::*, Ray},
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::Time,
+    transform::{
+        components::{GlobalTransform, Transform},
+        TransformSystem,
+    },
 };
 
-pub struct LookTransformPlugin;
+/// Registers the crate's components and adds the systems in [`LookTransformSystem::Sync`].
+///
+/// `LookTransformPlugin::default()` adds all of them. With [`LookTransformPluginConfig::auto_sync`] disabled,
+/// [`look_transform_system`] and the systems that adjust its output, like [`head_bob_system`], are left out, so nothing
+/// writes the `Transform`; add them yourself or replace them with your own variants. The systems for [`CameraCommand`]s,
+/// [`CameraTransition`]s, and [`CameraShake`] are always added.
+///
+/// To schedule the camera relative to your own systems, e.g. within a `GameplaySet`, use `LookTransformPlugin::in_set`,
+/// `before`, and `after`. These configure [`LookTransformSystem::Sync`], and like it, your sets must be in `PostUpdate`.
+#[derive(Default)]
+pub struct LookTransformPlugin {
+    pub config: LookTransformPluginConfig,
+    in_sets: Vec<InternedSystemSet>,
+    before: Vec<InternedSystemSet>,
+    after: Vec<InternedSystemSet>,
+}
+
+impl LookTransformPlugin {
+    pub fn new(config: LookTransformPluginConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Puts [`LookTransformSystem::Sync`] in `set`, so it runs whenever `set` does.
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.in_sets.push(set.intern());
+        self
+    }
+
+    /// Runs [`LookTransformSystem::Sync`] before `set`.
+    pub fn before(mut self, set: impl SystemSet) -> Self {
+        self.before.push(set.intern());
+        self
+    }
+
+    /// Runs [`LookTransformSystem::Sync`] after `set`.
+    pub fn after(mut self, set: impl SystemSet) -> Self {
+        self.after.push(set.intern());
+        self
+    }
+
+    /// Syncs with [`lean_look_transform_system`] instead of [`look_transform_system`], for apps where no camera has a
+    /// smoother. See [`LookTransformPluginConfig::lean`].
+    pub fn lean() -> Self {
+        Self::new(LookTransformPluginConfig {
+            lean: true,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LookTransformPluginConfig {
+    /// Whether to add [`look_transform_system`], which writes each smoothed `LookTransform` to the `Transform`.
+    pub auto_sync: bool,
+    /// Whether to add [`lean_look_transform_system`] instead, which copies each `LookTransform` to the `Transform`
+    /// without smoothing, e.g. for a grid of many security cameras. Smoothers, [`CameraLag`], [`CameraConstraint`],
+    /// [`CameraShake`], and [`LookTransformMoved`] events are all ignored, and [`HeadBob`], [`SmoothRoll`], and
+    /// [`ProceduralDrift`] aren't applied.
+    pub lean: bool,
+}
+
+impl Default for LookTransformPluginConfig {
+    fn default() -> Self {
+        Self {
+            auto_sync: true,
+            lean: false,
+        }
+    }
+}
+
+/// The systems that apply [`CameraCommand`]s, [`CameraTransition`]s, and [`CameraShake`], then write each camera's
+/// smoothed `Transform`. These run in `PostUpdate` before Bevy's transform propagation, so changes to a `LookTransform`
+/// made anywhere in `Update` are visible in the same frame. With [`LookTransformFixedPlugin`], they run in `FixedUpdate`
+/// instead.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LookTransformSystem {
+    Sync,
+}
+
+/// Finer-grained stages of a frame, for ordering systems relative to the camera.
+///
+/// - `LookTransformInput` holds the built-in controllers' systems, in `Update`. Order systems that read the camera after
+///   the controllers with `.after(LookTransformSystemSet::LookTransformInput)`.
+/// - `LookTransformUpdate` holds [`look_transform_system`], which writes the smoothed `Transform`, in
+///   [`LookTransformSystem::Sync`]. A system `.before(LookTransformSystemSet::LookTransformUpdate)` there sees the final
+///   `LookTransform` of the frame, after commands, transitions, and the [`LookAtConstraint`].
+/// - `LookTransformPostProcess` holds the systems that adjust the written `Transform`, like [`head_bob_system`], also
+///   in [`LookTransformSystem::Sync`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::enum_variant_names)]
+pub enum LookTransformSystemSet {
+    LookTransformInput,
+    LookTransformUpdate,
+    LookTransformPostProcess,
+}
 
 impl Plugin for LookTransformPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, look_transform_system);
+        add_common_systems(app);
+        app.configure_sets(
+            PostUpdate,
+            LookTransformSystem::Sync.before(TransformSystem::TransformPropagate),
+        );
+        configure_sync_set(app, PostUpdate, &self.in_sets, &self.before, &self.after);
+        add_sync_systems(app, PostUpdate, self.config);
+    }
+}
+
+/// Like [`LookTransformPlugin`], but runs [`LookTransformSystem::Sync`] in `FixedUpdate`, for cameras that follow
+/// physics bodies stepped at the fixed timestep. Add one plugin or the other, not both.
+///
+/// Since Bevy swaps the fixed clock into `Res<Time>` during `FixedUpdate`, smoothing advances by the fixed timestep.
+/// The `Transform` then only changes on frames where a fixed step runs, which looks jittery whenever the frame rate
+/// doesn't match the fixed rate, unless the camera is interpolated between steps when rendering.
+///
+/// `in_set`, `before`, and `after` work like [`LookTransformPlugin`]'s, but your sets must be in `FixedUpdate`.
+#[derive(Default)]
+pub struct LookTransformFixedPlugin {
+    pub config: LookTransformPluginConfig,
+    in_sets: Vec<InternedSystemSet>,
+    before: Vec<InternedSystemSet>,
+    after: Vec<InternedSystemSet>,
+}
+
+impl LookTransformFixedPlugin {
+    pub fn new(config: LookTransformPluginConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Puts [`LookTransformSystem::Sync`] in `set`, so it runs whenever `set` does.
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.in_sets.push(set.intern());
+        self
+    }
+
+    /// Runs [`LookTransformSystem::Sync`] before `set`.
+    pub fn before(mut self, set: impl SystemSet) -> Self {
+        self.before.push(set.intern());
+        self
+    }
+
+    /// Runs [`LookTransformSystem::Sync`] after `set`.
+    pub fn after(mut self, set: impl SystemSet) -> Self {
+        self.after.push(set.intern());
+        self
+    }
+}
+
+impl Plugin for LookTransformFixedPlugin {
+    fn build(&self, app: &mut App) {
+        add_common_systems(app);
+        configure_sync_set(app, FixedUpdate, &self.in_sets, &self.before, &self.after);
+        add_sync_systems(app, FixedUpdate, self.config);
+    }
+}
+
+/// Everything both plugins add outside of [`LookTransformSystem::Sync`]. The plugin being built isn't counted as added
+/// yet, so finding either one means both were added.
+fn add_common_systems(app: &mut App) {
+    if app.is_plugin_added::<LookTransformPlugin>()
+        || app.is_plugin_added::<LookTransformFixedPlugin>()
+    {
+        panic!("LookTransformPlugin and LookTransformFixedPlugin can't both be added");
+    }
+
+    register_types(app);
+    app.add_systems(
+        PreUpdate,
+        (
+            match_transform_on_start_system,
+            record_previous_look_transform_system,
+        )
+            .chain(),
+    )
+    .add_systems(
+        Update,
+        (
+            scene_camera_transition_system,
+            restore_controller_pose_system.before(LookTransformSystemSet::LookTransformInput),
+        ),
+    )
+    .add_systems(
+        Last,
+        (clear_camera_input_lock_system, camera_lifecycle_system),
+    );
+}
+
+fn configure_sync_set(
+    app: &mut App,
+    schedule: impl ScheduleLabel + Clone,
+    in_sets: &[InternedSystemSet],
+    before: &[InternedSystemSet],
+    after: &[InternedSystemSet],
+) {
+    for &set in in_sets {
+        app.configure_sets(schedule.clone(), LookTransformSystem::Sync.in_set(set));
+    }
+    for &set in before {
+        app.configure_sets(schedule.clone(), LookTransformSystem::Sync.before(set));
+    }
+    for &set in after {
+        app.configure_sets(schedule.clone(), LookTransformSystem::Sync.after(set));
+    }
+}
+
+fn register_types(app: &mut App) {
+    app.register_type::<LookTransform>()
+        .register_type::<Option<LookTransform>>()
+        .register_type::<Smoother>()
+        .register_type::<InterpolationMode>()
+        .register_type::<LagWeightCurve>()
+        .register_type::<SpringSmoother>()
+        .register_type::<CameraTransition>()
+        .register_type::<CameraControllerBlend>()
+        .register_type::<CameraLookAtAnimation>()
+        .register_type::<BlendedCameraController>()
+        .register_type::<Inactive>()
+        .register_type::<AutoUpVector>()
+        .register_type::<WorldGravity>()
+        .init_resource::<WorldGravity>()
+        .register_type::<CameraCircle>()
+        .register_type::<TurntablePlane>()
+        .register_type::<SceneCameraTransition>()
+        .register_type::<EasingFn>()
+        .register_type::<CameraShake>()
+        .register_type::<CameraConstraint>()
+        .register_type::<WorldBounds>()
+        .register_type::<LookAtConstraint>()
+        .register_type::<MaxRotationSpeed>()
+        .register_type::<AdaptiveSmoother>()
+        .register_type::<HeadBob>()
+        .register_type::<SmoothRoll>()
+        .register_type::<ProceduralDrift>()
+        .register_type::<Inertia>()
+        .register_type::<CameraLag>()
+        .register_type::<FollowExactly>()
+        .register_type::<LookTransformVelocity>()
+        .register_type::<MatchTransformOnStart>()
+        .register_type::<MotionBlurHint>()
+        .register_type::<PitchYawRoll>()
+        .register_type::<PreviousLookTransform>()
+        .register_type::<LookTransformMovedEpsilon>()
+        .init_resource::<LookTransformMovedEpsilon>()
+        .register_type::<AllCameraControllersDisabled>()
+        .register_type::<CameraInputLock>()
+        .init_resource::<CameraInputLock>()
+        .add_event::<CameraCommand>()
+        .add_event::<CameraAdded>()
+        .add_event::<CameraRemoved>()
+        .add_event::<CameraLookAtFinished>()
+        .add_event::<LookTransformMoved>();
+
+    #[cfg(feature = "bevy_egui")]
+    app.add_systems(
+        Update,
+        egui_camera_input_lock_system.before(LookTransformSystemSet::LookTransformInput),
+    );
+}
+
+fn add_sync_systems(
+    app: &mut App,
+    schedule: impl ScheduleLabel + Clone,
+    config: LookTransformPluginConfig,
+) {
+    app.configure_sets(
+        schedule.clone(),
+        (
+            LookTransformSystemSet::LookTransformUpdate,
+            LookTransformSystemSet::LookTransformPostProcess,
+        )
+            .chain()
+            .after(adaptive_smoother_system)
+            .in_set(LookTransformSystem::Sync),
+    )
+    .add_systems(
+        schedule.clone(),
+        (
+            camera_circle_system,
+            apply_camera_commands,
+            camera_transition_system,
+            camera_controller_blend_system,
+            camera_look_at_animation_system,
+            blend_camera_controllers_system,
+            auto_up_vector_system,
+            inertia_system,
+            look_at_constraint_system,
+            clamp_rotation_speed_system,
+            camera_shake_system,
+            adaptive_smoother_system,
+        )
+            .chain()
+            .in_set(LookTransformSystem::Sync),
+    );
+
+    app.add_systems(
+        schedule.clone(),
+        compute_look_transform_velocity_system
+            .after(look_transform_system)
+            .in_set(LookTransformSystemSet::LookTransformUpdate),
+    );
+
+    if config.auto_sync && config.lean {
+        // The lean system only writes changed cameras, so anything added on top would accumulate.
+        app.add_systems(
+            schedule,
+            lean_look_transform_system.in_set(LookTransformSystemSet::LookTransformUpdate),
+        );
+    } else if config.auto_sync {
+        app.add_systems(
+            schedule,
+            (
+                look_transform_system.in_set(LookTransformSystemSet::LookTransformUpdate),
+                (head_bob_system, smooth_roll_system, procedural_drift_system)
+                    .chain()
+                    .in_set(LookTransformSystemSet::LookTransformPostProcess),
+            ),
+        );
+    }
+}
+
+/// A `LookTransform` with exactly one smoother. Defaults to the exponential `Smoother`; use
+/// `LookTransformBundle<SpringSmoother>` for spring physics instead.
+///
+/// The default bundle is built from the defaults of its components.
+#[derive(Bundle, Clone, Default)]
+pub struct LookTransformBundle<S: CameraSmoother = Smoother> {
+    pub transform: LookTransform,
+    pub smoother: S,
+}
+
+impl<S: CameraSmoother> LookTransformBundle<S> {
+    /// Replaces the `up` vector of the bundled `LookTransform`, e.g. for worlds with a Z-up convention.
+    pub fn with_up(mut self, up: Vec3) -> Self {
+        self.transform.up = up;
+        self
+    }
+
+    /// Spawns a [`CameraConstraint`] along with the bundle.
+    pub fn with_constraint(self, constraint: CameraConstraint) -> (Self, CameraConstraint) {
+        (self, constraint)
+    }
+}
+
+impl LookTransformBundle {
+    /// A camera at `eye` looking at `target`, with `Vec3::Y` up and a `Smoother` with `lag_weight`.
+    pub fn looking_at(eye: Vec3, target: Vec3, lag_weight: f32) -> Self {
+        Self::looking_at_with_up(eye, target, Vec3::Y, lag_weight)
+    }
+
+    /// Like `LookTransformBundle::looking_at`, with another `up` vector, e.g. `Vec3::Z`.
+    pub fn looking_at_with_up(eye: Vec3, target: Vec3, up: Vec3, lag_weight: f32) -> Self {
+        Self {
+            transform: LookTransform::new(eye, target, up),
+            smoother: Smoother::new(lag_weight),
+        }
     }
 }
 
-#[derive(Bundle, Clone)]
-pub struct LookTransformBundle {
+/// A `LookTransform` without a smoother, for cameras that should follow it exactly, e.g. a cinematic rail camera driven
+/// by a `CameraPathFollower`. [`look_transform_system`] writes it straight to the `Transform`, but sends no
+/// [`LookTransformMoved`] events for it.
+///
+/// Use a [`LookTransformBundle`] instead to smooth the camera's motion.
+#[derive(Bundle, Clone, Default)]
+pub struct MinimalLookTransformBundle {
     pub transform: LookTransform,
-    pub smoother: Smoother,
+    pub follow: FollowExactly,
 }
 
+impl MinimalLookTransformBundle {
+    pub fn new(transform: LookTransform) -> Self {
+        Self {
+            transform,
+            follow: FollowExactly,
+        }
+    }
+}
+
+/// Makes [`look_transform_system`] write the `LookTransform` straight to the `Transform` of a camera without a smoother.
+/// Cameras with neither this nor a smoother are left alone, so their `Transform` can be written by something else.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct FollowExactly;
+
+/// Marker trait for components that smooth a `LookTransform` into the scene graph `Transform`. A camera should have at most
+/// one of these.
+pub trait CameraSmoother: Component + Clone {}
+
+impl CameraSmoother for Smoother {}
+
+impl CameraSmoother for SpringSmoother {}
+
 /// An eye and the target it's looking at. As a component, this can be modified in place of bevy's `Transform`, and the two will
 /// stay in sync.
-#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+///
+/// The default, a reasonable starting point, has the `eye` at `(0, 0, 5)` looking at the origin with `Vec3::Y` up.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[reflect(Component, Default, Debug, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq, Hash)]
 pub struct LookTransform {
     pub eye: Vec3,
     pub target: Vec3,
     pub up: Vec3,
 }
 
+/// Two `LookTransform`s are equal only if all of their components are bit-identical, so `0.0` and `-0.0` differ and `NaN`
+/// equals itself. This keeps equality consistent with `Hash`, so a `LookTransform` can be a `HashMap` key. Use
+/// `LookTransform::abs_diff_eq` to compare computed transforms.
+impl PartialEq for LookTransform {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl Eq for LookTransform {}
+
+impl std::hash::Hash for LookTransform {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
 impl From<LookTransform> for Transform {
     fn from(t: LookTransform) -> Self {
         eye_look_at_target_transform(t.eye, t.target, t.up)
     }
 }
 
+/// Uses a radius of `1.0`. See `LookTransform::from_transform` to choose the radius. Since this is infallible,
+/// `LookTransform::try_from(transform)` also works.
+impl From<Transform> for LookTransform {
+    fn from(t: Transform) -> Self {
+        Self::from_transform(&t, 1.0)
+    }
+}
+
+/// An `(eye, target)` pair, with `Vec3::Y` up.
+impl From<(Vec3, Vec3)> for LookTransform {
+    fn from((eye, target): (Vec3, Vec3)) -> Self {
+        Self::new(eye, target, Vec3::Y)
+    }
+}
+
+/// An `(eye, target, up)` triple.
+impl From<(Vec3, Vec3, Vec3)> for LookTransform {
+    fn from((eye, target, up): (Vec3, Vec3, Vec3)) -> Self {
+        Self::new(eye, target, up)
+    }
+}
+
+/// Destructures into `(eye, target, up)`, e.g. `let (eye, target, up) = look.into();`.
+impl From<LookTransform> for (Vec3, Vec3, Vec3) {
+    fn from(t: LookTransform) -> Self {
+        (t.eye, t.target, t.up)
+    }
+}
+
 impl Default for LookTransform {
     fn default() -> Self {
         Self {
-            eye: Vec3::default(),
-            target: Vec3::default(),
+            eye: Vec3::new(0.0, 0.0, 5.0),
+            target: Vec3::ZERO,
             up: Vec3::Y,
         }
     }
@@ -49,97 +520,2338 @@ impl LookTransform {
         Self { eye, target, up }
     }
 
+    /// Reconstructs a `LookTransform` whose `eye` is at the transform's translation and whose `target` is `radius` units
+    /// along its forward vector.
+    pub fn from_transform(t: &Transform, radius: f32) -> Self {
+        Self {
+            eye: t.translation,
+            target: t.translation + radius * t.forward(),
+            up: t.up(),
+        }
+    }
+
+    /// A `LookTransform` at `eye` facing the direction of the angles, with the `target` one unit away. See
+    /// [`PitchYawRoll`] for the conventions.
+    pub fn from_pitch_yaw_roll(eye: Vec3, pitch: f32, yaw: f32, roll: f32) -> Self {
+        let angles = PitchYawRoll::new(pitch, yaw, roll);
+
+        Self::new(eye, eye + angles.look_direction(), angles.up())
+    }
+
     pub fn radius(&self) -> f32 {
         (self.target - self.eye).length()
     }
 
+    /// Whether each coordinate of the `eye`, `target`, and `up` is within `max_abs_diff` of `other`'s.
+    pub fn abs_diff_eq(&self, other: &LookTransform, max_abs_diff: f32) -> bool {
+        self.eye.abs_diff_eq(other.eye, max_abs_diff)
+            && self.target.abs_diff_eq(other.target, max_abs_diff)
+            && self.up.abs_diff_eq(other.up, max_abs_diff)
+    }
+
+    /// How far apart two camera states are: the distance between the `eye`s plus the distance between the `target`s.
+    /// The `up` vectors are ignored.
+    pub fn distance_to(&self, other: &LookTransform) -> f32 {
+        self.eye.distance(other.eye) + self.target.distance(other.target)
+    }
+
+    /// Whether `distance_to(other)` is at most `tolerance`.
+    pub fn is_near(&self, other: &LookTransform, tolerance: f32) -> bool {
+        self.distance_to(other) <= tolerance
+    }
+
+    /// The angle between the two look directions, in radians, ignoring the positions and radii, e.g. to check whether two
+    /// cameras are essentially aligned. Zooming in or out doesn't change it.
+    ///
+    /// Returns `0.0` if either transform has `eye == target`.
+    pub fn angular_distance_to(&self, other: &LookTransform) -> f32 {
+        match (self.look_direction(), other.look_direction()) {
+            (Some(a), Some(b)) => a.angle_between(b),
+            _ => 0.0,
+        }
+    }
+
+    /// The distance between the `eye`s, the [`LookTransform::angular_distance_to`], and the difference in `radius`, for
+    /// callers that care about each kind of change.
+    pub fn full_distance_to(&self, other: &LookTransform) -> (f32, f32, f32) {
+        (
+            self.eye.distance(other.eye),
+            self.angular_distance_to(other),
+            (other.radius() - self.radius()).abs(),
+        )
+    }
+
+    /// Whether `point` is within `threshold_degrees` of the look direction, e.g. for "look at the door to open it"
+    /// triggers.
+    ///
+    /// Returns `false` if `eye == target` or `eye == point`, since there's no direction to compare.
+    pub fn is_looking_at(&self, point: Vec3, threshold_degrees: f32) -> bool {
+        match (self.look_direction(), (point - self.eye).try_normalize()) {
+            (Some(look), Some(to_point)) => {
+                look.angle_between(to_point) < threshold_degrees.to_radians()
+            }
+            _ => false,
+        }
+    }
+
+    fn to_bits(self) -> [u32; 9] {
+        let [a, b, c] = [self.eye, self.target, self.up].map(|v| v.to_array().map(f32::to_bits));
+        [a[0], a[1], a[2], b[0], b[1], b[2], c[0], c[1], c[2]]
+    }
+
     pub fn look_direction(&self) -> Option<Vec3> {
         (self.target - self.eye).try_normalize()
     }
-}
 
-fn eye_look_at_target_transform(eye: Vec3, target: Vec3, up: Vec3) -> Transform {
-    // If eye and target are very close, we avoid imprecision issues by keeping the look vector a unit vector.
-    let look_vector = (target - eye).normalize();
-    let look_at = eye + look_vector;
+    /// The ray from the `eye` along the look direction, e.g. for picking whatever is in the center of the screen.
+    ///
+    /// Returns `None` if `eye == target`.
+    pub fn as_ray(&self) -> Option<Ray> {
+        Some(Ray {
+            origin: self.eye,
+            direction: self.look_direction()?,
+        })
+    }
 
-    Transform::from_translation(eye).looking_at(look_at, up)
-}
+    /// Moves the `eye` toward or away from the `target` so the `radius` is within `[min, max]`, keeping the look
+    /// direction, `target`, and `up` unchanged.
+    ///
+    /// Returns `self` unchanged if `eye == target`, since there is no look direction to move along.
+    pub fn clamp_radius(self, min: f32, max: f32) -> Self {
+        self.with_radius(self.radius().clamp(min, max))
+    }
 
-/// Preforms exponential smoothing on a `LookTransform`. Set the `lag_weight` between `0.0` and `1.0`, where higher is smoother.
-#[derive(Clone, Component, Copy, Debug, Reflect)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[reflect(Component, Default, Debug)]
-pub struct Smoother {
-    lag_weight: f32,
-    lerp_tfm: Option<LookTransform>,
-    enabled: bool,
-}
+    /// Replaces the `eye`, e.g. `look.with_eye(spawn_point)`. Unlike `with_radius`, this may change the look direction.
+    pub fn with_eye(mut self, eye: Vec3) -> Self {
+        self.eye = eye;
+        self
+    }
+
+    /// Replaces the `target`, e.g. `look.with_target(player_position)`. The `eye` stays where it is.
+    pub fn with_target(mut self, target: Vec3) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn with_up(mut self, up: Vec3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Moves the `eye` along the look direction so it is exactly `radius` units from the `target`.
+    ///
+    /// Returns `self` unchanged if `eye == target`, since there is no look direction to move along.
+    pub fn with_radius(self, radius: f32) -> Self {
+        let Some(dir) = self.look_direction() else {
+            debug!(
+                "Cannot set the radius of a LookTransform whose eye and target are both at {}",
+                self.eye
+            );
+            return self;
+        };
 
-impl Default for Smoother {
-    fn default() -> Self {
         Self {
-            lag_weight: 0.9,
-            lerp_tfm: Some(LookTransform::default()),
-            enabled: true,
+            eye: self.target - radius * dir,
+            ..self
         }
     }
-}
 
-impl Smoother {
-    pub fn new(lag_weight: f32) -> Self {
+    /// Turns to look at `point` from the same `eye`.
+    ///
+    /// Returns `self` unchanged if `point == eye`, since there would be no look direction.
+    pub fn face_towards(&self, point: Vec3) -> Self {
+        if point == self.eye {
+            debug!(
+                "Cannot face a LookTransform toward its own eye at {}",
+                self.eye
+            );
+            return *self;
+        }
+
         Self {
-            lag_weight,
-            lerp_tfm: None,
-            enabled: true,
+            target: point,
+            ..*self
         }
     }
 
-    pub(crate) fn set_enabled(&mut self, enabled: bool) {
-        self.enabled = enabled;
-        if self.enabled {
-            // To prevent camera jumping from last lerp before disabling to the current position,
-            // reset smoother state
-            self.reset();
+    /// Turns toward `world_point` from the same `eye`, by at most `max_angular_speed_deg_per_sec * dt` degrees, keeping
+    /// the radius. Call it every frame to track a moving point at a limited speed.
+    ///
+    /// This is a low-level building block for custom controllers: it doesn't involve a `Smoother`, which would smooth the
+    /// result further. Leaves `self` alone if `eye == target` or `eye == world_point`.
+    pub fn smooth_look_at(
+        &mut self,
+        world_point: Vec3,
+        max_angular_speed_deg_per_sec: f32,
+        dt: f32,
+    ) -> &mut Self {
+        let (Some(from), Some(to)) = (
+            self.look_direction(),
+            (world_point - self.eye).try_normalize(),
+        ) else {
+            return self;
+        };
+
+        let rotation = Quat::from_rotation_arc(from, to);
+        let angle = Quat::IDENTITY.angle_between(rotation);
+        let max_angle = max_angular_speed_deg_per_sec.max(0.0).to_radians() * dt;
+        let rotation = if angle > max_angle {
+            Quat::IDENTITY.slerp(rotation, max_angle / angle)
+        } else {
+            rotation
+        };
+        self.target = self.eye + self.radius() * (rotation * from);
+
+        self
+    }
+
+    /// Like `face_towards`, for the translation of `target_entity`'s `GlobalTransform`, e.g. to point a security camera
+    /// at an intruder from an AI system. `None` if the entity isn't in `transforms`.
+    pub fn face_entity(
+        &self,
+        target_entity: Entity,
+        transforms: &Query<&GlobalTransform>,
+    ) -> Option<Self> {
+        let target = transforms.get(target_entity).ok()?;
+
+        Some(self.face_towards(target.translation()))
+    }
+
+    /// Turns to look along `dir` from the same `eye`, keeping the `radius`, or `1.0` if the `radius` is zero.
+    ///
+    /// Returns `self` unchanged if `dir` is zero.
+    pub fn face_direction(&self, dir: Vec3) -> Self {
+        let Some(dir) = dir.try_normalize() else {
+            debug!("Cannot face a LookTransform in the zero direction");
+            return *self;
+        };
+        let radius = match self.radius() {
+            r if r > 0.0 => r,
+            _ => 1.0,
+        };
+
+        Self {
+            target: self.eye + radius * dir,
+            ..*self
         }
     }
 
-    pub fn set_lag_weight(&mut self, lag_weight: f32) {
-        self.lag_weight = lag_weight;
+    /// Swings the `eye` around the `target` by `angle_radians` about `up`, e.g. for Q/E keys. Positive angles turn
+    /// counter-clockwise, increasing the `yaw`.
+    ///
+    /// Returns `self` unchanged if `up` is zero.
+    pub fn rotate_around_up(&self, angle_radians: f32) -> Self {
+        let Some(axis) = self.up.try_normalize() else {
+            debug!("Cannot rotate a LookTransform around a zero up vector");
+            return *self;
+        };
+
+        self.rotate_around_axis(axis, angle_radians)
     }
 
-    pub fn smooth_transform(&mut self, new_tfm: &LookTransform) -> LookTransform {
-        debug_assert!(0.0 <= self.lag_weight);
-        debug_assert!(self.lag_weight < 1.0);
+    /// Swings the `eye` around the `target` by `angle_radians` about the camera's `right` vector. Positive angles
+    /// increase the `pitch`. Rotating past straight up or down flips the view, so clamp the angle if that matters.
+    ///
+    /// Returns `self` unchanged if the look direction is undefined or parallel to `up`.
+    pub fn rotate_around_right(&self, angle_radians: f32) -> Self {
+        let Some(axis) = self.right() else {
+            debug!("Cannot rotate a LookTransform without a right vector");
+            return *self;
+        };
 
-        let old_lerp_tfm = self.lerp_tfm.unwrap_or(*new_tfm);
+        self.rotate_around_axis(axis, angle_radians)
+    }
 
-        let lead_weight = 1.0 - self.lag_weight;
-        let lerp_tfm = LookTransform {
-            eye: old_lerp_tfm.eye * self.lag_weight + new_tfm.eye * lead_weight,
-            target: old_lerp_tfm.target * self.lag_weight + new_tfm.target * lead_weight,
-            up: new_tfm.up,
+    /// Orbits the `eye` around the `target` by changing its elevation and azimuth, in radians, with the same conventions
+    /// as [`orbit_angles`]. The radius is kept, and the elevation is clamped just short of the poles so the view never
+    /// flips.
+    pub fn orbit_by_delta(&self, delta_pitch: f32, delta_yaw: f32) -> Self {
+        let (pitch, yaw) = orbit_angles(self.eye, self.target, self.up);
+        let pitch = (pitch + delta_pitch).clamp(-MAX_ORBIT_PITCH, MAX_ORBIT_PITCH);
+
+        Self {
+            eye: self.target + self.radius() * orbit_direction(self.up, pitch, yaw + delta_yaw),
+            ..*self
+        }
+    }
+
+    /// Moves the `eye` toward `new_eye` on the sphere around the `target`, keeping the radius. Returns `self` if
+    /// `new_eye == target`.
+    pub fn orbit_to(&self, new_eye: Vec3) -> Self {
+        self.orbit_to_direction(new_eye - self.target)
+    }
+
+    /// Puts the `eye` in the direction `dir` from the `target`, keeping the radius. `dir` is normalized, and `self` is
+    /// returned if it's zero.
+    pub fn orbit_to_direction(&self, dir: Vec3) -> Self {
+        let Some(dir) = dir.try_normalize() else {
+            debug!("Cannot orbit a LookTransform to a zero direction");
+            return *self;
         };
 
-        self.lerp_tfm = Some(lerp_tfm);
+        Self {
+            eye: self.target + self.radius() * dir,
+            ..*self
+        }
+    }
 
-        lerp_tfm
+    fn rotate_around_axis(&self, axis: Vec3, angle_radians: f32) -> Self {
+        let offset = Quat::from_axis_angle(axis, angle_radians) * (self.eye - self.target);
+
+        Self {
+            eye: self.target + offset,
+            ..*self
+        }
     }
 
-    pub fn reset(&mut self) {
-        self.lerp_tfm = None;
+    /// Looks at a sphere from the current direction, backing off until it fits in a view of `fov` radians. Use the
+    /// narrower of the vertical and horizontal fields of view to fit both.
+    ///
+    /// The `up` is kept, and if `eye == target`, the camera looks down `Vec3::NEG_Z`.
+    pub fn frame_sphere(&self, center: Vec3, radius: f32, fov: f32) -> Self {
+        let dir = self.look_direction().unwrap_or(Vec3::NEG_Z);
+        let distance = radius / (0.5 * fov).sin().max(f32::EPSILON);
+
+        Self {
+            eye: center - distance * dir,
+            target: center,
+            up: self.up,
+        }
     }
-}
 
-pub fn look_transform_system(
-    mut cameras: Query<(&LookTransform, &mut Transform, Option<&mut Smoother>)>,
-) {
-    for (look_transform, mut scene_transform, smoother) in cameras.iter_mut() {
-        match smoother {
-            Some(mut s) if s.enabled => {
-                *scene_transform = s.smooth_transform(look_transform).into()
-            }
-            _ => (),
+    /// Looks at the center of a box from the current direction, backing off until every corner fits in a view with a
+    /// vertical field of view of `fov` radians and the given `aspect_ratio` (width over height). With a `padding` of
+    /// `0.1`, the box is fit with 10% to spare.
+    ///
+    /// The `up` is kept, and if `eye == target`, the camera looks down `Vec3::NEG_Z`.
+    pub fn frame_box(
+        &self,
+        center: Vec3,
+        half_extents: Vec3,
+        fov: f32,
+        aspect_ratio: f32,
+        padding: f32,
+    ) -> Self {
+        let dir = self.look_direction().unwrap_or(Vec3::NEG_Z);
+        let right = dir.cross(self.up).try_normalize().unwrap_or(Vec3::X);
+        let up = right.cross(dir);
+
+        let tan_y = (0.5 * fov).tan().max(f32::EPSILON);
+        let tan_x = aspect_ratio * tan_y;
+        let half_extents = (1.0 + padding) * half_extents;
+
+        let mut distance: f32 = 0.0;
+        for i in 0..8 {
+            let sign = |bit| if i & bit == 0 { -1.0 } else { 1.0 };
+            let corner = half_extents * Vec3::new(sign(1), sign(2), sign(4));
+            // The corner is `depth` closer to the eye than the center is, so it needs that much more room.
+            let depth = -corner.dot(dir);
+            let needed_x = corner.dot(right).abs() / tan_x + depth;
+            let needed_y = corner.dot(up).abs() / tan_y + depth;
+            distance = distance.max(needed_x).max(needed_y);
+        }
+
+        Self {
+            eye: center - distance * dir,
+            target: center,
+            up: self.up,
+        }
+    }
+
+    /// The signed distance from the `eye` to the plane through `point` with the given `normal`. Positive on the side the
+    /// normal points toward. The `normal` doesn't need to be normalized.
+    pub fn distance_to_plane(&self, normal: Vec3, point: Vec3) -> f32 {
+        (self.eye - point).dot(normal.normalize())
+    }
+
+    /// The closest point to the `eye` on the plane through `point` with the given `normal`.
+    pub fn project_eye_onto_plane(&self, normal: Vec3, point: Vec3) -> Vec3 {
+        self.eye - self.distance_to_plane(normal, point) * normal.normalize()
+    }
+
+    /// Flattens the look direction onto the plane with the given `normal`, keeping the `eye` and the `radius`, e.g. to
+    /// level a top-down or isometric camera. The `normal` doesn't need to be normalized.
+    ///
+    /// Returns `self` unchanged if the camera looks along the `normal`, or if `eye == target`.
+    pub fn project_look_onto_plane(&self, normal: Vec3) -> Self {
+        let Some(dir) = self
+            .look_direction()
+            .and_then(|dir| dir.reject_from(normal).try_normalize())
+        else {
+            debug!(
+                "Can't project the look direction of {self:?} onto the plane with normal {normal}"
+            );
+            return *self;
+        };
+
+        Self {
+            target: self.eye + self.radius() * dir,
+            ..*self
+        }
+    }
+
+    /// The elevation of the look direction above the plane perpendicular to `up`, in radians. Positive when looking up.
+    ///
+    /// Returns `0.0` if `eye == target`.
+    pub fn pitch(&self) -> f32 {
+        let Some(dir) = self.look_direction() else {
+            return 0.0;
+        };
+
+        dir.dot(self.up.normalize()).clamp(-1.0, 1.0).asin()
+    }
+
+    /// The azimuth of the look direction around `up`, in radians, in `(-PI, PI]`.
+    ///
+    /// Zero yaw means looking along `Vec3::NEG_Z` (Bevy's default forward) when `up` is `Vec3::Y`, and positive yaw turns
+    /// counter-clockwise around `up`, so a yaw of `PI / 2` looks along `Vec3::NEG_X`. For other `up` vectors, the reference
+    /// directions are rotated by the shortest rotation from `Vec3::Y` to `up`.
+    ///
+    /// Returns `0.0` if `eye == target`.
+    pub fn yaw(&self) -> f32 {
+        let Some(dir) = self.look_direction() else {
+            return 0.0;
+        };
+
+        let rotation = Quat::from_rotation_arc(Vec3::Y, self.up.normalize());
+        let forward = rotation * Vec3::NEG_Z;
+        let left = rotation * Vec3::NEG_X;
+
+        dir.dot(left).atan2(dir.dot(forward))
+    }
+
+    /// The camera's right vector, perpendicular to both the look direction and `up`. Returns `None` if the look direction
+    /// is undefined or parallel to `up`.
+    pub fn right(&self) -> Option<Vec3> {
+        self.look_direction()?.cross(self.up).try_normalize()
+    }
+
+    /// The camera's own up vector, perpendicular to the look direction. Unlike `up`, this tilts with the camera's pitch.
+    pub fn local_up(&self) -> Option<Vec3> {
+        Some(self.right()?.cross(self.look_direction()?))
+    }
+
+    /// Moves the `eye` and `target` by `world_delta`, keeping the look direction and radius.
+    pub fn translate(&self, world_delta: Vec3) -> Self {
+        Self {
+            eye: self.eye + world_delta,
+            target: self.target + world_delta,
+            up: self.up,
+        }
+    }
+
+    /// Like `translate`, but in the camera's own axes: `X` is `right`, `Y` is `local_up`, and `Z` is the look direction,
+    /// so a positive `Z` moves forward, unlike Bevy's `Transform` where forward is `-Z`.
+    ///
+    /// Returns `self` if `eye == target` or the camera looks along `up`, since its axes are undefined.
+    pub fn translate_local(&self, local_delta: Vec3) -> Self {
+        let (Some(look), Some(right), Some(up)) =
+            (self.look_direction(), self.right(), self.local_up())
+        else {
+            return *self;
         };
+
+        self.translate(local_delta.x * right + local_delta.y * up + local_delta.z * look)
+    }
+
+    /// A `Transform` at `object_position` that faces the `eye`. Same as `billboard_full`.
+    pub fn billboard_transform(&self, object_position: Vec3) -> Transform {
+        self.billboard_full(object_position)
+    }
+
+    /// A `Transform` at `object_position` whose `+Z` axis (the front of a `Quad` mesh) points straight at the `eye`,
+    /// with its `+Y` axis as close to `up` as possible.
+    pub fn billboard_full(&self, object_position: Vec3) -> Transform {
+        if object_position == self.eye {
+            return Transform::from_translation(object_position);
+        }
+
+        // The object's forward (`-Z`) points away from the eye.
+        eye_look_at_target_transform(object_position, 2.0 * object_position - self.eye, self.up)
+    }
+
+    /// Like `billboard_full`, but only rotates around the world `Y` axis, so the object stays upright, e.g. for trees
+    /// and characters drawn as sprites.
+    pub fn billboard_y_axis(&self, object_position: Vec3) -> Transform {
+        let to_eye = self.eye - object_position;
+        let rotation = if to_eye.x == 0.0 && to_eye.z == 0.0 {
+            Quat::IDENTITY
+        } else {
+            Quat::from_rotation_y(to_eye.x.atan2(to_eye.z))
+        };
+
+        Transform::from_translation(object_position).with_rotation(rotation)
+    }
+
+    /// Linearly interpolates the `eye`, `target`, and `up` between `self` (at `t = 0.0`) and `other` (at `t = 1.0`).
+    pub fn lerp(self, other: LookTransform, t: f32) -> LookTransform {
+        LookTransform {
+            eye: self.eye.lerp(other.eye, t),
+            target: self.target.lerp(other.target, t),
+            up: self.up.lerp(other.up, t).try_normalize().unwrap_or(self.up),
+        }
+    }
+
+    /// The view matrix, which maps world space to the camera's view space, e.g. for a compute shader or a custom render
+    /// pass. This is the inverse of the matrix of `Transform::from(self)`.
+    pub fn to_view_matrix(&self) -> Mat4 {
+        Transform::from(*self).compute_matrix().inverse()
+    }
+
+    /// Interpolates between `self` (at `t = 0.0`) and `other` (at `t = 1.0`) by spherically interpolating the look
+    /// orientation, so the camera turns at a constant angular speed. The `eye` moves linearly and the `radius` is
+    /// interpolated, so the camera doesn't jump to a different distance.
+    ///
+    /// Returns `self` if either transform has `eye == target`.
+    pub fn slerp(self, other: LookTransform, t: f32) -> LookTransform {
+        if self.look_direction().is_none() || other.look_direction().is_none() {
+            return self;
+        }
+
+        let rotation = Transform::from(self)
+            .rotation
+            .slerp(Transform::from(other).rotation, t);
+        let radius = self.radius() + (other.radius() - self.radius()) * t;
+        let eye = self.eye.lerp(other.eye, t);
+
+        LookTransform {
+            eye,
+            target: eye + radius * (rotation * Vec3::NEG_Z),
+            up: self.up.lerp(other.up, t).try_normalize().unwrap_or(self.up),
+        }
+    }
+
+    /// Interpolates between `self` (at `t = 0.0`) and `other` (at `t = 1.0`) for orbit cameras, swinging the `eye` along
+    /// a great circle around the `target` instead of cutting through the sphere like `lerp`. The `target` and `radius`
+    /// are interpolated linearly, so the `radius` stays constant when both are the same.
+    ///
+    /// When the two look from opposite sides, the `eye` swings around `self.up`.
+    pub fn interpolate_arc(&self, other: &LookTransform, t: f32) -> Self {
+        let target = self.target.lerp(other.target, t);
+        let radius = self.radius() + (other.radius() - self.radius()) * t;
+        let direction = slerp_up(self.eye - self.target, other.eye - other.target, t, self.up);
+
+        LookTransform {
+            eye: target + radius * direction,
+            target,
+            up: self.up.lerp(other.up, t).try_normalize().unwrap_or(self.up),
+        }
+    }
+}
+
+#[cfg(feature = "render")]
+impl LookTransform {
+    /// The world-space ray through a point on the screen, given in normalized device coordinates, where `(-1, -1)` is the
+    /// bottom left corner and `(1, 1)` is the top right.
+    ///
+    /// The ray's origin is the `eye`, and its direction is computed from the `projection`'s field of view and aspect ratio,
+    /// so `Vec2::ZERO` gives the look direction.
+    pub fn screen_to_world_ray(&self, ndc: Vec2, projection: &PerspectiveProjection) -> Ray {
+        let half_height = (0.5 * projection.fov).tan();
+        let half_width = projection.aspect_ratio * half_height;
+        let view_direction = Vec3::new(ndc.x * half_width, ndc.y * half_height, -1.0);
+
+        Ray {
+            origin: self.eye,
+            direction: (Transform::from(*self).rotation * view_direction).normalize(),
+        }
+    }
+
+    /// The `projection` matrix times the [`LookTransform::to_view_matrix`], which maps world space to clip space.
+    pub fn to_view_projection_matrix(&self, projection: &PerspectiveProjection) -> Mat4 {
+        projection.get_projection_matrix() * self.to_view_matrix()
+    }
+
+    /// The pixel position of `world_point` in a viewport of `viewport` pixels, with `(0, 0)` at the top left like
+    /// Bevy's `Camera::world_to_viewport`, e.g. to place a health bar over an enemy. `None` if the point isn't in front of
+    /// the camera.
+    ///
+    /// Points outside the field of view give positions outside the viewport.
+    pub fn project_point_to_screen(
+        &self,
+        world_point: Vec3,
+        viewport: UVec2,
+        projection: &PerspectiveProjection,
+    ) -> Option<Vec2> {
+        let clip = self.to_view_projection_matrix(projection) * world_point.extend(1.0);
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = clip.truncate().truncate() / clip.w;
+        Some(Vec2::new(0.5 * (ndc.x + 1.0), 0.5 * (1.0 - ndc.y)) * viewport.as_vec2())
+    }
+
+    /// Like `LookTransform::frame_box`, for an `Aabb`, e.g. a mesh's bounds transformed into world space.
+    pub fn frame_aabb(&self, aabb: &Aabb, fov: f32, aspect_ratio: f32, padding: f32) -> Self {
+        self.frame_box(
+            aabb.center.into(),
+            aabb.half_extents.into(),
+            fov,
+            aspect_ratio,
+            padding,
+        )
+    }
+}
+
+/// Things can get weird if we are parallel to the UP vector.
+const MAX_ORBIT_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+fn eye_look_at_target_transform(eye: Vec3, target: Vec3, up: Vec3) -> Transform {
+    // If eye and target are very close, we avoid imprecision issues by keeping the look vector a unit vector.
+    let look_vector = (target - eye).normalize();
+    let look_at = eye + look_vector;
+
+    if look_vector.cross(up).length_squared() < 1e-8 {
+        debug!("LookTransform up vector {up} is parallel to the look direction {look_vector}");
+    }
+
+    Transform::from_translation(eye).looking_at(look_at, up)
+}
+
+/// The frame rate at which a `Smoother` lag weight is applied exactly once per frame. At any other frame rate, the weight is
+/// adjusted so that the camera decays toward its goal at the same speed.
+pub(crate) const REFERENCE_FPS: f32 = 60.0;
+
+/// Preforms exponential smoothing on a `LookTransform`. Set the `lag_weight` between `0.0` and `1.0`, where higher is smoother.
+///
+/// The `lag_weight` is the fraction of the old transform that is kept after one frame at 60 FPS. Smoothing is frame-rate
+/// independent, so the camera will feel the same at any frame rate.
+///
+/// The `eye` and `target` can be given different weights with `Smoother::new_independent`. For example, a first-person
+/// camera usually feels better when the target isn't smoothed as heavily as the eye.
+///
+/// A `lag_weight` of `0.0` snaps straight to the `LookTransform` with no smoothing. Weights approaching `1.0` take longer
+/// and longer to catch up, until the camera appears frozen. Weights outside of `[0.0, 1.0)` are saturated when smoothing,
+/// since `1.0` would never move and anything larger would diverge; use `Smoother::try_new` to reject them up front.
+///
+/// By default, the `eye` moves in a straight line toward its goal. For orbiting cameras, use
+/// `Smoother::with_interpolation(InterpolationMode::SphericalArc)` so it swings around the target instead.
+///
+/// The `up` vector snaps to its goal unless it's given a lag weight with `Smoother::with_up_lag_weight`, e.g. for a
+/// spaceship camera that rolls. It then rotates toward its goal along the shortest arc.
+///
+/// A new or reset `Smoother` has nothing to smooth from, so its first frame snaps to the `LookTransform`, and it smooths
+/// from there. This also holds when it's added to a camera that's already moving.
+///
+/// With a [`LagWeightCurve`], the lag weights ease in as the camera starts moving, or ease out once input stops.
+///
+/// The default, a reasonable starting point, has a `lag_weight` of `0.5`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct Smoother {
+    eye_lag_weight: f32,
+    target_lag_weight: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    up_lag_weight: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    interpolation: InterpolationMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    lag_curve: LagWeightCurve,
+    // Transient state that is rebuilt on the first frame after loading.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    lerp_tfm: Option<LookTransform>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_goal: Option<LookTransform>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    elapsed_since_input: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    elapsed_in_motion: f32,
+    enabled: bool,
+}
+
+impl Default for Smoother {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Smoother {
+    pub fn new(lag_weight: f32) -> Self {
+        Self::new_independent(lag_weight, lag_weight)
+    }
+
+    /// Like `Smoother::new`, but fails unless `lag_weight` is in `[0.0, 1.0)`.
+    pub fn try_new(lag_weight: f32) -> Result<Self, SmootherError> {
+        if (0.0..1.0).contains(&lag_weight) {
+            Ok(Self::new(lag_weight))
+        } else {
+            Err(SmootherError::InvalidLagWeight(lag_weight))
+        }
+    }
+
+    /// Smooths the `eye` and `target` with separate lag weights.
+    pub fn new_independent(eye_lag_weight: f32, target_lag_weight: f32) -> Self {
+        Self {
+            eye_lag_weight,
+            target_lag_weight,
+            up_lag_weight: 0.0,
+            interpolation: InterpolationMode::default(),
+            lag_curve: LagWeightCurve::default(),
+            lerp_tfm: None,
+            last_goal: None,
+            elapsed_since_input: 0.0,
+            elapsed_in_motion: 0.0,
+            enabled: true,
+        }
+    }
+
+    /// Also smooths the `up` vector, which otherwise snaps to its goal with a weight of `0.0`.
+    pub fn with_up_lag_weight(mut self, lag_weight: f32) -> Self {
+        self.up_lag_weight = lag_weight;
+        self
+    }
+
+    pub fn with_interpolation(mut self, interpolation: InterpolationMode) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn interpolation(&self) -> InterpolationMode {
+        self.interpolation
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: InterpolationMode) {
+        self.interpolation = interpolation;
+    }
+
+    pub fn with_lag_curve(mut self, lag_curve: LagWeightCurve) -> Self {
+        self.lag_curve = lag_curve;
+        self
+    }
+
+    pub fn lag_curve(&self) -> LagWeightCurve {
+        self.lag_curve
+    }
+
+    pub fn set_lag_curve(&mut self, lag_curve: LagWeightCurve) {
+        self.lag_curve = lag_curve;
+    }
+
+    /// Seconds since the `LookTransform` last changed, i.e. since the last input. Only kept with a [`LagWeightCurve`]
+    /// other than `Constant`.
+    pub fn elapsed_since_input(&self) -> f32 {
+        self.elapsed_since_input
+    }
+
+    /// Whether the smoother is writing the `Transform`. Camera controllers disable their smoother while they're disabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Stops or resumes writing the camera's `Transform`, e.g. to hand the camera over to an animation or another
+    /// system for a while.
+    ///
+    /// While disabled, [`look_transform_system`] leaves the `Transform` alone and the smoother doesn't interpolate.
+    /// Camera controllers keep reading input and moving the `LookTransform` unless they're disabled too; a controller
+    /// also calls this with its own `enabled` flag whenever the controller is changed. When re-enabled, the smoother
+    /// snaps to the current `LookTransform` rather than animating from where it stopped.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if self.enabled {
+            // To prevent camera jumping from last lerp before disabling to the current position,
+            // reset smoother state
+            self.reset();
+        }
+    }
+
+    /// Sets both the eye and target lag weights.
+    pub fn set_lag_weight(&mut self, lag_weight: f32) {
+        self.eye_lag_weight = lag_weight;
+        self.target_lag_weight = lag_weight;
+    }
+
+    /// The lag weight set by `Smoother::new` or `set_lag_weight`. If the eye and target have independent weights, this is
+    /// the eye weight.
+    pub fn lag_weight(&self) -> f32 {
+        self.eye_lag_weight
+    }
+
+    pub fn eye_lag_weight(&self) -> f32 {
+        self.eye_lag_weight
+    }
+
+    pub fn target_lag_weight(&self) -> f32 {
+        self.target_lag_weight
+    }
+
+    pub fn up_lag_weight(&self) -> f32 {
+        self.up_lag_weight
+    }
+
+    /// The most recent smoothed transform, i.e. where the camera is actually drawn. `None` until the first frame after
+    /// creation or a reset.
+    pub fn current_transform(&self) -> Option<LookTransform> {
+        self.lerp_tfm
+    }
+
+    pub fn set_eye_lag_weight(&mut self, lag_weight: f32) {
+        self.eye_lag_weight = lag_weight;
+    }
+
+    pub fn set_target_lag_weight(&mut self, lag_weight: f32) {
+        self.target_lag_weight = lag_weight;
+    }
+
+    pub fn set_up_lag_weight(&mut self, lag_weight: f32) {
+        self.up_lag_weight = lag_weight;
+    }
+
+    /// The exponential smoothing a `Smoother` applies, for any other value, e.g. a field of view, exposure, or volume.
+    /// Moves `old` toward `new` as if by a `Smoother` with `lag_weight`, `dt` seconds after the last step.
+    pub fn smooth_value(old: f32, new: f32, lag_weight: f32, dt: f32) -> f32 {
+        let lag_weight = saturate_lag_weight(lag_weight).powf(dt * REFERENCE_FPS);
+
+        old + (new - old) * (1.0 - lag_weight)
+    }
+
+    /// Like [`Smoother::smooth_value`], for a `Vec3`.
+    pub fn smooth_vec3(old: Vec3, new: Vec3, lag_weight: f32, dt: f32) -> Vec3 {
+        let lag_weight = saturate_lag_weight(lag_weight).powf(dt * REFERENCE_FPS);
+
+        old.lerp(new, 1.0 - lag_weight)
+    }
+
+    /// Moves the smoothed transform toward `new_tfm`, where `dt` is the number of seconds elapsed since the last call.
+    pub fn smooth_transform(&mut self, new_tfm: &LookTransform, dt: f32) -> LookTransform {
+        let lerp_tfm = self.peek(new_tfm, dt);
+        if self.lag_curve != LagWeightCurve::Constant {
+            (self.elapsed_in_motion, self.elapsed_since_input) = self.advance_timers(new_tfm, dt);
+            self.last_goal = Some(*new_tfm);
+        }
+        self.lerp_tfm = Some(lerp_tfm);
+
+        lerp_tfm
+    }
+
+    /// The seconds the camera will have been moving, and since the last input, after a step toward `new_tfm`. The camera
+    /// is moving from the first change to its goal until it has caught up with it again.
+    fn advance_timers(&self, new_tfm: &LookTransform, dt: f32) -> (f32, f32) {
+        let had_input = self.last_goal.is_some_and(|goal| goal != *new_tfm);
+        let caught_up = self
+            .lerp_tfm
+            .map_or(true, |lerp_tfm| lerp_tfm.abs_diff_eq(new_tfm, 1e-4));
+
+        let elapsed_in_motion = if had_input || !caught_up {
+            self.elapsed_in_motion + dt
+        } else {
+            0.0
+        };
+        let elapsed_since_input = if had_input {
+            0.0
+        } else {
+            self.elapsed_since_input + dt
+        };
+
+        (elapsed_in_motion, elapsed_since_input)
+    }
+
+    /// The transform `smooth_transform` would return, without advancing the smoother, e.g. to predict where the camera
+    /// will be next frame.
+    pub fn peek(&self, new_tfm: &LookTransform, dt: f32) -> LookTransform {
+        debug_assert!(0.0 <= dt);
+
+        let old_lerp_tfm = self.lerp_tfm.unwrap_or(*new_tfm);
+        let scale = match self.lag_curve {
+            LagWeightCurve::Constant => 1.0,
+            curve => {
+                let (elapsed_in_motion, elapsed_since_input) = self.advance_timers(new_tfm, dt);
+                curve.scale(elapsed_in_motion, elapsed_since_input)
+            }
+        };
+
+        let target = Self::smooth_vec3(
+            old_lerp_tfm.target,
+            new_tfm.target,
+            scale * self.target_lag_weight,
+            dt,
+        );
+        let eye = match self.interpolation {
+            InterpolationMode::WorldSpace => Self::smooth_vec3(
+                old_lerp_tfm.eye,
+                new_tfm.eye,
+                scale * self.eye_lag_weight,
+                dt,
+            ),
+            InterpolationMode::SphericalArc => {
+                let eye_lag_weight =
+                    saturate_lag_weight(scale * self.eye_lag_weight).powf(dt * REFERENCE_FPS);
+                target + arc_offset(&old_lerp_tfm, new_tfm, 1.0 - eye_lag_weight)
+            }
+        };
+        let up = if self.up_lag_weight == 0.0 {
+            new_tfm.up
+        } else {
+            let up_lag_weight =
+                saturate_lag_weight(scale * self.up_lag_weight).powf(dt * REFERENCE_FPS);
+            slerp_up(
+                old_lerp_tfm.up,
+                new_tfm.up,
+                1.0 - up_lag_weight,
+                target - eye,
+            )
+        };
+
+        LookTransform { eye, target, up }
+    }
+
+    /// Forgets the smoothed state, so the next call to `smooth_transform` snaps directly to the given transform without
+    /// animating. Use this when teleporting a camera.
+    pub fn reset(&mut self) {
+        self.lerp_tfm = None;
+        self.reset_timers();
+    }
+
+    /// Like `reset`, but seeds the smoothed state with `tfm`, so smoothing continues from there.
+    pub fn reset_to(&mut self, tfm: &LookTransform) {
+        self.lerp_tfm = Some(*tfm);
+        self.reset_timers();
+    }
+
+    fn reset_timers(&mut self) {
+        self.last_goal = None;
+        self.elapsed_since_input = 0.0;
+        self.elapsed_in_motion = 0.0;
+    }
+}
+
+/// How a `Smoother`'s lag weights change over time, scaling them from `0.0`, which follows the `LookTransform` exactly,
+/// to their full values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum LagWeightCurve {
+    /// Always the full lag weights.
+    #[default]
+    Constant,
+    /// Ramps the lag weights up from `0.0` over this many seconds once the camera starts moving from rest, so the lag
+    /// builds up gradually rather than suddenly becoming visible.
+    EaseIn(f32),
+    /// Ramps the lag weights down to `0.0` over this many seconds after the last input, so the camera settles promptly
+    /// once input stops.
+    EaseOut(f32),
+}
+
+impl LagWeightCurve {
+    /// The factor for the lag weights, after the camera has been moving for `elapsed_in_motion` seconds, and
+    /// `elapsed_since_input` seconds after the last input.
+    pub fn scale(self, elapsed_in_motion: f32, elapsed_since_input: f32) -> f32 {
+        let ramp = |elapsed: f32, duration: f32| {
+            if duration > 0.0 {
+                (elapsed / duration).clamp(0.0, 1.0)
+            } else if elapsed > 0.0 {
+                1.0
+            } else {
+                0.0
+            }
+        };
+
+        match self {
+            Self::Constant => 1.0,
+            Self::EaseIn(duration) => ramp(elapsed_in_motion, duration),
+            Self::EaseOut(duration) => 1.0 - ramp(elapsed_since_input, duration),
+        }
+    }
+}
+
+/// How a `Smoother` moves the `eye` toward its goal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum InterpolationMode {
+    /// In a straight line, which cuts inside the sphere around the target when orbiting.
+    #[default]
+    WorldSpace,
+    /// Along an arc around the smoothed `target`, interpolating the `radius` and the pitch and yaw about `up`
+    /// separately. The yaw always takes the shorter way around.
+    SphericalArc,
+}
+
+/// The offset of the `eye` from the `target`, `t` of the way along the arc from `from` to `to`.
+fn arc_offset(from: &LookTransform, to: &LookTransform, t: f32) -> Vec3 {
+    use std::f32::consts::{PI, TAU};
+
+    let (from_pitch, from_yaw) = orbit_angles(from.eye, from.target, to.up);
+    let (to_pitch, to_yaw) = orbit_angles(to.eye, to.target, to.up);
+    let yaw_delta = (to_yaw - from_yaw + PI).rem_euclid(TAU) - PI;
+
+    let pitch = from_pitch + t * (to_pitch - from_pitch);
+    let yaw = from_yaw + t * yaw_delta;
+    let radius = from.radius() + t * (to.radius() - from.radius());
+
+    radius * orbit_direction(to.up, pitch, yaw)
+}
+
+/// Rotates the unit vector along `from` toward `to` by `t` of the angle between them. When they're opposite, every great
+/// circle is equally short, so it rolls around `look` to keep the view level with the look direction.
+fn slerp_up(from: Vec3, to: Vec3, t: f32, look: Vec3) -> Vec3 {
+    let (Some(from), Some(to)) = (from.try_normalize(), to.try_normalize()) else {
+        return to;
+    };
+
+    let rotation = if from.dot(to) < -1.0 + 1e-6 {
+        let axis = look
+            .reject_from(from)
+            .try_normalize()
+            .unwrap_or_else(|| from.any_orthonormal_vector());
+        Quat::from_axis_angle(axis, std::f32::consts::PI)
+    } else {
+        Quat::from_rotation_arc(from, to)
+    };
+
+    (Quat::IDENTITY.slerp(rotation, t) * from).normalize()
+}
+
+/// Clamps a lag weight into `[0.0, MAX_LAG_WEIGHT]`, treating NaN as `0.0`.
+fn saturate_lag_weight(lag_weight: f32) -> f32 {
+    if lag_weight.is_nan() {
+        0.0
+    } else {
+        lag_weight.clamp(0.0, MAX_LAG_WEIGHT)
+    }
+}
+
+/// The largest lag weight that still moves the camera noticeably. At 60 FPS, it takes about 11 seconds to close half
+/// the distance to the goal.
+const MAX_LAG_WEIGHT: f32 = 0.999;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmootherError {
+    /// The lag weight wasn't in `[0.0, 1.0)`.
+    InvalidLagWeight(f32),
+}
+
+impl std::fmt::Display for SmootherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLagWeight(w) => write!(f, "lag weight {w} is not in [0.0, 1.0)"),
+        }
+    }
+}
+
+impl std::error::Error for SmootherError {}
+
+/// Smooths a `LookTransform` by simulating a damped spring pulling the `eye` and `target` toward their goals. Unlike
+/// `Smoother`, an under-damped spring can overshoot, which feels snappier for action games.
+///
+/// The spring is critically damped (fastest settling without overshoot) when `damping == 2.0 * stiffness.sqrt()`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct SpringSmoother {
+    pub stiffness: f32,
+    pub damping: f32,
+    /// Current velocities of the (eye, target).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub velocity: (Vec3, Vec3),
+    #[cfg_attr(feature = "serde", serde(skip))]
+    lerp_tfm: Option<LookTransform>,
+    enabled: bool,
+}
+
+impl Default for SpringSmoother {
+    fn default() -> Self {
+        Self::critically_damped(100.0)
+    }
+}
+
+impl SpringSmoother {
+    /// The longest step `smooth_transform` simulates at once, in seconds.
+    pub const MAX_STEP: f32 = 1.0 / 120.0;
+
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            velocity: (Vec3::ZERO, Vec3::ZERO),
+            lerp_tfm: None,
+            enabled: true,
+        }
+    }
+
+    pub fn critically_damped(stiffness: f32) -> Self {
+        Self::new(stiffness, 2.0 * stiffness.sqrt())
+    }
+
+    /// Whether the smoother is writing the `Transform`. Camera controllers disable their smoother while they're disabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The most recent smoothed transform, like [`Smoother::current_transform`].
+    pub fn current_transform(&self) -> Option<LookTransform> {
+        self.lerp_tfm
+    }
+
+    /// Stops or resumes writing the camera's `Transform`, like [`Smoother::set_enabled`].
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if self.enabled {
+            self.reset();
+        }
+    }
+
+    /// Advances the spring simulation toward `new_tfm` by `dt` seconds with symplectic Euler steps of at most
+    /// [`Self::MAX_STEP`], since a single step of a stiff spring blows up on a long frame.
+    pub fn smooth_transform(&mut self, new_tfm: &LookTransform, dt: f32) -> LookTransform {
+        debug_assert!(0.0 <= dt);
+
+        let mut lerp_tfm = self.lerp_tfm.unwrap_or(*new_tfm);
+        let steps = (dt / Self::MAX_STEP).ceil().max(1.0);
+        let step = dt / steps;
+        let (eye_velocity, target_velocity) = &mut self.velocity;
+        for _ in 0..steps as u32 {
+            let eye_accel =
+                self.stiffness * (new_tfm.eye - lerp_tfm.eye) - self.damping * *eye_velocity;
+            let target_accel = self.stiffness * (new_tfm.target - lerp_tfm.target)
+                - self.damping * *target_velocity;
+            *eye_velocity += step * eye_accel;
+            *target_velocity += step * target_accel;
+
+            lerp_tfm.eye += step * *eye_velocity;
+            lerp_tfm.target += step * *target_velocity;
+        }
+        lerp_tfm.up = new_tfm.up;
+
+        self.lerp_tfm = Some(lerp_tfm);
+
+        lerp_tfm
+    }
+
+    /// Forgets the simulated state and velocity, so the next call to `smooth_transform` snaps directly to the given transform.
+    pub fn reset(&mut self) {
+        self.lerp_tfm = None;
+        self.velocity = (Vec3::ZERO, Vec3::ZERO);
+    }
+}
+
+/// Sent by [`look_transform_system`] when a camera's smoothed `LookTransform`, i.e. where it's drawn, moves by more than
+/// the [`LookTransformMovedEpsilon`], for systems like LOD and audio that follow the camera.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct LookTransformMoved {
+    pub entity: Entity,
+    /// The smoothed transform on the previous frame.
+    pub old: LookTransform,
+    pub new: LookTransform,
+}
+
+/// The smallest change in any coordinate of a smoothed `LookTransform` that sends a [`LookTransformMoved`] event, so
+/// floating point noise doesn't.
+#[derive(Clone, Copy, Debug, Resource, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct LookTransformMovedEpsilon(pub f32);
+
+impl Default for LookTransformMovedEpsilon {
+    fn default() -> Self {
+        Self(10.0 * f32::EPSILON)
+    }
+}
+
+/// The components [`look_transform_system`] reads and writes on each camera.
+type SyncedCamera = (
+    Entity,
+    &'static LookTransform,
+    &'static mut Transform,
+    Option<&'static mut Smoother>,
+    Option<&'static mut SpringSmoother>,
+    Option<&'static CameraShake>,
+    Option<&'static CameraConstraint>,
+    Option<&'static mut CameraLag>,
+    Option<&'static FollowExactly>,
+);
+
+/// With the `parallel_transform_sync` feature, cameras are synced in parallel on the `ComputeTaskPool`, which pays off
+/// with hundreds of them. [`LookTransformMoved`] events are then sent in `Entity` order, rather than query order.
+pub fn look_transform_system(
+    time: Res<Time>,
+    epsilon: Res<LookTransformMovedEpsilon>,
+    mut moved: EventWriter<LookTransformMoved>,
+    mut cameras: Query<SyncedCamera, Without<Inactive>>,
+) {
+    let dt = time.delta_seconds();
+    let t = time.elapsed_seconds_wrapped();
+    let now = time.elapsed_seconds();
+
+    #[cfg(not(feature = "parallel_transform_sync"))]
+    for camera in cameras.iter_mut() {
+        if let Some(event) = sync_camera(camera, dt, t, now, epsilon.0) {
+            moved.send(event);
+        }
+    }
+
+    #[cfg(feature = "parallel_transform_sync")]
+    {
+        let events = std::sync::Mutex::new(Vec::new());
+        cameras.par_iter_mut().for_each(|camera| {
+            if let Some(event) = sync_camera(camera, dt, t, now, epsilon.0) {
+                events.lock().unwrap().push(event);
+            }
+        });
+
+        let mut events = events.into_inner().unwrap();
+        events.sort_unstable_by_key(|event| event.entity);
+        moved.send_batch(events);
+    }
+}
+
+fn sync_camera(
+    camera: QueryItem<SyncedCamera>,
+    dt: f32,
+    t: f32,
+    now: f32,
+    epsilon: f32,
+) -> Option<LookTransformMoved> {
+    let (
+        entity,
+        look_transform,
+        mut scene_transform,
+        smoother,
+        spring,
+        shake,
+        constraint,
+        lag,
+        follow,
+    ) = camera;
+    let look_transform = match lag {
+        Some(mut lag) => lag.update(now, *look_transform),
+        None => *look_transform,
+    };
+    if !is_synced(smoother.as_deref(), spring.as_deref(), follow.is_some()) {
+        return None;
+    }
+
+    let (old, mut smoothed) = match (smoother, spring) {
+        (Some(mut s), _) if s.enabled => (s.lerp_tfm, s.smooth_transform(&look_transform, dt)),
+        (_, Some(mut s)) if s.enabled => (s.lerp_tfm, s.smooth_transform(&look_transform, dt)),
+        _ => (None, look_transform),
+    };
+    let event = old
+        .filter(|old| !old.abs_diff_eq(&smoothed, epsilon))
+        .map(|old| LookTransformMoved {
+            entity,
+            old,
+            new: smoothed,
+        });
+    if let Some(constraint) = constraint {
+        smoothed = constraint.apply(smoothed);
+    }
+
+    *scene_transform = smoothed.into();
+    if let Some(shake) = shake {
+        shake.apply(&mut scene_transform, t);
+    }
+
+    event
+}
+
+/// Whether [`look_transform_system`] writes to a camera's `Transform`: it has an enabled smoother, or no smoother and
+/// [`FollowExactly`]. A disabled smoother leaves the `Transform` alone. Post-processing like head bob should only be
+/// added on top of a `Transform` that was just written.
+pub(crate) fn is_synced(
+    smoother: Option<&Smoother>,
+    spring: Option<&SpringSmoother>,
+    follow_exactly: bool,
+) -> bool {
+    match (smoother, spring) {
+        (Some(s), _) if s.enabled => true,
+        (_, Some(s)) if s.enabled => true,
+        (Some(_), _) | (_, Some(_)) => false,
+        (None, None) => follow_exactly,
+    }
+}
+
+/// Writes each changed `LookTransform` straight to the `Transform`. Added instead of [`look_transform_system`] by
+/// [`LookTransformPlugin::lean`].
+#[allow(clippy::type_complexity)]
+pub fn lean_look_transform_system(
+    mut cameras: Query<
+        (&LookTransform, &mut Transform),
+        (Changed<LookTransform>, Without<Inactive>),
+    >,
+) {
+    for (look_transform, mut scene_transform) in cameras.iter_mut() {
+        *scene_transform = (*look_transform).into();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::ecs::system::SystemState;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn test_smoothing_is_frame_rate_independent() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-4.0, 5.0, 6.0), Vec3::Y);
+        let dt = 1.0 / 30.0;
+
+        let mut full_step = Smoother::new(0.8);
+        full_step.smooth_transform(&start, dt);
+        let full = full_step.smooth_transform(&goal, dt);
+
+        let mut half_steps = Smoother::new(0.8);
+        half_steps.smooth_transform(&start, dt);
+        half_steps.smooth_transform(&goal, 0.5 * dt);
+        let half = half_steps.smooth_transform(&goal, 0.5 * dt);
+
+        assert_relative_eq!(full.eye.x, half.eye.x, epsilon = 1e-5);
+        assert_relative_eq!(full.eye.y, half.eye.y, epsilon = 1e-5);
+        assert_relative_eq!(full.eye.z, half.eye.z, epsilon = 1e-5);
+        assert_relative_eq!(full.target.x, half.target.x, epsilon = 1e-5);
+        assert_relative_eq!(full.target.y, half.target.y, epsilon = 1e-5);
+        assert_relative_eq!(full.target.z, half.target.z, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_smooth_value_matches_smoother() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-4.0, 5.0, 6.0), Vec3::Y);
+        let dt = 1.0 / 30.0;
+
+        let mut smoother = Smoother::new(0.8);
+        smoother.smooth_transform(&start, dt);
+        let smoothed = smoother.smooth_transform(&goal, dt);
+
+        assert_eq!(
+            Smoother::smooth_vec3(start.eye, goal.eye, 0.8, dt),
+            smoothed.eye
+        );
+        assert_relative_eq!(
+            Smoother::smooth_value(start.target.y, goal.target.y, 0.8, dt),
+            smoothed.target.y,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_spherical_arc_keeps_radius() {
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let goal = LookTransform::new(Vec3::new(5.0, 0.0, 0.0), Vec3::ZERO, Vec3::Y);
+
+        let mut smoother = Smoother::new(0.5).with_interpolation(InterpolationMode::SphericalArc);
+        smoother.smooth_transform(&start, 1.0 / 60.0);
+        let tfm = smoother.smooth_transform(&goal, 1.0 / 60.0);
+
+        let expected = 5.0 * Vec3::new(1.0, 0.0, 1.0).normalize();
+        assert_relative_eq!(tfm.radius(), 5.0, epsilon = 1e-5);
+        assert!(tfm.eye.abs_diff_eq(expected, 1e-5));
+
+        let mut linear = Smoother::new(0.5);
+        linear.smooth_transform(&start, 1.0 / 60.0);
+        assert!(linear.smooth_transform(&goal, 1.0 / 60.0).radius() < 4.0);
+    }
+
+    #[test]
+    fn test_independent_lag_weights() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::X, Vec3::X + Vec3::Z, Vec3::Y);
+
+        let mut smoother = Smoother::new_independent(0.5, 0.0);
+        smoother.smooth_transform(&start, 1.0 / 60.0);
+        let tfm = smoother.smooth_transform(&goal, 1.0 / 60.0);
+
+        assert_relative_eq!(tfm.eye.x, 0.5, epsilon = 1e-5);
+        assert_relative_eq!(tfm.target.x, 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_defaults() {
+        let bundle = LookTransformBundle::<Smoother>::default();
+        assert_eq!(bundle.transform.radius(), 5.0);
+        assert_eq!(bundle.transform.look_direction(), Some(Vec3::NEG_Z));
+        assert_eq!(bundle.smoother.lag_weight(), 0.5);
+        assert_eq!(bundle.smoother.current_transform(), None);
+    }
+
+    #[test]
+    fn test_invalid_lag_weights() {
+        assert!(Smoother::try_new(0.0).is_ok());
+        assert_eq!(
+            Smoother::try_new(1.0).unwrap_err(),
+            SmootherError::InvalidLagWeight(1.0)
+        );
+        assert!(Smoother::try_new(-0.1).is_err());
+
+        // Out of range weights saturate instead of diverging.
+        let goal = LookTransform::new(Vec3::X, Vec3::ZERO, Vec3::Y);
+        let mut smoother = Smoother::new(1.5);
+        smoother.reset_to(&LookTransform::new(Vec3::ZERO, Vec3::ZERO, Vec3::Y));
+        let smoothed = smoother.smooth_transform(&goal, 1.0 / 60.0);
+        assert!(smoothed.eye.x > 0.0 && smoothed.eye.x < 1.0);
+
+        let mut smoother = Smoother::new(-1.0);
+        smoother.reset_to(&LookTransform::new(Vec3::ZERO, Vec3::ZERO, Vec3::Y));
+        assert_eq!(smoother.smooth_transform(&goal, 1.0 / 60.0), goal);
+    }
+
+    #[test]
+    fn test_up_lag_weight_rolls_smoothly() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let mut smoother = Smoother::new(0.0).with_up_lag_weight(0.5);
+        smoother.smooth_transform(&start, 1.0 / 60.0);
+
+        // Flipping upside down rolls around the look direction.
+        let flipped = LookTransform {
+            up: Vec3::NEG_Y,
+            ..start
+        };
+        let up = smoother.smooth_transform(&flipped, 1.0 / 60.0).up;
+        assert_relative_eq!(up.length(), 1.0, epsilon = 1e-5);
+        assert_relative_eq!(up.dot(Vec3::NEG_Z), 0.0, epsilon = 1e-5);
+        assert_relative_eq!(up.dot(Vec3::Y), 0.0, epsilon = 1e-5);
+
+        let snapping = Smoother::new(0.0).peek(&flipped, 1.0 / 60.0);
+        assert_eq!(snapping.up, Vec3::NEG_Y);
+    }
+
+    #[test]
+    fn test_peek_does_not_advance() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::X, Vec3::new(1.0, 0.0, 1.0), Vec3::Y);
+
+        let mut smoother = Smoother::new(0.9);
+        smoother.smooth_transform(&start, 1.0 / 60.0);
+        let peeked = smoother.peek(&goal, 1.0 / 60.0);
+        assert_eq!(smoother.peek(&goal, 1.0 / 60.0), peeked);
+        assert_eq!(smoother.current_transform(), Some(start));
+        assert_eq!(smoother.smooth_transform(&goal, 1.0 / 60.0), peeked);
+    }
+
+    #[test]
+    fn test_reset_snaps_to_new_transform() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let teleport = LookTransform::new(Vec3::splat(100.0), Vec3::splat(101.0), Vec3::Y);
+
+        let mut smoother = Smoother::new(0.9);
+        smoother.smooth_transform(&start, 1.0 / 60.0);
+        smoother.reset();
+        assert_eq!(smoother.smooth_transform(&teleport, 1.0 / 60.0), teleport);
+
+        smoother.reset_to(&start);
+        assert_eq!(smoother.smooth_transform(&start, 1.0 / 60.0), start);
+    }
+
+    #[test]
+    fn test_smoother_added_mid_flight_snaps_then_smooths() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<LookTransformMovedEpsilon>()
+            .add_event::<LookTransformMoved>()
+            .add_systems(Update, look_transform_system);
+        let camera = app
+            .world
+            .spawn((LookTransform::default(), Transform::default()))
+            .id();
+        let step = |app: &mut App, dx: f32| {
+            app.world.get_mut::<LookTransform>(camera).unwrap().eye.x += dx;
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+            app.world.get::<Transform>(camera).unwrap().translation.x
+        };
+
+        step(&mut app, 1.0);
+        step(&mut app, 1.0);
+        app.world.entity_mut(camera).insert(Smoother::new(0.9));
+        assert_eq!(step(&mut app, 1.0), 3.0);
+        let x = step(&mut app, 1.0);
+        assert!(3.0 < x && x < 4.0);
+    }
+
+    #[test]
+    fn test_disabled_smoother_leaves_transform_alone() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<LookTransformMovedEpsilon>()
+            .add_event::<LookTransformMoved>()
+            .add_systems(Update, look_transform_system);
+        let mut smoother = Smoother::new(0.0);
+        smoother.set_enabled(false);
+        let untouched = Transform::from_xyz(7.0, 8.0, 9.0);
+        let camera = app
+            .world
+            .spawn((LookTransform::default(), smoother, untouched))
+            .id();
+
+        app.update();
+        assert_eq!(*app.world.get::<Transform>(camera).unwrap(), untouched);
+
+        app.world
+            .get_mut::<Smoother>(camera)
+            .unwrap()
+            .set_enabled(true);
+        app.update();
+        assert_eq!(
+            *app.world.get::<Transform>(camera).unwrap(),
+            Transform::from(LookTransform::default())
+        );
+    }
+
+    #[test]
+    fn test_slerp_keeps_interpolated_radius() {
+        let a = LookTransform::new(Vec3::ZERO, Vec3::new(0.0, 0.0, -2.0), Vec3::Y);
+        let b = LookTransform::new(Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0), Vec3::Y);
+
+        let mid = a.slerp(b, 0.5);
+        assert_relative_eq!(mid.radius(), 3.0, epsilon = 1e-5);
+
+        let expected_dir = Vec3::new(1.0, 0.0, -1.0).normalize();
+        let dir = mid.look_direction().unwrap();
+        assert_relative_eq!(dir.x, expected_dir.x, epsilon = 1e-5);
+        assert_relative_eq!(dir.z, expected_dir.z, epsilon = 1e-5);
+
+        let degenerate = LookTransform::new(Vec3::ONE, Vec3::ONE, Vec3::Y);
+        assert_eq!(degenerate.slerp(b, 0.5), degenerate);
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn test_interpolate_arc_keeps_radius() {
+        let from = LookTransform::new(Vec3::new(0.0, 0.0, 2.0), Vec3::ZERO, Vec3::Y);
+        let to = LookTransform::new(Vec3::new(2.0, 0.0, 0.0), Vec3::ZERO, Vec3::Y);
+        let halfway = from.interpolate_arc(&to, 0.5);
+        assert!(halfway
+            .eye
+            .abs_diff_eq(Vec3::new(1.0, 0.0, 1.0).normalize() * 2.0, 1e-5));
+        assert_relative_eq!(from.lerp(to, 0.5).radius(), 2.0f32.sqrt(), epsilon = 1e-5);
+
+        // Opposite sides swing around `up`, so the camera stays level.
+        let behind = LookTransform::new(Vec3::new(0.0, 0.0, -2.0), Vec3::ZERO, Vec3::Y);
+        let halfway = from.interpolate_arc(&behind, 0.5);
+        assert_relative_eq!(halfway.eye.y, 0.0, epsilon = 1e-5);
+        assert_relative_eq!(halfway.radius(), 2.0, epsilon = 1e-5);
+
+        // The target and radius move linearly.
+        let moved = LookTransform::new(Vec3::new(4.0, 0.0, 4.0), Vec3::new(0.0, 0.0, 4.0), Vec3::Y);
+        let halfway = from.interpolate_arc(&moved, 0.5);
+        assert!(halfway.target.abs_diff_eq(Vec3::new(0.0, 0.0, 2.0), 1e-5));
+        assert_relative_eq!(halfway.radius(), 3.0, epsilon = 1e-5);
+        assert_eq!(from.interpolate_arc(&moved, 1.0).target, moved.target);
+    }
+
+    #[test]
+    fn test_face_entity() {
+        let mut world = World::new();
+        let target = world
+            .spawn(GlobalTransform::from_translation(Vec3::new(3.0, 0.0, 5.0)))
+            .id();
+        let missing = world.spawn_empty().id();
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        let mut state: SystemState<Query<&GlobalTransform>> = SystemState::new(&mut world);
+        let transforms = state.get(&world);
+        assert_eq!(
+            look.face_entity(target, &transforms),
+            Some(look.face_towards(Vec3::new(3.0, 0.0, 5.0)))
+        );
+        assert_eq!(look.face_entity(missing, &transforms), None);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let look = LookTransform::new(Vec3::ONE, Vec3::X, Vec3::Y);
+        assert_eq!(LookTransform::from((Vec3::ONE, Vec3::X)), look);
+        assert_eq!(LookTransform::from((Vec3::ONE, Vec3::X, Vec3::Y)), look);
+
+        let (eye, target, up) = look.into();
+        assert_eq!((eye, target, up), (Vec3::ONE, Vec3::X, Vec3::Y));
+    }
+
+    #[test]
+    fn test_builder_methods() {
+        let look = LookTransform::default()
+            .with_eye(Vec3::ONE)
+            .with_target(Vec3::X)
+            .with_up(Vec3::Z);
+        assert_eq!(look, LookTransform::new(Vec3::ONE, Vec3::X, Vec3::Z));
+    }
+
+    #[test]
+    fn test_face_towards_and_direction() {
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        let faced = look.face_towards(Vec3::new(3.0, 0.0, 5.0));
+        assert_eq!(faced.eye, look.eye);
+        assert_eq!(faced.target, Vec3::new(3.0, 0.0, 5.0));
+        assert_eq!(look.face_towards(look.eye), look);
+
+        let faced = look.face_direction(Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(faced.target, Vec3::new(0.0, 5.0, 5.0));
+        assert_eq!(look.face_direction(Vec3::ZERO), look);
+    }
+
+    #[test]
+    fn test_equality_is_bitwise_and_hashable() {
+        let look = LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y);
+        let negative_zero = LookTransform {
+            target: Vec3::splat(-0.0),
+            ..look
+        };
+        assert_ne!(look, negative_zero);
+
+        let mut seen = std::collections::HashSet::new();
+        assert!(seen.insert(look));
+        assert!(!seen.insert(LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y)));
+        assert!(seen.insert(negative_zero));
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let look = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let moved = LookTransform::new(Vec3::X, Vec3::new(0.0, 2.0, -1.0), Vec3::X);
+        assert_eq!(look.distance_to(&moved), 3.0);
+        assert!(look.is_near(&moved, 3.0));
+        assert!(!look.is_near(&moved, 2.9));
+    }
+
+    #[test]
+    fn test_translate_and_translate_local() {
+        let look = LookTransform::new(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), Vec3::Y);
+        let moved = look.translate(Vec3::ONE);
+        assert_eq!(moved.eye, Vec3::ONE);
+        assert_eq!(moved.target, Vec3::new(3.0, 1.0, 1.0));
+
+        // Looking along +X, right is +Z.
+        let moved = look.translate_local(Vec3::new(1.0, 2.0, 3.0));
+        assert!(moved.eye.abs_diff_eq(Vec3::new(3.0, 2.0, 1.0), 1e-6));
+        assert!(moved.target.abs_diff_eq(Vec3::new(5.0, 2.0, 1.0), 1e-6));
+
+        let straight_up = LookTransform::new(Vec3::ZERO, Vec3::Y, Vec3::Y);
+        assert_eq!(straight_up.translate_local(Vec3::ONE), straight_up);
+    }
+
+    #[test]
+    fn test_orbit_to_keeps_radius() {
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ONE, Vec3::Y);
+        let radius = look.radius();
+        let moved = look.orbit_to(Vec3::new(1.0, 10.0, 1.0));
+        assert!(moved.eye.abs_diff_eq(Vec3::ONE + radius * Vec3::Y, 1e-5));
+        assert_eq!(moved.target, look.target);
+        assert_eq!(
+            look.orbit_to_direction(Vec3::X),
+            look.orbit_to(Vec3::new(7.0, 1.0, 1.0))
+        );
+
+        assert_eq!(look.orbit_to(look.target), look);
+    }
+
+    #[test]
+    fn test_lag_curve_eases_lag_weight() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::X, Vec3::new(1.0, 0.0, -1.0), Vec3::Y);
+        let first_step = |curve| {
+            let mut smoother = Smoother::new(0.9).with_lag_curve(curve);
+            smoother.smooth_transform(&start, 0.1);
+            smoother.smooth_transform(&goal, 0.1).eye.x
+        };
+
+        // Easing in, the lag builds up over a second, so the first step follows more closely.
+        let constant = first_step(LagWeightCurve::Constant);
+        let eased_in = first_step(LagWeightCurve::EaseIn(1.0));
+        assert!(constant < eased_in && eased_in < 1.0);
+
+        // Easing out, the camera catches up once input has stopped for long enough.
+        let mut smoother = Smoother::new(0.9).with_lag_curve(LagWeightCurve::EaseOut(0.5));
+        smoother.smooth_transform(&start, 0.1);
+        assert_relative_eq!(smoother.smooth_transform(&goal, 0.1).eye.x, constant);
+        for _ in 0..4 {
+            smoother.smooth_transform(&goal, 0.1);
+        }
+        assert_relative_eq!(smoother.elapsed_since_input(), 0.4, epsilon = 1e-5);
+        assert_eq!(smoother.smooth_transform(&goal, 0.1), goal);
+    }
+
+    #[test]
+    fn test_bundle_looking_at() {
+        let bundle = LookTransformBundle::looking_at(Vec3::ONE, Vec3::ZERO, 0.7);
+        assert_eq!(
+            bundle.transform,
+            LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y)
+        );
+        assert_eq!(bundle.smoother.lag_weight(), 0.7);
+
+        let z_up = LookTransformBundle::looking_at_with_up(Vec3::ONE, Vec3::ZERO, Vec3::Z, 0.7);
+        assert_eq!(z_up.transform.up, Vec3::Z);
+    }
+
+    #[test]
+    fn test_minimal_bundle_follows_exactly() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::default());
+        let look = LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((MinimalLookTransformBundle::new(look), Transform::default()))
+            .id();
+        app.update();
+
+        assert_eq!(*app.world.get::<Transform>(camera).unwrap(), look.into());
+    }
+
+    #[test]
+    fn test_smooth_look_at_limits_speed() {
+        let mut look = LookTransform::new(Vec3::ZERO, Vec3::new(0.0, 0.0, -2.0), Vec3::Y);
+        let point = Vec3::new(10.0, 0.0, 0.0);
+        look.smooth_look_at(point, 45.0, 1.0);
+        assert!(look.target.abs_diff_eq(
+            2.0 * Vec3::new(FRAC_PI_4.sin(), 0.0, -FRAC_PI_4.cos()),
+            1e-5
+        ));
+
+        // The rest of the turn fits in the next step, which keeps the radius.
+        look.smooth_look_at(point, 45.0, 2.0);
+        assert!(look.target.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn test_is_looking_at() {
+        let look = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        assert!(look.is_looking_at(Vec3::new(0.0, 1.0, -10.0), 10.0));
+        assert!(!look.is_looking_at(Vec3::new(0.0, 2.0, -10.0), 10.0));
+        assert!(!look.is_looking_at(Vec3::Z, 90.0));
+
+        assert!(!look.is_looking_at(look.eye, 180.0));
+        let degenerate = LookTransform::new(Vec3::ZERO, Vec3::ZERO, Vec3::Y);
+        assert!(!degenerate.is_looking_at(Vec3::NEG_Z, 180.0));
+    }
+
+    #[test]
+    fn test_angular_distance_ignores_zoom() {
+        let look = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let zoomed = look.with_radius(5.0);
+        assert_eq!(look.angular_distance_to(&zoomed), 0.0);
+        assert_eq!(look.full_distance_to(&zoomed), (4.0, 0.0, 4.0));
+
+        let turned = LookTransform::new(Vec3::X, Vec3::new(2.0, 0.0, 0.0), Vec3::Y);
+        assert_relative_eq!(look.angular_distance_to(&turned), FRAC_PI_2);
+        let (position, angle, radius) = look.full_distance_to(&turned);
+        assert_eq!((position, radius), (1.0, 0.0));
+        assert_relative_eq!(angle, FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_as_ray() {
+        let look = LookTransform::new(Vec3::ONE, Vec3::new(1.0, 1.0, -3.0), Vec3::Y);
+        let ray = look.as_ray().unwrap();
+        assert_eq!(ray.origin, Vec3::ONE);
+        assert_eq!(ray.direction, Vec3::NEG_Z);
+
+        assert!(LookTransform::new(Vec3::ONE, Vec3::ONE, Vec3::Y)
+            .as_ray()
+            .is_none());
+    }
+
+    #[test]
+    fn test_rotate_around_up_and_right() {
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        let turned = look.rotate_around_up(FRAC_PI_2);
+        assert!(turned.eye.abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-5));
+        assert_relative_eq!(turned.yaw(), look.yaw() + FRAC_PI_2, epsilon = 1e-5);
+
+        let tilted = look.rotate_around_right(0.25);
+        assert_relative_eq!(tilted.pitch(), 0.25, epsilon = 1e-5);
+        assert_relative_eq!(tilted.radius(), 5.0, epsilon = 1e-5);
+        assert_eq!(tilted.target, look.target);
+    }
+
+    #[test]
+    fn test_orbit_by_delta() {
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ONE, Vec3::Y);
+
+        let orbited = look.orbit_by_delta(0.3, FRAC_PI_2);
+        let (pitch, yaw) = orbit_angles(orbited.eye, orbited.target, orbited.up);
+        let (start_pitch, start_yaw) = orbit_angles(look.eye, look.target, look.up);
+        assert_relative_eq!(pitch, start_pitch + 0.3, epsilon = 1e-5);
+        assert_relative_eq!(yaw, start_yaw + FRAC_PI_2, epsilon = 1e-5);
+        assert_relative_eq!(orbited.radius(), look.radius(), epsilon = 1e-4);
+        assert_eq!(orbited.target, look.target);
+
+        // Orbiting over the top stops just short of the pole.
+        let over = look.orbit_by_delta(10.0, 0.0);
+        let (pitch, _) = orbit_angles(over.eye, over.target, over.up);
+        assert_relative_eq!(pitch, MAX_ORBIT_PITCH, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_frame_sphere_and_box() {
+        let look = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let center = Vec3::new(1.0, 2.0, 3.0);
+
+        let framed = look.frame_sphere(center, 1.0, FRAC_PI_2);
+        assert_eq!(framed.target, center);
+        assert_relative_eq!(framed.radius(), 2.0f32.sqrt(), epsilon = 1e-5);
+        let dir = framed.look_direction().unwrap();
+        assert!(dir.abs_diff_eq(Vec3::NEG_Z, 1e-5));
+
+        // A unit cube seen face on: the nearest face, 1 unit closer, must fit a 90 degree view.
+        let framed = look.frame_box(center, Vec3::ONE, FRAC_PI_2, 1.0, 0.0);
+        assert_eq!(framed.target, center);
+        assert_relative_eq!(framed.radius(), 2.0, epsilon = 1e-5);
+        // Twice as wide as tall, so the height limits the view.
+        let wide = look.frame_box(center, Vec3::new(2.0, 1.0, 1.0), FRAC_PI_2, 2.0, 0.0);
+        assert_relative_eq!(wide.radius(), 2.0, epsilon = 1e-5);
+        let padded = look.frame_box(center, Vec3::ONE, FRAC_PI_2, 1.0, 0.5);
+        assert_relative_eq!(padded.radius(), 3.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_clamp_radius() {
+        let transform =
+            LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::new(0.0, 0.0, 2.0), Vec3::Y);
+
+        let clamped = transform.clamp_radius(1.0, 4.0);
+        assert_eq!(clamped.eye, Vec3::new(0.0, 0.0, 6.0));
+        assert_eq!(clamped.target, transform.target);
+        assert_eq!(transform.clamp_radius(1.0, 20.0), transform);
+        assert_eq!(transform.with_radius(1.0).eye, Vec3::new(0.0, 0.0, 3.0));
+
+        let degenerate = LookTransform::new(Vec3::ONE, Vec3::ONE, Vec3::Y);
+        assert_eq!(degenerate.clamp_radius(1.0, 4.0), degenerate);
+    }
+
+    #[test]
+    fn test_distance_to_plane() {
+        let transform = LookTransform::new(Vec3::new(1.0, 5.0, 2.0), Vec3::ZERO, Vec3::Y);
+
+        let floor = (Vec3::Y, Vec3::new(7.0, 1.0, -3.0));
+        assert_relative_eq!(transform.distance_to_plane(floor.0, floor.1), 4.0);
+        assert_eq!(
+            transform.project_eye_onto_plane(floor.0, floor.1),
+            Vec3::new(1.0, 1.0, 2.0)
+        );
+
+        // A wall at x = 3, facing away from the eye and with an unnormalized normal.
+        let wall = (Vec3::new(2.0, 0.0, 0.0), Vec3::new(3.0, 0.0, 0.0));
+        assert_relative_eq!(transform.distance_to_plane(wall.0, wall.1), -2.0);
+        assert_eq!(
+            transform.project_eye_onto_plane(wall.0, wall.1),
+            Vec3::new(3.0, 5.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_project_look_onto_plane() {
+        let transform =
+            LookTransform::new(Vec3::new(0.0, 3.0, 0.0), Vec3::new(0.0, 0.0, -4.0), Vec3::Y);
+        let flat = transform.project_look_onto_plane(2.0 * Vec3::Y);
+        assert_eq!(flat.eye, transform.eye);
+        assert!(flat.target.abs_diff_eq(Vec3::new(0.0, 3.0, -5.0), 1e-5));
+
+        let down = LookTransform::new(Vec3::new(0.0, 3.0, 0.0), Vec3::ZERO, Vec3::Z);
+        assert_eq!(down.project_look_onto_plane(Vec3::Y), down);
+    }
+
+    #[test]
+    fn test_billboards_face_eye() {
+        let camera = LookTransform::new(Vec3::new(3.0, 4.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let object = Vec3::new(3.0, 0.0, 1.0);
+
+        let full = camera.billboard_full(object);
+        assert_eq!(full.translation, object);
+        let back = full.back();
+        let to_eye = (camera.eye - object).normalize();
+        assert_relative_eq!(back.x, to_eye.x, epsilon = 1e-5);
+        assert_relative_eq!(back.y, to_eye.y, epsilon = 1e-5);
+        assert_relative_eq!(back.z, to_eye.z, epsilon = 1e-5);
+
+        let upright = camera.billboard_y_axis(object);
+        assert_relative_eq!(upright.up().y, 1.0, epsilon = 1e-5);
+        assert_relative_eq!(upright.back().z, 1.0, epsilon = 1e-5);
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_screen_to_world_ray() {
+        let camera = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let projection = PerspectiveProjection {
+            fov: std::f32::consts::FRAC_PI_2,
+            aspect_ratio: 2.0,
+            ..Default::default()
+        };
+
+        let center = camera.screen_to_world_ray(Vec2::ZERO, &projection);
+        assert_eq!(center.origin, camera.eye);
+        assert_relative_eq!(center.direction.z, -1.0, epsilon = 1e-5);
+
+        // With a 90 degree vertical field of view, the top right corner is up 1 and right 2 per unit forward.
+        let corner = camera.screen_to_world_ray(Vec2::ONE, &projection);
+        let expected = Vec3::new(2.0, 1.0, -1.0).normalize();
+        assert_relative_eq!(corner.direction.x, expected.x, epsilon = 1e-5);
+        assert_relative_eq!(corner.direction.y, expected.y, epsilon = 1e-5);
+        assert_relative_eq!(corner.direction.z, expected.z, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_to_view_matrix() {
+        let camera =
+            LookTransform::new(Vec3::new(1.0, 2.0, 5.0), Vec3::new(1.0, 2.0, 0.0), Vec3::Y);
+        let view = camera.to_view_matrix();
+
+        // The eye is the view space origin, looking down -Z.
+        assert!(view
+            .transform_point3(camera.eye)
+            .abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(view
+            .transform_point3(camera.target)
+            .abs_diff_eq(Vec3::new(0.0, 0.0, -5.0), 1e-5));
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_project_point_to_screen() {
+        let camera = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let projection = PerspectiveProjection {
+            fov: std::f32::consts::FRAC_PI_2,
+            aspect_ratio: 2.0,
+            ..Default::default()
+        };
+        let viewport = UVec2::new(200, 100);
+
+        let center = camera
+            .project_point_to_screen(Vec3::ZERO, viewport, &projection)
+            .unwrap();
+        assert_relative_eq!(center.x, 100.0, epsilon = 1e-3);
+        assert_relative_eq!(center.y, 50.0, epsilon = 1e-3);
+
+        // The inverse of `test_screen_to_world_ray`: up 1 and right 2 per unit forward is the top right corner.
+        let corner = camera
+            .project_point_to_screen(Vec3::new(2.0, 1.0, 4.0), viewport, &projection)
+            .unwrap();
+        assert_relative_eq!(corner.x, 200.0, epsilon = 1e-3);
+        assert_relative_eq!(corner.y, 0.0, epsilon = 1e-3);
+
+        assert_eq!(
+            camera.project_point_to_screen(Vec3::new(0.0, 0.0, 6.0), viewport, &projection),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_transform_round_trip() {
+        let look = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.0, 5.0), Vec3::Y);
+        let transform = Transform::from(look);
+
+        let back = LookTransform::from_transform(&transform, look.radius());
+        assert_relative_eq!(back.target.x, look.target.x, epsilon = 1e-5);
+        assert_relative_eq!(back.target.y, look.target.y, epsilon = 1e-5);
+        assert_relative_eq!(back.target.z, look.target.z, epsilon = 1e-5);
+
+        let unit = LookTransform::from(transform);
+        assert_relative_eq!(unit.radius(), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_camera_frame_matches_transform() {
+        let look = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.0, 5.0), Vec3::Y);
+        let transform = Transform::from(look);
+
+        let right = look.right().unwrap();
+        let local_up = look.local_up().unwrap();
+        assert_relative_eq!(right.dot(transform.right()), 1.0, epsilon = 1e-5);
+        assert_relative_eq!(local_up.dot(transform.up()), 1.0, epsilon = 1e-5);
+
+        let looking_up = LookTransform::new(Vec3::ZERO, Vec3::Y, Vec3::Y);
+        assert_eq!(looking_up.right(), None);
+    }
+
+    #[test]
+    fn test_plugin_registers_reflected_types() {
+        let mut app = App::new();
+        app.add_plugins(LookTransformPlugin::default());
+
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        assert!(registry
+            .get(std::any::TypeId::of::<LookTransform>())
+            .is_some());
+        assert!(registry.get(std::any::TypeId::of::<Smoother>()).is_some());
+        assert!(registry
+            .get(std::any::TypeId::of::<SpringSmoother>())
+            .is_some());
+    }
+
+    #[test]
+    fn test_global_transform_updated_in_same_frame() {
+        use bevy::transform::{TransformBundle, TransformPlugin};
+
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_millis(16));
+        app.insert_resource(time)
+            .add_plugins((TransformPlugin, LookTransformPlugin::default()));
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y),
+                    smoother: Smoother::new(0.0),
+                },
+                TransformBundle::default(),
+            ))
+            .id();
+        app.update();
+
+        app.world.get_mut::<LookTransform>(camera).unwrap().eye = Vec3::X;
+        app.update();
+        let global = app.world.get::<GlobalTransform>(camera).unwrap();
+        assert_eq!(global.translation(), Vec3::X);
+    }
+
+    #[test]
+    fn test_sync_in_user_sets() {
+        #[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        enum GameplaySet {
+            Paused,
+            Moving,
+        }
+
+        let spawn_camera = |app: &mut App| {
+            app.world
+                .spawn((
+                    LookTransformBundle {
+                        transform: LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y),
+                        smoother: Smoother::new(0.0),
+                    },
+                    Transform::default(),
+                ))
+                .id()
+        };
+
+        // Nothing is written while the containing set doesn't run.
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .configure_sets(PostUpdate, GameplaySet::Paused.run_if(|| false))
+            .add_plugins(LookTransformPlugin::default().in_set(GameplaySet::Paused));
+        let camera = spawn_camera(&mut app);
+        app.update();
+        assert_eq!(
+            *app.world.get::<Transform>(camera).unwrap(),
+            Transform::default()
+        );
+
+        // Changes made in an earlier set of `PostUpdate` reach the `Transform` in the same frame.
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(
+                PostUpdate,
+                (|mut cameras: Query<&mut LookTransform>| cameras.single_mut().eye = Vec3::X)
+                    .in_set(GameplaySet::Moving),
+            )
+            .add_plugins(LookTransformPlugin::default().after(GameplaySet::Moving));
+        let camera = spawn_camera(&mut app);
+        app.update();
+        assert_eq!(
+            app.world.get::<Transform>(camera).unwrap().translation,
+            Vec3::X
+        );
+    }
+
+    #[test]
+    fn test_lean_sync_without_smoother() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::lean());
+        let look = LookTransform::new(Vec3::X, Vec3::NEG_Z, Vec3::Y);
+        let camera = app.world.spawn((look, Transform::default())).id();
+        app.update();
+
+        assert_eq!(
+            *app.world.get::<Transform>(camera).unwrap(),
+            Transform::from(look)
+        );
+    }
+
+    #[test]
+    fn test_lean_sync_skips_drift() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::lean());
+        let look = LookTransform::new(Vec3::X, Vec3::NEG_Z, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((
+                MinimalLookTransformBundle::new(look),
+                Transform::default(),
+                ProceduralDrift::default(),
+            ))
+            .id();
+        for _ in 0..10 {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.1));
+            app.update();
+        }
+
+        assert_eq!(
+            *app.world.get::<Transform>(camera).unwrap(),
+            Transform::from(look)
+        );
+    }
+
+    #[test]
+    fn test_disable_auto_sync() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::new(LookTransformPluginConfig {
+                auto_sync: false,
+                ..Default::default()
+            }));
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: LookTransform::new(Vec3::X, Vec3::NEG_Z, Vec3::Y),
+                    smoother: Smoother::new(0.0),
+                },
+                Transform::default(),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(
+            *app.world.get::<Transform>(camera).unwrap(),
+            Transform::default()
+        );
+    }
+
+    #[test]
+    fn test_pitch_and_yaw() {
+        let forward = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        assert_relative_eq!(forward.pitch(), 0.0);
+        assert_relative_eq!(forward.yaw(), 0.0);
+
+        let left_and_up =
+            LookTransform::new(Vec3::ONE, Vec3::ONE + Vec3::new(-1.0, 1.0, 0.0), Vec3::Y);
+        assert_relative_eq!(
+            left_and_up.pitch(),
+            std::f32::consts::FRAC_PI_4,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            left_and_up.yaw(),
+            std::f32::consts::FRAC_PI_2,
+            epsilon = 1e-5
+        );
+
+        let degenerate = LookTransform::new(Vec3::ONE, Vec3::ONE, Vec3::Y);
+        assert_eq!(degenerate.pitch(), 0.0);
+        assert_eq!(degenerate.yaw(), 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let look = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.0, 5.0), Vec3::Y);
+        let text = ron::to_string(&look).unwrap();
+        assert_eq!(ron::from_str::<LookTransform>(&text).unwrap(), look);
+
+        let mut smoother = Smoother::new_independent(0.5, 0.25);
+        smoother.smooth_transform(&look, 1.0 / 60.0);
+        let text = ron::to_string(&smoother).unwrap();
+        let loaded: Smoother = ron::from_str(&text).unwrap();
+        assert_eq!(loaded.eye_lag_weight, 0.5);
+        assert_eq!(loaded.target_lag_weight, 0.25);
+        assert_eq!(loaded.lerp_tfm, None);
+    }
+
+    #[test]
+    fn test_critically_damped_spring_settles_without_overshoot() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::X, Vec3::X + Vec3::Z, Vec3::Y);
+
+        let mut spring = SpringSmoother::critically_damped(100.0);
+        spring.smooth_transform(&start, 1.0 / 60.0);
+        let mut tfm = start;
+        for _ in 0..300 {
+            tfm = spring.smooth_transform(&goal, 1.0 / 60.0);
+            assert!(tfm.eye.x <= goal.eye.x + 1e-3);
+        }
+
+        assert_relative_eq!(tfm.eye.x, goal.eye.x, epsilon = 1e-3);
+        assert_relative_eq!(tfm.target.x, goal.target.x, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_spring_stays_bounded_on_long_frames() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let goal = LookTransform::new(Vec3::X, Vec3::X + Vec3::Z, Vec3::Y);
+
+        let mut spring = SpringSmoother::critically_damped(100.0);
+        spring.smooth_transform(&start, 0.25);
+        for _ in 0..20 {
+            let tfm = spring.smooth_transform(&goal, 0.25);
+            assert!(tfm.eye.x.abs() <= 2.0);
+            assert!(tfm.target.x.abs() <= 2.0);
+        }
+    }
+
+    #[test]
+    fn test_fixed_plugin_syncs_in_fixed_update() {
+        let mut app = App::new();
+        app.add_plugins(LookTransformFixedPlugin::default())
+            .init_resource::<Time>();
+        let look = LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: look,
+                    smoother: Smoother::new(0.5),
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        app.world.run_schedule(FixedUpdate);
+        assert_eq!(
+            app.world.get::<Transform>(camera),
+            Some(&Transform::from(look))
+        );
+    }
+
+    #[test]
+    fn test_fixed_plugin_sync_in_user_sets() {
+        #[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        struct PhysicsSet;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .configure_sets(FixedUpdate, PhysicsSet.run_if(|| false))
+            .add_plugins(LookTransformFixedPlugin::default().in_set(PhysicsSet));
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y),
+                    smoother: Smoother::new(0.0),
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        app.world.run_schedule(FixedUpdate);
+        assert_eq!(
+            *app.world.get::<Transform>(camera).unwrap(),
+            Transform::default()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sync_plugins_are_exclusive() {
+        App::new().add_plugins((
+            LookTransformPlugin::default(),
+            LookTransformFixedPlugin::default(),
+        ));
+    }
+
+    #[test]
+    fn test_moved_events_skip_noise() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<LookTransformMovedEpsilon>()
+            .add_event::<LookTransformMoved>()
+            .add_systems(Update, look_transform_system);
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: LookTransform::default(),
+                    smoother: Smoother::new(0.0),
+                },
+                Transform::default(),
+            ))
+            .id();
+        let moved_events = |app: &mut App| {
+            let events = app.world.resource::<Events<LookTransformMoved>>();
+            let moved: Vec<_> = events.get_reader().read(events).copied().collect();
+            app.world
+                .resource_mut::<Events<LookTransformMoved>>()
+                .clear();
+            moved
+        };
+
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        };
+
+        step(&mut app);
+        app.world.get_mut::<LookTransform>(camera).unwrap().eye.x += 1e-7;
+        step(&mut app);
+        assert!(moved_events(&mut app).is_empty());
+
+        app.world.get_mut::<LookTransform>(camera).unwrap().eye.x += 1.0;
+        step(&mut app);
+        let moved = moved_events(&mut app);
+        assert_eq!(moved.len(), 1);
+        assert_eq!(moved[0].entity, camera);
+        assert_relative_eq!(moved[0].new.eye.x - moved[0].old.eye.x, 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_syncs_many_cameras() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::default());
+        let look = |i: usize, dy: f32| {
+            let eye = Vec3::new(i as f32, dy, 0.0);
+            LookTransform::new(eye, eye + Vec3::NEG_Z, Vec3::Y)
+        };
+        let cameras: Vec<_> = (0..500)
+            .map(|i| {
+                app.world
+                    .spawn((
+                        LookTransformBundle {
+                            transform: look(i, 0.0),
+                            smoother: Smoother::new(0.0),
+                        },
+                        Transform::default(),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.update();
+        for (i, &camera) in cameras.iter().enumerate() {
+            let transform = app.world.get::<Transform>(camera).unwrap();
+            assert_eq!(transform.translation, look(i, 0.0).eye);
+        }
+
+        // Only the cameras that moved send events.
+        for (i, &camera) in cameras.iter().enumerate().step_by(2) {
+            *app.world.get_mut::<LookTransform>(camera).unwrap() = look(i, 1.0);
+        }
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+        app.update();
+        let events = app.world.resource::<Events<LookTransformMoved>>();
+        let moved: Vec<_> = events.get_reader().read(events).map(|e| e.entity).collect();
+        assert_eq!(moved.len(), 250);
+        assert!(moved
+            .iter()
+            .all(|e| cameras.iter().step_by(2).any(|c| c == e)));
+        for (i, &camera) in cameras.iter().enumerate() {
+            let transform = app.world.get::<Transform>(camera).unwrap();
+            assert_eq!(
+                transform.translation,
+                look(i, (i % 2 == 0) as u8 as f32).eye
+            );
+        }
     }
 }
@@ -0,0 +1,93 @@
+use crate::{LookTransform, Smoother, SpringSmoother};
+
+use bevy::{
+    ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect, transform::components::Transform,
+};
+
+/// Replaces the camera's `LookTransform` with one matching its `Transform` when it's spawned, e.g. for a camera placed
+/// in an editor, so the default `LookTransform` doesn't move it on the first frame.
+///
+/// [`match_transform_on_start_system`] reconstructs the `LookTransform` with `LookTransform::from_transform`, putting the
+/// `target` `radius` units in front of the camera, resets any smoother so it starts there, and removes this component.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct MatchTransformOnStart {
+    pub radius: f32,
+}
+
+impl Default for MatchTransformOnStart {
+    fn default() -> Self {
+        Self { radius: 5.0 }
+    }
+}
+
+/// Runs in `PreUpdate`, so controllers start from the matched `LookTransform` on the camera's first frame.
+#[allow(clippy::type_complexity)]
+pub fn match_transform_on_start_system(
+    mut commands: Commands,
+    mut cameras: Query<(
+        Entity,
+        &MatchTransformOnStart,
+        &Transform,
+        &mut LookTransform,
+        Option<&mut Smoother>,
+        Option<&mut SpringSmoother>,
+    )>,
+) {
+    for (entity, matching, transform, mut look_transform, smoother, spring) in cameras.iter_mut() {
+        *look_transform = LookTransform::from_transform(transform, matching.radius);
+        if let Some(mut smoother) = smoother {
+            smoother.reset();
+        }
+        if let Some(mut spring) = spring {
+            spring.reset();
+        }
+        commands.entity(entity).remove::<MatchTransformOnStart>();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{LookTransformBundle, LookTransformPlugin};
+
+    use bevy::{app::prelude::*, math::prelude::*, time::Time};
+
+    #[test]
+    fn test_keeps_placed_transform() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::default());
+        let placed =
+            Transform::from_xyz(3.0, 4.0, 5.0).looking_at(Vec3::new(3.0, 0.0, 0.0), Vec3::Y);
+        let camera = app
+            .world
+            .spawn((
+                LookTransformBundle {
+                    transform: LookTransform::default(),
+                    smoother: Smoother::new(0.9),
+                },
+                placed,
+                MatchTransformOnStart { radius: 2.0 },
+            ))
+            .id();
+        app.update();
+
+        assert!(app.world.get::<MatchTransformOnStart>(camera).is_none());
+        let look_transform = app.world.get::<LookTransform>(camera).unwrap();
+        assert_eq!(look_transform.eye, placed.translation);
+        assert!((look_transform.radius() - 2.0).abs() < 1e-5);
+        let transform = app.world.get::<Transform>(camera).unwrap();
+        assert!(transform.translation.abs_diff_eq(placed.translation, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(placed.rotation, 1e-5));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::LookTransform;
+
+use bevy::{ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect};
+
+/// Asks the `LookTransformPlugin` to keep a [`PreviousLookTransform`] on this camera, e.g. for a motion blur pass.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct MotionBlurHint;
+
+/// The camera's `LookTransform` as of the end of the previous frame, for computing per-camera motion vectors.
+///
+/// Inserted and updated by [`record_previous_look_transform_system`] on cameras with a [`MotionBlurHint`]. On the first
+/// frame, it's the same as the current `LookTransform`.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct PreviousLookTransform(pub LookTransform);
+
+/// Runs in `PreUpdate`, before anything moves the cameras this frame.
+pub fn record_previous_look_transform_system(
+    mut commands: Commands,
+    mut cameras: Query<
+        (Entity, &LookTransform, Option<&mut PreviousLookTransform>),
+        With<MotionBlurHint>,
+    >,
+) {
+    for (entity, transform, previous) in cameras.iter_mut() {
+        match previous {
+            Some(mut previous) => previous.0 = *transform,
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(PreviousLookTransform(*transform));
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::LookTransformPlugin;
+
+    use bevy::{app::prelude::*, math::prelude::*, time::Time};
+
+    #[test]
+    fn test_previous_look_transform_lags_a_frame() {
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(LookTransformPlugin::default());
+        let camera = app.world.spawn((start, MotionBlurHint)).id();
+        let plain = app.world.spawn(start).id();
+
+        app.update();
+        let previous = |app: &App| app.world.get::<PreviousLookTransform>(camera).unwrap().0;
+        assert_eq!(previous(&app), start);
+        assert!(app.world.get::<PreviousLookTransform>(plain).is_none());
+
+        let moved = LookTransform::new(Vec3::new(1.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        *app.world.get_mut::<LookTransform>(camera).unwrap() = moved;
+        assert_eq!(previous(&app), start);
+        app.update();
+        assert_eq!(previous(&app), moved);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::LookTransform;
+
+use bevy::{ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect};
+
+/// A camera's orientation as angles in radians, e.g. for a controller to accumulate input in and clamp, or for a UI to
+/// show. The [`FpsCameraController`](crate::controllers::fps::FpsCameraController) keeps one on its camera as its own
+/// state, and writes it to the `LookTransform` with [`LookTransform::from_pitch_yaw_roll`].
+///
+/// The `pitch` and `yaw` are relative to `Vec3::Y`, with the same conventions as [`LookTransform::pitch`] and
+/// [`LookTransform::yaw`]. The `roll` turns `up` around the look direction, with a positive `roll` tilting it toward the
+/// camera's right.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct PitchYawRoll {
+    pub pitch: f32,
+    pub yaw: f32,
+    pub roll: f32,
+}
+
+impl PitchYawRoll {
+    pub fn new(pitch: f32, yaw: f32, roll: f32) -> Self {
+        Self { pitch, yaw, roll }
+    }
+
+    /// The angles of `transform`. All zero if `eye == target`, and the `roll` is zero when looking along `Vec3::Y`.
+    pub fn from_look_transform(transform: &LookTransform) -> Self {
+        let Some(dir) = transform.look_direction() else {
+            return Self::default();
+        };
+        let unrolled = LookTransform {
+            up: Vec3::Y,
+            ..*transform
+        };
+        let roll = match (
+            Vec3::Y.reject_from_normalized(dir).try_normalize(),
+            transform.up.reject_from_normalized(dir).try_normalize(),
+        ) {
+            (Some(level), Some(up)) => level.cross(up).dot(dir).atan2(level.dot(up)),
+            _ => 0.0,
+        };
+
+        Self::new(unrolled.pitch(), unrolled.yaw(), roll)
+    }
+
+    pub fn look_direction(&self) -> Vec3 {
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+
+        Quat::from_rotation_y(self.yaw) * Vec3::new(0.0, sin_pitch, -cos_pitch)
+    }
+
+    /// `Vec3::Y` turned around the look direction by the `roll`.
+    pub fn up(&self) -> Vec3 {
+        Quat::from_axis_angle(self.look_direction(), self.roll) * Vec3::Y
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_round_trips_through_look_transform() {
+        let transform =
+            LookTransform::from_pitch_yaw_roll(Vec3::ONE, 0.3, 0.5 * std::f32::consts::PI, 0.2);
+        assert!(transform
+            .look_direction()
+            .unwrap()
+            .abs_diff_eq(Vec3::new(-0.3f32.cos(), 0.3f32.sin(), 0.0), 1e-5));
+        // Rolling right tilts `up` toward the camera's right.
+        let level_right = transform.look_direction().unwrap().cross(Vec3::Y);
+        assert!(transform.up.dot(level_right) > 0.0);
+
+        let angles = PitchYawRoll::from_look_transform(&transform);
+        assert_relative_eq!(angles.pitch, 0.3, epsilon = 1e-5);
+        assert_relative_eq!(angles.yaw, 0.5 * std::f32::consts::PI, epsilon = 1e-5);
+        assert_relative_eq!(angles.roll, 0.2, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{look_transform::is_synced, FollowExactly, LookTransform, Smoother, SpringSmoother};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+    transform::components::Transform,
+};
+
+/// Banks the camera into sideways movement, like a plane or a car taking a turn.
+///
+/// The lateral speed of the `eye`, i.e. along the camera's right axis, is scaled by `roll_weight` to get a roll angle,
+/// at most `max_angle` radians, which `current_roll` approaches exponentially at `roll_speed`. Like
+/// [`HeadBob`](crate::HeadBob), the roll rotates the scene graph `Transform` around the look direction after smoothing,
+/// so the `LookTransform::up` itself is never disturbed. A positive roll tilts the up vector to the right.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct SmoothRoll {
+    /// The largest roll, in radians, in either direction.
+    pub max_angle: f32,
+    /// How quickly `current_roll` follows the movement, per second. Higher is snappier.
+    pub roll_speed: f32,
+    /// Radians of roll per unit per second of lateral speed.
+    pub roll_weight: f32,
+    pub current_roll: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_eye: Option<Vec3>,
+}
+
+impl Default for SmoothRoll {
+    fn default() -> Self {
+        Self::new(0.3, 4.0)
+    }
+}
+
+impl SmoothRoll {
+    pub fn new(max_angle: f32, roll_speed: f32) -> Self {
+        Self {
+            max_angle,
+            roll_speed,
+            roll_weight: 0.05,
+            current_roll: 0.0,
+            last_eye: None,
+        }
+    }
+
+    /// Advances the roll by `dt` seconds for a camera at `transform`, and returns the new `current_roll`.
+    pub fn update(&mut self, transform: &LookTransform, dt: f32) -> f32 {
+        let last_eye = self.last_eye.replace(transform.eye);
+        let (Some(last_eye), Some(look_direction)) = (last_eye, transform.look_direction()) else {
+            return self.current_roll;
+        };
+        if dt <= 0.0 {
+            return self.current_roll;
+        }
+
+        let right = look_direction.cross(transform.up).normalize_or_zero();
+        let lateral_speed = (transform.eye - last_eye).dot(right) / dt;
+        let goal = (self.roll_weight * lateral_speed).clamp(-self.max_angle, self.max_angle);
+        self.current_roll += (goal - self.current_roll) * (1.0 - (-self.roll_speed * dt).exp());
+
+        self.current_roll
+    }
+}
+
+/// Runs after `look_transform_system`, which rewrites the `Transform` every frame, so the roll doesn't accumulate.
+#[allow(clippy::type_complexity)]
+pub fn smooth_roll_system(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &mut SmoothRoll,
+        &LookTransform,
+        &mut Transform,
+        Option<&Smoother>,
+        Option<&SpringSmoother>,
+        Option<&FollowExactly>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (mut roll, look_transform, mut transform, smoother, spring, follow) in cameras.iter_mut() {
+        let angle = roll.update(look_transform, dt);
+
+        let synced = is_synced(smoother, spring, follow.is_some());
+        if synced && angle != 0.0 {
+            // The camera looks down its local -Z, so rolling about the look direction is a negative local Z rotation.
+            transform.rotate_local_z(-angle);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_banks_toward_lateral_movement() {
+        let mut roll = SmoothRoll {
+            roll_weight: 0.1,
+            ..SmoothRoll::new(0.2, 1000.0)
+        };
+        let mut look = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        roll.update(&look, 0.1);
+
+        // Moving right at 1 unit per second.
+        look.eye.x += 0.1;
+        look.target.x += 0.1;
+        assert_relative_eq!(roll.update(&look, 0.1), 0.1, epsilon = 1e-4);
+
+        // Fast enough to clamp.
+        look.eye.x += 1.0;
+        look.target.x += 1.0;
+        assert_relative_eq!(roll.update(&look, 0.1), 0.2, epsilon = 1e-4);
+
+        let mut transform = Transform::from(look);
+        transform.rotate_local_z(-roll.current_roll);
+        assert!(transform.up().x > 0.0);
+    }
+}
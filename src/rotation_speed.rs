@@ -0,0 +1,142 @@
+use crate::LookTransform;
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+};
+
+/// A hard cap on how fast the camera's look direction can turn, so a fast mouse flick can't whip the view around.
+///
+/// Unlike sensitivity, which scales all input, or a `Smoother`, which follows the input faithfully, only lagging, this
+/// leaves slow turns alone and limits fast ones to `max_degrees_per_second`, catching up over the next frames. It's
+/// applied to the `LookTransform` before smoothing, which feels best with a `Smoother` too.
+///
+/// The camera turns around whichever of the `eye` and `target` moved less this frame, so first-person cameras keep
+/// their `eye` and orbit cameras keep their `target`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct MaxRotationSpeed {
+    pub max_degrees_per_second: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_transform: Option<LookTransform>,
+}
+
+impl Default for MaxRotationSpeed {
+    fn default() -> Self {
+        Self::new(720.0)
+    }
+}
+
+impl MaxRotationSpeed {
+    pub fn new(max_degrees_per_second: f32) -> Self {
+        Self {
+            max_degrees_per_second,
+            last_transform: None,
+        }
+    }
+
+    /// Limits the turn from last frame's clamped transform to `transform` over `dt` seconds, and remembers the result.
+    pub fn clamp(&mut self, transform: LookTransform, dt: f32) -> LookTransform {
+        let clamped = self.clamp_from_last(transform, dt);
+        self.last_transform = Some(clamped);
+
+        clamped
+    }
+
+    fn clamp_from_last(&self, transform: LookTransform, dt: f32) -> LookTransform {
+        let Some(last) = self.last_transform else {
+            return transform;
+        };
+        let (Some(from), Some(to)) = (last.look_direction(), transform.look_direction()) else {
+            return transform;
+        };
+
+        let max_angle = self.max_degrees_per_second.max(0.0).to_radians() * dt;
+        let angle = from.angle_between(to);
+        if angle <= max_angle {
+            return transform;
+        }
+
+        let rotation = Quat::from_rotation_arc(from, to);
+        let dir = Quat::IDENTITY.slerp(rotation, max_angle / angle) * from;
+        let radius = transform.radius();
+        if transform.eye.distance(last.eye) <= transform.target.distance(last.target) {
+            LookTransform {
+                target: transform.eye + radius * dir,
+                ..transform
+            }
+        } else {
+            LookTransform {
+                eye: transform.target - radius * dir,
+                ..transform
+            }
+        }
+    }
+}
+
+pub fn clamp_rotation_speed_system(
+    time: Res<Time>,
+    mut cameras: Query<(&mut MaxRotationSpeed, &mut LookTransform)>,
+) {
+    let dt = time.delta_seconds();
+    for (mut max_speed, mut transform) in cameras.iter_mut() {
+        let clamped = max_speed.clamp(*transform, dt);
+        if clamped != *transform {
+            *transform = clamped;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_clamps_fast_turns() {
+        let mut max_speed = MaxRotationSpeed::new(90.0);
+        let start = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        assert_eq!(max_speed.clamp(start, 0.1), start);
+
+        // A slow turn is untouched.
+        let slow = LookTransform::new(
+            Vec3::ZERO,
+            Quat::from_rotation_y(0.1) * Vec3::NEG_Z,
+            Vec3::Y,
+        );
+        assert_eq!(max_speed.clamp(slow, 0.1), slow);
+
+        // A flick straight to the right only turns 9 degrees further in a tenth of a second, around the eye.
+        let flick = LookTransform::new(Vec3::ZERO, 2.0 * Vec3::X, Vec3::Y);
+        let clamped = max_speed.clamp(flick, 0.1);
+        assert_eq!(clamped.eye, Vec3::ZERO);
+        assert_relative_eq!(clamped.radius(), 2.0, epsilon = 1e-5);
+        assert_relative_eq!(
+            clamped
+                .look_direction()
+                .unwrap()
+                .angle_between(slow.look_direction().unwrap()),
+            9f32.to_radians(),
+            epsilon = 1e-5
+        );
+
+        // An orbit camera turns around its target instead.
+        let mut max_speed = MaxRotationSpeed::new(90.0);
+        let orbit = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        max_speed.clamp(orbit, 0.1);
+        let swung = max_speed.clamp(
+            LookTransform::new(Vec3::new(5.0, 0.0, 0.0), Vec3::ZERO, Vec3::Y),
+            0.1,
+        );
+        assert_eq!(swung.target, Vec3::ZERO);
+        assert_relative_eq!(swung.radius(), 5.0, epsilon = 1e-5);
+    }
+}
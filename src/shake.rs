@@ -0,0 +1,133 @@
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+    transform::components::Transform,
+};
+
+/// How many times per second the shake noise changes direction.
+const SHAKE_FREQUENCY: f32 = 15.0;
+
+/// Trauma-based camera shake. Call `add_trauma` on impacts; the shake strength is proportional to `trauma²` and fades as
+/// `trauma` decays.
+///
+/// The shake is applied to the scene graph `Transform` after smoothing, so the `LookTransform` itself is never disturbed.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraShake {
+    /// In `[0.0, 1.0]`.
+    pub trauma: f32,
+    /// How much `trauma` is lost per second.
+    pub decay: f32,
+    /// The largest yaw, pitch, and roll offset in radians, reached at full trauma.
+    pub max_angle: f32,
+    /// The largest translation offset along each axis, reached at full trauma.
+    pub max_offset: f32,
+    /// Cameras with different seeds shake differently.
+    pub seed: u64,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_angle: 0.1,
+            max_offset: 0.2,
+            seed: 0,
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// Applies the shake at time `t` (in seconds) to the camera's `transform`.
+    pub fn apply(&self, transform: &mut Transform, t: f32) {
+        let shake = self.trauma * self.trauma;
+        if shake <= 0.0 {
+            return;
+        }
+
+        let t = t * SHAKE_FREQUENCY;
+        let noise = |channel| value_noise(self.seed, channel, t);
+
+        let rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            self.max_angle * shake * noise(0),
+            self.max_angle * shake * noise(1),
+            self.max_angle * shake * noise(2),
+        );
+        let offset = self.max_offset * shake * Vec3::new(noise(3), noise(4), noise(5));
+
+        transform.translation += transform.rotation * offset;
+        transform.rotation *= rotation;
+    }
+}
+
+pub fn camera_shake_system(time: Res<Time>, mut cameras: Query<&mut CameraShake>) {
+    let dt = time.delta_seconds();
+    for mut shake in cameras.iter_mut() {
+        if shake.trauma > 0.0 {
+            shake.trauma = (shake.trauma - shake.decay * dt).max(0.0);
+        }
+    }
+}
+
+/// Smooth 1D noise in `[-1.0, 1.0]`, made by interpolating random values at integer lattice points.
+pub(crate) fn value_noise(seed: u64, channel: u64, t: f32) -> f32 {
+    let i = t.floor();
+    let f = t - i;
+    let i = i as i64;
+
+    let a = lattice_value(seed, channel, i);
+    let b = lattice_value(seed, channel, i + 1);
+    let smooth_f = f * f * (3.0 - 2.0 * f);
+
+    a + (b - a) * smooth_f
+}
+
+fn lattice_value(seed: u64, channel: u64, i: i64) -> f32 {
+    // SplitMix64 finalizer.
+    let mut x = seed
+        .wrapping_add(channel.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .wrapping_add((i as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9));
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+
+    (x >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_noise_is_bounded_and_continuous() {
+        let mut prev = value_noise(7, 0, 0.0);
+        for step in 1..1000 {
+            let v = value_noise(7, 0, step as f32 * 0.01);
+            assert!((-1.0..=1.0).contains(&v));
+            assert!((v - prev).abs() < 0.1);
+            prev = v;
+        }
+    }
+
+    #[test]
+    fn test_no_trauma_no_shake() {
+        let shake = CameraShake::default();
+        let mut transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        shake.apply(&mut transform, 12.3);
+
+        assert_eq!(transform, Transform::from_xyz(1.0, 2.0, 3.0));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{CameraCommand, LookTransform, LookTransformSystemSet};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, input::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+};
+
+/// Sends a [`CameraShortcut`] for each shortcut key pressed, as configured by the [`CameraShortcutConfig`] resource.
+pub struct CameraShortcutPlugin;
+
+impl Plugin for CameraShortcutPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraShortcutConfig>()
+            .init_resource::<CameraShortcutConfig>()
+            .add_event::<CameraShortcut>()
+            .add_event::<CameraCommand>()
+            .add_systems(
+                Update,
+                camera_shortcut_system.in_set(LookTransformSystemSet::LookTransformInput),
+            );
+    }
+}
+
+/// An editor-style camera operation requested from the keyboard.
+///
+/// Only `Reset` has a [`CameraCommand`] equivalent, which is sent along with it. What to frame, how to switch
+/// projections, and which camera to jump to depend on the app, so handle the others with your own systems.
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub enum CameraShortcut {
+    /// Frame the selection.
+    Frame,
+    /// Move back to [`CameraShortcutConfig::home`].
+    Reset,
+    /// Switch between perspective and orthographic projections.
+    ToggleOrthographic,
+    /// Look through the scene's camera, e.g. the game camera while in an editor view.
+    JumpToCamera,
+}
+
+/// The keys for each [`CameraShortcut`]. The defaults follow Blender.
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct CameraShortcutConfig {
+    pub frame_key: KeyCode,
+    pub reset_key: KeyCode,
+    pub toggle_ortho_key: KeyCode,
+    pub jump_to_camera_key: KeyCode,
+    /// Where `Reset` moves the cameras to.
+    pub home: LookTransform,
+    /// How many seconds the `CameraCommand::Transition` to `home` lasts.
+    pub reset_duration: f32,
+    pub enabled: bool,
+}
+
+impl Default for CameraShortcutConfig {
+    fn default() -> Self {
+        Self {
+            frame_key: KeyCode::F,
+            reset_key: KeyCode::Home,
+            toggle_ortho_key: KeyCode::Numpad5,
+            jump_to_camera_key: KeyCode::Numpad0,
+            home: LookTransform::default(),
+            reset_duration: 0.5,
+            enabled: true,
+        }
+    }
+}
+
+pub fn camera_shortcut_system(
+    config: Res<CameraShortcutConfig>,
+    keyboard: Res<Input<KeyCode>>,
+    mut shortcuts: EventWriter<CameraShortcut>,
+    mut commands: EventWriter<CameraCommand>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    for (key, shortcut) in [
+        (config.frame_key, CameraShortcut::Frame),
+        (config.reset_key, CameraShortcut::Reset),
+        (config.toggle_ortho_key, CameraShortcut::ToggleOrthographic),
+        (config.jump_to_camera_key, CameraShortcut::JumpToCamera),
+    ] {
+        if !keyboard.just_pressed(key) {
+            continue;
+        }
+        if shortcut == CameraShortcut::Reset {
+            commands.send(CameraCommand::Transition {
+                to: config.home,
+                duration: config.reset_duration,
+            });
+        }
+        shortcuts.send(shortcut);
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_send_shortcuts_and_commands() {
+        let config = CameraShortcutConfig::default();
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(config.frame_key);
+        keyboard.press(config.reset_key);
+
+        let mut app = App::new();
+        app.insert_resource(keyboard)
+            .add_plugins(CameraShortcutPlugin);
+        app.update();
+
+        let shortcuts = app.world.resource::<Events<CameraShortcut>>();
+        let sent: Vec<_> = shortcuts.get_reader().read(shortcuts).copied().collect();
+        assert_eq!(sent, [CameraShortcut::Frame, CameraShortcut::Reset]);
+
+        let commands = app.world.resource::<Events<CameraCommand>>();
+        let sent: Vec<_> = commands.get_reader().read(commands).copied().collect();
+        assert_eq!(
+            sent,
+            [CameraCommand::Transition {
+                to: config.home,
+                duration: config.reset_duration,
+            }]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{LookTransformVelocity, REFERENCE_FPS};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    render::camera::Projection, time::Time,
+};
+
+/// Smooths changes to the field of view of [`SmoothFov`] cameras, and widens it for fast [`DynamicFovController`]
+/// cameras.
+pub struct SmoothFovPlugin;
+
+impl Plugin for SmoothFovPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SmoothFov>()
+            .register_type::<DynamicFovController>()
+            .add_systems(Update, (smooth_fov_system, dynamic_fov_system).chain());
+    }
+}
+
+/// Exponentially smooths the vertical field of view of a camera with a perspective `Projection`, e.g. for zooming by
+/// narrowing the view instead of moving the `eye`. Set `target_fov` and the projection follows.
+///
+/// This only touches the `Projection`, so it can be used alongside a `LookTransformBundle` and any controller.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct SmoothFov {
+    /// Radians.
+    pub target_fov: f32,
+    /// Radians.
+    pub current_fov: f32,
+    /// Between `0.0` and `1.0`, where higher is smoother, applied the same way as a `Smoother`'s lag weight.
+    pub lag_weight: f32,
+}
+
+impl Default for SmoothFov {
+    fn default() -> Self {
+        Self::new(std::f32::consts::FRAC_PI_4, 0.9)
+    }
+}
+
+impl SmoothFov {
+    pub fn new(initial_fov: f32, lag_weight: f32) -> Self {
+        Self {
+            target_fov: initial_fov,
+            current_fov: initial_fov,
+            lag_weight,
+        }
+    }
+
+    /// Moves `current_fov` toward `target_fov` by a frame of `dt` seconds and returns it.
+    pub fn update(&mut self, dt: f32) -> f32 {
+        let lag_weight = self.lag_weight.powf(dt * REFERENCE_FPS);
+        self.current_fov = self.target_fov + (self.current_fov - self.target_fov) * lag_weight;
+        self.current_fov
+    }
+}
+
+pub fn smooth_fov_system(time: Res<Time>, mut cameras: Query<(&mut SmoothFov, &mut Projection)>) {
+    let dt = time.delta_seconds();
+    for (mut fov, mut projection) in cameras.iter_mut() {
+        let new_fov = fov.update(dt);
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = new_fov;
+        }
+    }
+}
+
+/// Widens the vertical field of view of a camera with a perspective `Projection` as its `eye` moves faster, for a sense
+/// of speed in racing games and shooters. The camera also needs a [`LookTransformVelocity`].
+///
+/// The target field of view rises linearly from `base_fov` at rest to `max_fov` at `1.0 / speed_scale` units per second,
+/// and the field of view follows it with the same smoothing as a [`SmoothFov`]. With a `SmoothFov` on the same camera,
+/// the widening is added on top of the `SmoothFov`'s field of view instead of `base_fov`, so user-driven zooms still
+/// work while moving.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct DynamicFovController {
+    /// Radians.
+    pub base_fov: f32,
+    /// Radians.
+    pub max_fov: f32,
+    /// Per unit per second of `eye` speed.
+    pub speed_scale: f32,
+    /// Between `0.0` and `1.0`, where higher is smoother, applied the same way as a `Smoother`'s lag weight.
+    pub smoothing: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    widening: f32,
+}
+
+impl Default for DynamicFovController {
+    fn default() -> Self {
+        Self::new(
+            std::f32::consts::FRAC_PI_4,
+            std::f32::consts::FRAC_PI_3,
+            0.05,
+            0.9,
+        )
+    }
+}
+
+impl DynamicFovController {
+    pub fn new(base_fov: f32, max_fov: f32, speed_scale: f32, smoothing: f32) -> Self {
+        Self {
+            base_fov,
+            max_fov,
+            speed_scale,
+            smoothing,
+            widening: 0.0,
+        }
+    }
+
+    /// How much wider than its base the field of view currently is, in radians.
+    pub fn widening(&self) -> f32 {
+        self.widening
+    }
+
+    /// Moves the widening toward the target for `speed` by a frame of `dt` seconds and returns it.
+    pub fn update(&mut self, speed: f32, dt: f32) -> f32 {
+        let t = (speed * self.speed_scale).clamp(0.0, 1.0);
+        let target = t * (self.max_fov - self.base_fov);
+        let lag_weight = self.smoothing.powf(dt * REFERENCE_FPS);
+        self.widening = target + (self.widening - target) * lag_weight;
+        self.widening
+    }
+}
+
+pub fn dynamic_fov_system(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &mut DynamicFovController,
+        &LookTransformVelocity,
+        Option<&SmoothFov>,
+        &mut Projection,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (mut controller, velocity, smooth_fov, mut projection) in cameras.iter_mut() {
+        let widening = controller.update(velocity.eye_velocity.length(), dt);
+        let base_fov = smooth_fov.map_or(controller.base_fov, |fov| fov.current_fov);
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = base_fov + widening;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_update_is_frame_rate_independent() {
+        let mut at_60 = SmoothFov::new(1.0, 0.5);
+        at_60.target_fov = 0.5;
+        let mut at_30 = at_60;
+
+        at_60.update(1.0 / 60.0);
+        at_60.update(1.0 / 60.0);
+        at_30.update(1.0 / 30.0);
+
+        assert_relative_eq!(at_60.current_fov, 0.625, epsilon = 1e-5);
+        assert_relative_eq!(at_30.current_fov, at_60.current_fov, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_dynamic_fov_widens_with_speed() {
+        let mut controller = DynamicFovController::new(1.0, 1.5, 0.1, 0.0);
+        assert_relative_eq!(controller.update(5.0, 1.0 / 60.0), 0.25);
+        assert_relative_eq!(controller.update(100.0, 1.0 / 60.0), 0.5);
+        assert_relative_eq!(controller.update(0.0, 1.0 / 60.0), 0.0);
+
+        controller.smoothing = 0.5;
+        assert_relative_eq!(controller.update(10.0, 1.0 / 60.0), 0.25);
+    }
+}
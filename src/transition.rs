@@ -0,0 +1,358 @@
+use crate::{ActiveCameras, LookTransform};
+
+use bevy::{ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time};
+
+/// Animates a camera's `LookTransform` from `from` to `to` over `duration` seconds, then removes itself.
+///
+/// While this component is present, the built-in camera controllers ignore the camera, so the transition can't be fought
+/// by user input. Control resumes as soon as the transition completes.
+///
+/// With `blend_controller`, the transition instead crossfades from the camera's controller to `to`: it hands itself off
+/// to a [`CameraControllerBlend`], which the controllers don't ignore.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraTransition {
+    pub from: LookTransform,
+    pub to: LookTransform,
+    pub duration: f32,
+    pub elapsed: f32,
+    pub easing: EasingFn,
+    /// Keep the controller running, starting from `from`, and blend what it would do toward `to` by the eased progress.
+    pub blend_controller: bool,
+}
+
+impl Default for CameraTransition {
+    fn default() -> Self {
+        Self::new(
+            LookTransform::default(),
+            LookTransform::default(),
+            1.0,
+            EasingFn::default(),
+        )
+    }
+}
+
+impl CameraTransition {
+    pub fn new(from: LookTransform, to: LookTransform, duration: f32, easing: EasingFn) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing,
+            blend_controller: false,
+        }
+    }
+
+    /// The linear progress of the transition, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// The transform at the current point in the transition.
+    pub fn current(&self) -> LookTransform {
+        self.from.lerp(self.to, self.easing.ease(self.progress()))
+    }
+}
+
+/// A [`CameraTransition`] with `blend_controller`, crossfading between the camera's controller and the transition's `to`.
+///
+/// Each frame, [`restore_controller_pose_system`] puts back the `LookTransform` the controller left last frame, so it
+/// moves the camera as though no transition were playing. [`camera_controller_blend_system`] then remembers that pose
+/// and shows a blend of it and `to` instead, weighted by the eased progress, removing this component once the camera
+/// reaches `to`. Control resumes from there.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraControllerBlend {
+    pub transition: CameraTransition,
+    /// Where the controller alone would have the camera.
+    controller: LookTransform,
+}
+
+impl CameraControllerBlend {
+    pub fn new(transition: CameraTransition) -> Self {
+        Self {
+            transition,
+            controller: transition.from,
+        }
+    }
+
+    /// Where the controller alone would have the camera.
+    pub fn controller(&self) -> LookTransform {
+        self.controller
+    }
+
+    /// The transform at the current point in the blend.
+    pub fn current(&self) -> LookTransform {
+        let t = self.transition.easing.ease(self.transition.progress());
+
+        self.controller.lerp(self.transition.to, t)
+    }
+}
+
+/// Insert this resource to animate the active camera to `to`, e.g. after spawning a new scene whose camera should be
+/// somewhere else.
+///
+/// When `from` is `None`, the camera starts from wherever it is when the resource is seen, so it can be inserted as
+/// soon as the new scene is ready without knowing the old camera position. The resource is removed once the transition
+/// starts, and the transition itself runs as a [`CameraTransition`] on the first camera in [`ActiveCameras`].
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct SceneCameraTransition {
+    pub from: Option<LookTransform>,
+    pub to: LookTransform,
+    pub duration: f32,
+    pub easing: EasingFn,
+}
+
+impl Default for SceneCameraTransition {
+    fn default() -> Self {
+        Self::new(LookTransform::default(), 1.0, EasingFn::default())
+    }
+}
+
+impl SceneCameraTransition {
+    pub fn new(to: LookTransform, duration: f32, easing: EasingFn) -> Self {
+        Self {
+            from: None,
+            to,
+            duration,
+            easing,
+        }
+    }
+}
+
+/// Maps linear progress in `[0.0, 1.0]` to eased progress in `[0.0, 1.0]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum EasingFn {
+    #[default]
+    Linear,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+}
+
+impl EasingFn {
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EasingFn::Linear => t,
+            EasingFn::EaseInCubic => t * t * t,
+            EasingFn::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            EasingFn::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+pub fn camera_transition_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut CameraTransition, &mut LookTransform)>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut transition, mut transform) in cameras.iter_mut() {
+        if transition.blend_controller {
+            *transform = transition.from;
+            commands
+                .entity(entity)
+                .remove::<CameraTransition>()
+                .insert(CameraControllerBlend::new(*transition));
+            continue;
+        }
+
+        transition.elapsed += dt;
+        *transform = transition.current();
+
+        if transition.is_finished() {
+            commands.entity(entity).remove::<CameraTransition>();
+        }
+    }
+}
+
+/// Runs in `Update`, before `LookTransformSystemSet::LookTransformInput`.
+pub fn restore_controller_pose_system(
+    mut cameras: Query<(&CameraControllerBlend, &mut LookTransform)>,
+) {
+    for (blend, mut transform) in cameras.iter_mut() {
+        *transform = blend.controller;
+    }
+}
+
+pub fn camera_controller_blend_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut CameraControllerBlend, &mut LookTransform)>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut blend, mut transform) in cameras.iter_mut() {
+        blend.controller = *transform;
+        blend.transition.elapsed += dt;
+        *transform = blend.current();
+
+        if blend.transition.is_finished() {
+            commands.entity(entity).remove::<CameraControllerBlend>();
+        }
+    }
+}
+
+/// Starts the [`SceneCameraTransition`], if there is one.
+pub fn scene_camera_transition_system(
+    mut commands: Commands,
+    transition: Option<Res<SceneCameraTransition>>,
+    cameras: Query<(Entity, &LookTransform, Option<&CameraTransition>)>,
+    active_cameras: ActiveCameras,
+) {
+    let Some(transition) = transition else {
+        return;
+    };
+    let Some((entity, transform, current)) = cameras
+        .iter()
+        .find(|(entity, ..)| active_cameras.contains(*entity))
+    else {
+        return;
+    };
+
+    // Like `CameraCommand::Transition`, interrupting a transition starts from wherever it had got to.
+    let from = transition
+        .from
+        .unwrap_or_else(|| current.map_or(*transform, CameraTransition::current));
+    commands.entity(entity).insert(CameraTransition::new(
+        from,
+        transition.to,
+        transition.duration,
+        transition.easing,
+    ));
+    commands.remove_resource::<SceneCameraTransition>();
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_easing_endpoints() {
+        for easing in [
+            EasingFn::Linear,
+            EasingFn::EaseInCubic,
+            EasingFn::EaseOutCubic,
+            EasingFn::EaseInOutCubic,
+        ] {
+            assert_relative_eq!(easing.ease(0.0), 0.0);
+            assert_relative_eq!(easing.ease(1.0), 1.0);
+        }
+        assert_relative_eq!(EasingFn::EaseInOutCubic.ease(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_scene_transition_starts_from_current_position() {
+        use bevy::{app::prelude::*, math::prelude::*};
+
+        let mut app = App::new();
+        app.add_systems(Update, scene_camera_transition_system);
+        let start = LookTransform::new(Vec3::ONE, Vec3::ZERO, Vec3::Y);
+        let camera = app.world.spawn(start).id();
+        app.update();
+        assert!(app.world.get::<CameraTransition>(camera).is_none());
+
+        let to = LookTransform::new(Vec3::new(0.0, 10.0, 10.0), Vec3::ZERO, Vec3::Y);
+        app.insert_resource(SceneCameraTransition::new(
+            to,
+            2.0,
+            EasingFn::EaseInOutCubic,
+        ));
+        app.update();
+
+        let transition = app.world.get::<CameraTransition>(camera).unwrap();
+        assert_eq!(transition.from, start);
+        assert_eq!(transition.to, to);
+        assert_eq!(transition.easing, EasingFn::EaseInOutCubic);
+        assert!(!app.world.contains_resource::<SceneCameraTransition>());
+    }
+
+    #[test]
+    fn test_blend_controller_crossfades_to_target() {
+        use bevy::{app::prelude::*, math::prelude::*};
+        use std::time::Duration;
+
+        // Stands in for a controller, moving the eye along X every frame.
+        fn slide_system(mut cameras: Query<&mut LookTransform, Without<CameraTransition>>) {
+            for mut transform in cameras.iter_mut() {
+                transform.eye.x += 1.0;
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Time>().add_systems(
+            Update,
+            (
+                restore_controller_pose_system,
+                slide_system,
+                camera_transition_system,
+                camera_controller_blend_system,
+            )
+                .chain(),
+        );
+        let from = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let to = LookTransform::new(Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Z, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((
+                from,
+                CameraTransition {
+                    blend_controller: true,
+                    ..CameraTransition::new(from, to, 2.0, EasingFn::Linear)
+                },
+            ))
+            .id();
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0));
+            app.update();
+            app.world.get::<LookTransform>(camera).unwrap().eye
+        };
+
+        // The transition hands off to a blend, starting from `from`.
+        assert_eq!(step(&mut app), from.eye);
+        assert!(app.world.get::<CameraTransition>(camera).is_none());
+
+        // Halfway between where the controller has moved the camera and `to`.
+        step(&mut app);
+        let blend = app.world.get::<CameraControllerBlend>(camera).unwrap();
+        assert_eq!(blend.controller().eye, Vec3::X);
+        assert!(blend
+            .current()
+            .eye
+            .abs_diff_eq(Vec3::new(0.5, 5.0, 0.0), 1e-5));
+
+        assert!(step(&mut app).abs_diff_eq(to.eye, 1e-5));
+        assert!(app.world.get::<CameraControllerBlend>(camera).is_none());
+    }
+}
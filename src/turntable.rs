@@ -0,0 +1,146 @@
+use crate::{controllers::orbit::orbit_direction, CameraCommand, LookTransform};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+};
+
+/// Spins a camera's `eye` around its `target` at a constant rate, like a turntable in a product demo or model viewer.
+///
+/// The `eye` is rewritten every frame, overriding any controller that moves it, although panning still moves the
+/// `target` it circles. The first [`CameraCommand`] stops the turntable by removing this component, so scripted camera
+/// moves take over from it.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraCircle {
+    /// In radians per second. Positive speeds turn counterclockwise, looking down the plane's normal.
+    pub angular_speed: f32,
+    pub plane: TurntablePlane,
+    pub radius: f32,
+    /// Seconds since the turntable started. At `0.0`, the `eye` is on the plane's first axis, e.g. `+Z` for `XZ`.
+    pub elapsed: f32,
+}
+
+impl Default for CameraCircle {
+    fn default() -> Self {
+        Self::new(0.5, 5.0)
+    }
+}
+
+impl CameraCircle {
+    /// Circles in the `XZ` plane.
+    pub fn new(angular_speed: f32, radius: f32) -> Self {
+        Self {
+            angular_speed,
+            plane: TurntablePlane::XZ,
+            radius,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn with_plane(mut self, plane: TurntablePlane) -> Self {
+        self.plane = plane;
+        self
+    }
+
+    /// The `eye` around `target` after `elapsed` seconds.
+    pub fn eye(&self, target: Vec3) -> Vec3 {
+        let angle = self.angular_speed * self.elapsed;
+
+        target + self.radius * orbit_direction(self.plane.normal(), 0.0, angle)
+    }
+}
+
+/// The plane a [`CameraCircle`] turns in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum TurntablePlane {
+    /// Around the `Y` axis, level with the target.
+    #[default]
+    XZ,
+    /// Around the `Z` axis.
+    XY,
+    /// Around the `X` axis.
+    YZ,
+    /// Perpendicular to the given normal, which needn't be normalized.
+    Custom(Vec3),
+}
+
+impl TurntablePlane {
+    pub fn normal(&self) -> Vec3 {
+        match *self {
+            Self::XZ => Vec3::Y,
+            Self::XY => Vec3::Z,
+            Self::YZ => Vec3::X,
+            Self::Custom(normal) => normal.try_normalize().unwrap_or(Vec3::Y),
+        }
+    }
+}
+
+pub fn camera_circle_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut camera_commands: EventReader<CameraCommand>,
+    mut cameras: Query<(Entity, &mut CameraCircle, &mut LookTransform)>,
+) {
+    let interrupted = camera_commands.read().count() > 0;
+
+    let dt = time.delta_seconds();
+    for (entity, mut circle, mut transform) in cameras.iter_mut() {
+        if interrupted {
+            commands.entity(entity).remove::<CameraCircle>();
+            continue;
+        }
+
+        circle.elapsed += dt;
+        transform.eye = circle.eye(transform.target);
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::app::prelude::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_circles_until_commanded() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<CameraCommand>()
+            .add_systems(Update, camera_circle_system);
+        let target = Vec3::new(1.0, 2.0, 3.0);
+        let camera = app
+            .world
+            .spawn((
+                CameraCircle::new(std::f32::consts::FRAC_PI_2, 2.0),
+                LookTransform::new(Vec3::ZERO, target, Vec3::Y),
+            ))
+            .id();
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0));
+            app.update();
+            app.world.get::<LookTransform>(camera).unwrap().eye
+        };
+
+        // A quarter turn per second, counterclockwise from +Z when looking down from +Y.
+        assert!(step(&mut app).abs_diff_eq(target + 2.0 * Vec3::X, 1e-5));
+        assert!(step(&mut app).abs_diff_eq(target - 2.0 * Vec3::Z, 1e-5));
+
+        app.world.send_event(CameraCommand::SetEye(Vec3::ZERO));
+        let eye = step(&mut app);
+        assert!(app.world.get::<CameraCircle>(camera).is_none());
+        assert!(eye.abs_diff_eq(target - 2.0 * Vec3::Z, 1e-5));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::LookTransform;
+
+use bevy::{
+    ecs::prelude::*, log::warn, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    transform::components::GlobalTransform, utils::HashMap,
+};
+
+/// Points the camera's `up` somewhere other than its `LookTransform` says each frame, e.g. against a gravity that changes
+/// through portals or on spherical planets.
+///
+/// [`auto_up_vector_system`] sets the `LookTransform::up` before smoothing, so a `Smoother` with an up lag weight turns
+/// the camera to the new up smoothly. If the new up is parallel to the look direction, which would leave the camera's
+/// roll undefined, the old `up` is kept.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct AutoUpVector {
+    pub source: UpVectorSource,
+}
+
+impl AutoUpVector {
+    pub fn new(source: UpVectorSource) -> Self {
+        Self { source }
+    }
+}
+
+/// Where an [`AutoUpVector`] gets its up direction from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum UpVectorSource {
+    Fixed(Vec3),
+    /// Opposite the [`WorldGravity`] resource.
+    #[default]
+    FromGravity,
+    /// The `up` of the entity's `GlobalTransform`, e.g. the player standing on a planet.
+    FromEntity(Entity),
+}
+
+/// The direction of gravity for [`UpVectorSource::FromGravity`], to be kept in step with the physics engine's. Only the
+/// direction matters.
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource, Default, Debug)]
+pub struct WorldGravity(pub Vec3);
+
+impl Default for WorldGravity {
+    fn default() -> Self {
+        Self(-9.81 * Vec3::Y)
+    }
+}
+
+pub fn auto_up_vector_system(
+    gravity: Res<WorldGravity>,
+    entities: Query<&GlobalTransform>,
+    mut cameras: Query<(Entity, &AutoUpVector, &mut LookTransform)>,
+    // The up each camera last kept its old up vector instead of, so the warning isn't repeated every frame.
+    mut rejected: Local<HashMap<Entity, Vec3>>,
+) {
+    for (entity, auto_up, mut transform) in cameras.iter_mut() {
+        let up = match auto_up.source {
+            UpVectorSource::Fixed(up) => up,
+            UpVectorSource::FromGravity => -gravity.0,
+            UpVectorSource::FromEntity(source) => match entities.get(source) {
+                Ok(global) => global.up(),
+                Err(_) => continue,
+            },
+        };
+        let Some(up) = up.try_normalize() else {
+            continue;
+        };
+        if up == transform.up {
+            continue;
+        }
+
+        if let Some(dir) = transform.look_direction() {
+            if dir.cross(up).length_squared() < 1e-6 {
+                if rejected.insert(entity, up) != Some(up) {
+                    warn!(
+                        "Camera {:?} is looking along its new up vector {}, keeping its old up vector",
+                        entity, up
+                    );
+                }
+                continue;
+            }
+        }
+        rejected.remove(&entity);
+        transform.up = up;
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::{app::prelude::*, transform::components::Transform};
+
+    #[test]
+    fn test_follows_gravity_unless_looking_along_it() {
+        let mut app = App::new();
+        app.init_resource::<WorldGravity>()
+            .add_systems(Update, auto_up_vector_system);
+        let camera = app
+            .world
+            .spawn((
+                LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y),
+                AutoUpVector::default(),
+            ))
+            .id();
+        let planet = app
+            .world
+            .spawn(GlobalTransform::from(Transform::from_rotation(
+                Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2),
+            )))
+            .id();
+
+        app.world.resource_mut::<WorldGravity>().0 = Vec3::new(2.0, 0.0, 0.0);
+        app.update();
+        let up = |app: &App| app.world.get::<LookTransform>(camera).unwrap().up;
+        assert_eq!(up(&app), Vec3::NEG_X);
+
+        // Gravity along the look direction keeps the old up.
+        app.world.resource_mut::<WorldGravity>().0 = Vec3::Z;
+        app.update();
+        assert_eq!(up(&app), Vec3::NEG_X);
+
+        app.world.get_mut::<AutoUpVector>(camera).unwrap().source =
+            UpVectorSource::FromEntity(planet);
+        app.update();
+        assert!(up(&app).abs_diff_eq(Vec3::X, 1e-6));
+        app.world.get_mut::<AutoUpVector>(camera).unwrap().source = UpVectorSource::Fixed(Vec3::Y);
+        app.update();
+        assert_eq!(up(&app), Vec3::Y);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{CameraSmoother, LookTransform, LookTransformBundle, Smoother, SpringSmoother};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect, time::Time,
+};
+
+/// How fast a camera is moving, in units per second, e.g. for Doppler effects on an audio listener or to lower the level
+/// of detail while the view is moving quickly.
+///
+/// Measured from the smoothed transform that's actually drawn, or from the `LookTransform` if the camera has no
+/// smoother. Updated by [`compute_look_transform_velocity_system`] right after the `Transform` is written. Both
+/// velocities are zero until the camera has been seen for two frames.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct LookTransformVelocity {
+    pub eye_velocity: Vec3,
+    pub target_velocity: Vec3,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_transform: Option<LookTransform>,
+}
+
+impl LookTransformVelocity {
+    /// Measures the velocity from the last call to `transform`, `dt` seconds later. Does nothing if no time has passed.
+    pub fn update(&mut self, transform: &LookTransform, dt: f32) {
+        if dt <= 0.0 {
+            return;
+        }
+        if let Some(last) = self.last_transform {
+            self.eye_velocity = (transform.eye - last.eye) / dt;
+            self.target_velocity = (transform.target - last.target) / dt;
+        }
+        self.last_transform = Some(*transform);
+    }
+}
+
+/// A [`LookTransformBundle`] that also tracks its [`LookTransformVelocity`].
+#[derive(Bundle, Clone, Default)]
+pub struct FullLookTransformBundle<S: CameraSmoother = Smoother> {
+    pub look_transform: LookTransformBundle<S>,
+    pub velocity: LookTransformVelocity,
+}
+
+pub fn compute_look_transform_velocity_system(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &mut LookTransformVelocity,
+        &LookTransform,
+        Option<&Smoother>,
+        Option<&SpringSmoother>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (mut velocity, look_transform, smoother, spring) in cameras.iter_mut() {
+        let current = smoother
+            .and_then(Smoother::current_transform)
+            .or_else(|| spring.and_then(SpringSmoother::current_transform))
+            .unwrap_or(*look_transform);
+        velocity.update(&current, dt);
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::app::prelude::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_velocity_of_smoothed_transform() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_systems(
+            Update,
+            (
+                |mut smoothers: Query<(&LookTransform, &mut Smoother)>| {
+                    for (look, mut smoother) in smoothers.iter_mut() {
+                        smoother.smooth_transform(look, 0.5);
+                    }
+                },
+                compute_look_transform_velocity_system,
+            )
+                .chain(),
+        );
+        let camera = app
+            .world
+            .spawn(FullLookTransformBundle {
+                look_transform: LookTransformBundle {
+                    transform: LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y),
+                    smoother: Smoother::new(0.0),
+                },
+                ..Default::default()
+            })
+            .id();
+        let step = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            app.update();
+            *app.world.get::<LookTransformVelocity>(camera).unwrap()
+        };
+
+        assert_eq!(step(&mut app).eye_velocity, Vec3::ZERO);
+
+        app.world.get_mut::<LookTransform>(camera).unwrap().eye = Vec3::X;
+        let velocity = step(&mut app);
+        assert_eq!(velocity.eye_velocity, Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(velocity.target_velocity, Vec3::ZERO);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    math::prelude::*,
+    render::camera::{Camera, CameraProjection, PerspectiveProjection, Projection},
+    transform::components::Transform,
+    utils::HashMap,
+};
+
+/// Keeps the [`WorldToScreen`] resource up to date.
+pub struct WorldToScreenPlugin;
+
+impl Plugin for WorldToScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldToScreen>().add_systems(
+            PostUpdate,
+            update_world_to_screen_system.after(LookTransformSystem::Sync),
+        );
+    }
+}
+
+/// How each camera with a `LookTransform` projected the world onto the screen this frame, for [`world_to_screen`], e.g.
+/// to place targeting reticles from systems that don't query the cameras.
+///
+/// [`update_world_to_screen_system`] fills it in every frame from each camera's `Transform`, so it matches where the
+/// camera is drawn, including smoothing and shake, and its `PerspectiveProjection` or `Projection`. Cameras whose
+/// viewport size isn't known yet, e.g. on the first frame, are left out.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct WorldToScreen {
+    cameras: HashMap<Entity, ScreenProjection>,
+}
+
+impl WorldToScreen {
+    pub fn get(&self, camera: Entity) -> Option<&ScreenProjection> {
+        self.cameras.get(&camera)
+    }
+}
+
+/// One camera's entry in the [`WorldToScreen`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScreenProjection {
+    /// Maps world space to clip space.
+    pub view_projection: Mat4,
+    /// The logical size of the camera's viewport, in pixels.
+    pub viewport_size: Vec2,
+}
+
+impl ScreenProjection {
+    pub fn new(
+        transform: &Transform,
+        projection: &impl CameraProjection,
+        viewport_size: Vec2,
+    ) -> Self {
+        Self {
+            view_projection: projection.get_projection_matrix()
+                * transform.compute_matrix().inverse(),
+            viewport_size,
+        }
+    }
+
+    /// Like [`LookTransform::project_point_to_screen`]: the position of `world_pos` in the viewport, with `(0, 0)` at the
+    /// top left. `None` if the point isn't in front of the camera.
+    pub fn project(&self, world_pos: Vec3) -> Option<Vec2> {
+        let clip = self.view_projection * world_pos.extend(1.0);
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = clip.truncate().truncate() / clip.w;
+        Some(Vec2::new(0.5 * (ndc.x + 1.0), 0.5 * (1.0 - ndc.y)) * self.viewport_size)
+    }
+}
+
+/// Where `world_pos` appears in the viewport of the camera `entity`, with `(0, 0)` at the top left. `None` if the point
+/// is behind the camera, or the camera isn't in the [`WorldToScreen`].
+pub fn world_to_screen(
+    entity: Entity,
+    world_pos: Vec3,
+    world_to_screen: &WorldToScreen,
+) -> Option<Vec2> {
+    world_to_screen.get(entity)?.project(world_pos)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_world_to_screen_system(
+    mut world_to_screen: ResMut<WorldToScreen>,
+    cameras: Query<
+        (
+            Entity,
+            &Camera,
+            &Transform,
+            Option<&PerspectiveProjection>,
+            Option<&Projection>,
+        ),
+        With<LookTransform>,
+    >,
+) {
+    world_to_screen.cameras.clear();
+    for (entity, camera, transform, perspective, projection) in cameras.iter() {
+        let Some(viewport_size) = camera.logical_viewport_size() else {
+            continue;
+        };
+        let screen_projection = if let Some(perspective) = perspective {
+            ScreenProjection::new(transform, perspective, viewport_size)
+        } else if let Some(projection) = projection {
+            ScreenProjection::new(transform, projection, viewport_size)
+        } else {
+            continue;
+        };
+        world_to_screen.cameras.insert(entity, screen_projection);
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projects_like_look_transform() {
+        let look = LookTransform::new(Vec3::new(1.0, 2.0, 5.0), Vec3::new(0.0, 1.0, 0.0), Vec3::Y);
+        let projection = PerspectiveProjection {
+            aspect_ratio: 2.0,
+            ..Default::default()
+        };
+        let camera = Entity::from_raw(7);
+        let mut cache = WorldToScreen::default();
+        cache.cameras.insert(
+            camera,
+            ScreenProjection::new(&look.into(), &projection, Vec2::new(800.0, 400.0)),
+        );
+
+        let point = Vec3::new(0.5, 1.5, 0.0);
+        let expected = look
+            .project_point_to_screen(point, UVec2::new(800, 400), &projection)
+            .unwrap();
+        assert!(world_to_screen(camera, point, &cache)
+            .unwrap()
+            .abs_diff_eq(expected, 1e-2));
+        assert!(world_to_screen(camera, look.target, &cache)
+            .unwrap()
+            .abs_diff_eq(Vec2::new(400.0, 200.0), 1e-2));
+
+        assert_eq!(
+            world_to_screen(camera, Vec3::new(1.0, 2.0, 10.0), &cache),
+            None
+        );
+        assert_eq!(world_to_screen(Entity::from_raw(8), point, &cache), None);
+    }
+}
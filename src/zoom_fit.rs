@@ -0,0 +1,160 @@
+use crate::{CameraTransition, EasingFn, LookTransform};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::{
+        camera::{PerspectiveProjection, Projection},
+        primitives::Aabb,
+    },
+    transform::components::GlobalTransform,
+};
+
+/// Starts [`CameraZoomFit`]s.
+pub struct CameraZoomFitPlugin;
+
+impl Plugin for CameraZoomFitPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraZoomFit>()
+            .add_systems(Update, camera_zoom_fit_system);
+    }
+}
+
+/// Smoothly frames a group of entities, e.g. the selection when F is pressed in an editor.
+///
+/// [`camera_zoom_fit_system`] fits the box around the entities' `GlobalTransform` translations with
+/// `LookTransform::frame_aabb`, keeping the current look direction, and starts a [`CameraTransition`] there. Then this
+/// component removes itself. Entities that no longer exist are skipped, and if none are left, nothing happens. The field
+/// of view comes from the camera's perspective `Projection` or `PerspectiveProjection`, or Bevy's default without one.
+#[derive(Clone, Component, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, Debug)]
+pub struct CameraZoomFit {
+    pub entities: Vec<Entity>,
+    /// With `0.1`, the box is fit with 10% to spare.
+    pub padding: f32,
+    /// Seconds.
+    pub duration: f32,
+    pub easing: EasingFn,
+}
+
+impl Default for CameraZoomFit {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl CameraZoomFit {
+    pub fn new(entities: Vec<Entity>) -> Self {
+        Self {
+            entities,
+            padding: 0.1,
+            duration: 0.5,
+            easing: EasingFn::EaseInOutCubic,
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn camera_zoom_fit_system(
+    mut commands: Commands,
+    entities: Query<&GlobalTransform>,
+    cameras: Query<(
+        Entity,
+        &CameraZoomFit,
+        &LookTransform,
+        Option<&CameraTransition>,
+        Option<&PerspectiveProjection>,
+        Option<&Projection>,
+    )>,
+) {
+    for (camera, fit, transform, transition, perspective, projection) in cameras.iter() {
+        commands.entity(camera).remove::<CameraZoomFit>();
+
+        let mut points = fit
+            .entities
+            .iter()
+            .filter_map(|&entity| entities.get(entity).ok())
+            .map(GlobalTransform::translation);
+        let Some(first) = points.next() else {
+            continue;
+        };
+        let (min, max) = points.fold((first, first), |(min, max), p| (min.min(p), max.max(p)));
+
+        // Like `CameraCommand::Transition`, interrupting a transition starts from wherever it had got to.
+        let from = transition.map_or(*transform, CameraTransition::current);
+        let to = if min == max {
+            // A single point has no size to fit, so keep the distance.
+            LookTransform {
+                eye: min + from.eye - from.target,
+                target: min,
+                up: from.up,
+            }
+        } else {
+            let default_perspective = PerspectiveProjection::default();
+            let perspective = perspective
+                .or(match projection {
+                    Some(Projection::Perspective(perspective)) => Some(perspective),
+                    _ => None,
+                })
+                .unwrap_or(&default_perspective);
+            from.frame_aabb(
+                &Aabb::from_min_max(min, max),
+                perspective.fov,
+                perspective.aspect_ratio,
+                fit.padding,
+            )
+        };
+
+        commands
+            .entity(camera)
+            .insert(CameraTransition::new(from, to, fit.duration, fit.easing));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_zoom_fit_frames_existing_entities() {
+        let mut app = App::new();
+        app.add_systems(Update, camera_zoom_fit_system);
+        let a = app
+            .world
+            .spawn(GlobalTransform::from_translation(Vec3::new(-1.0, 0.0, 0.0)))
+            .id();
+        let b = app
+            .world
+            .spawn(GlobalTransform::from_translation(Vec3::new(3.0, 2.0, 0.0)))
+            .id();
+        let despawned = app.world.spawn_empty().id();
+        app.world.despawn(despawned);
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 50.0), Vec3::ZERO, Vec3::Y);
+        let fit = CameraZoomFit::new(vec![a, despawned, b]);
+        let camera = app.world.spawn((start, fit.clone())).id();
+        app.update();
+
+        assert!(app.world.get::<CameraZoomFit>(camera).is_none());
+        let transition = app.world.get::<CameraTransition>(camera).unwrap();
+        assert_eq!(transition.from, start);
+        let projection = PerspectiveProjection::default();
+        let aabb = Aabb::from_min_max(Vec3::new(-1.0, 0.0, 0.0), Vec3::new(3.0, 2.0, 0.0));
+        assert_eq!(
+            transition.to,
+            start.frame_aabb(&aabb, projection.fov, projection.aspect_ratio, fit.padding)
+        );
+        assert_eq!(transition.to.target, Vec3::new(1.0, 1.0, 0.0));
+    }
+}